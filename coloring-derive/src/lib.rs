@@ -0,0 +1,274 @@
+//! `#[derive(Styled)]`, for colored `Display` impls. Use it through `coloring` with the `derive`
+//! feature, rather than depending on this crate directly.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident, LitStr};
+
+/// Derive a `Display` impl that shows the fields one after another, each styled the way its
+/// `#[styled(...)]` attribute says.
+/// 
+/// The attribute can go on fields, on enum variants, and on the type itself, and holds any of:
+///   - `fg = "..."` and `bg = "..."`, for the foreground and background colors. They take
+///     anything `Color` can be parsed from, like `"red"`, `"bright_cyan"`, or `"#ff8700"`.
+///   - Style names like `bold`, `italic`, or `curly_underline`.
+///   - `skip`, on a field, to leave it out.
+///   - `separator = "..."`, on the type or a variant, for what goes between the fields instead
+///     of a space.
+/// 
+/// Fields are shown with their own `Display` impls. Styles on a variant or the type apply to all
+/// of its fields, with the fields' own styles layered on top. A unit struct or variant shows its
+/// name.
+/// 
+/// A style name that doesn't exist or a color that can't be parsed is a compile error.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// #[derive(Styled)]
+/// #[styled(separator = ": ")]
+/// struct Problem {
+///     #[styled(fg = "red", bold)]
+///     level: &'static str,
+///     message: String,
+///     #[styled(skip)]
+///     code: u32,
+/// }
+/// 
+/// #[derive(Styled)]
+/// enum Status {
+///     #[styled(fg = "green")]
+///     Passed,
+///     #[styled(fg = "yellow")]
+///     Skipped(#[styled(italic)] &'static str),
+/// }
+/// 
+/// let _color = override_scope(ColorChoice::Always);
+/// let problem = Problem { level: "error", message: "file not found".to_string(), code: 2 };
+/// assert_eq!(problem.to_string(), "\x1B[31;1merror\x1B[0m: file not found");
+/// assert_eq!(Status::Passed.to_string(), "\x1B[32mPassed\x1B[0m");
+/// println!("{}", Status::Skipped("slow"));
+/// ```
+/// 
+/// "octarine" isn't a color, so this doesn't compile:
+/// 
+/// ```compile_fail
+/// use coloring::*;
+/// 
+/// #[derive(Styled)]
+/// struct Spell(#[styled(fg = "octarine")] &'static str);
+/// ```
+#[proc_macro_derive(Styled, attributes(styled))]
+pub fn derive_styled(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let attrs = StyledAttrs::parse(&input.attrs, Level::Type)?;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, fields) = destructure(quote!(#name), &data.fields)?;
+            let body = fields_body(&fields, attrs.separator.as_ref(), &name.to_string());
+            let body = wrap(&attrs, body);
+            quote! {
+                let #pattern = self;
+                #body
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                let variant_name = &variant.ident;
+                let variant_attrs = StyledAttrs::parse(&variant.attrs, Level::Variant)?;
+                let (pattern, fields) = destructure(quote!(#name::#variant_name), &variant.fields)?;
+                let separator = variant_attrs.separator.as_ref().or(attrs.separator.as_ref());
+                let body = fields_body(&fields, separator, &variant_name.to_string());
+                let body = wrap(&variant_attrs, body);
+                arms.push(quote!(#pattern => { #body }));
+            }
+            wrap(&attrs, quote!(match self { #(#arms)* }))
+        }
+        Data::Union(data) => {
+            let message = "#[derive(Styled)] doesn't support unions";
+            return Err(syn::Error::new_spanned(data.union_token, message));
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(::core::fmt::Display));
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Display for #name #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+/// A field, bound to a variable by the pattern from [`destructure`].
+struct Field {
+    binding: Ident,
+    attrs: StyledAttrs,
+}
+
+/// A pattern binding every field of `path` by reference, and the fields that get shown.
+fn destructure(path: TokenStream, fields: &Fields) -> syn::Result<(TokenStream, Vec<Field>)> {
+    let mut shown = Vec::new();
+    let mut bindings = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let binding = format_ident!("__field{}", i);
+        let attrs = StyledAttrs::parse(&field.attrs, Level::Field)?;
+        match &field.ident {
+            Some(ident) => bindings.push(quote!(#ident: #binding)),
+            None => bindings.push(quote!(#binding)),
+        }
+        if attrs.skip {
+            continue;
+        }
+        shown.push(Field { binding, attrs });
+    }
+    let pattern = match fields {
+        Fields::Named(_) => quote!(#path { #(#bindings,)* }),
+        Fields::Unnamed(_) => quote!(#path ( #(#bindings,)* )),
+        Fields::Unit => quote!(#path),
+    };
+    Ok((pattern, shown))
+}
+
+/// Write `fields` one after another, or `unit_name` if there aren't any.
+fn fields_body(fields: &[Field], separator: Option<&LitStr>, unit_name: &str) -> TokenStream {
+    if fields.is_empty() {
+        return quote!(f.write_str(#unit_name)?;);
+    }
+    let default_separator = LitStr::new(" ", Span::call_site());
+    let separator = separator.unwrap_or(&default_separator);
+    let mut body = TokenStream::new();
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            body.extend(quote!(f.write_str(#separator)?;));
+        }
+        let binding = &field.binding;
+        body.extend(match field.attrs.formatting() {
+            Some(formatting) => quote! {
+                ::core::fmt::Display::fmt(&::coloring::Styled::new(#binding, #formatting), f)?;
+            },
+            None => quote!(::core::fmt::Display::fmt(#binding, f)?;),
+        });
+    }
+    body
+}
+
+/// `body`, styled with `attrs` if they have any colors or styles.
+fn wrap(attrs: &StyledAttrs, body: TokenStream) -> TokenStream {
+    match attrs.formatting() {
+        Some(formatting) => quote! {
+            let contents = ::coloring::__DisplayFn::new(|f: &mut ::core::fmt::Formatter<'_>| {
+                #body
+                ::core::result::Result::Ok(())
+            });
+            ::core::fmt::Display::fmt(&::coloring::Styled::new(contents, #formatting), f)?;
+        },
+        None => body,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Type,
+    Variant,
+    Field,
+}
+
+/// What's in the `#[styled(...)]` attributes on one thing.
+#[derive(Default)]
+struct StyledAttrs {
+    fg: Option<LitStr>,
+    bg: Option<LitStr>,
+    styles: Vec<Ident>,
+    skip: bool,
+    separator: Option<LitStr>,
+}
+
+impl StyledAttrs {
+    fn parse(attrs: &[Attribute], level: Level) -> syn::Result<StyledAttrs> {
+        let mut parsed = StyledAttrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("styled")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("fg") || meta.path.is_ident("bg") {
+                    let color: LitStr = meta.value()?.parse()?;
+                    let slot =
+                        if meta.path.is_ident("fg") { &mut parsed.fg } else { &mut parsed.bg };
+                    if slot.replace(color).is_some() {
+                        return Err(meta.error("more than one color given"));
+                    }
+                } else if meta.path.is_ident("separator") {
+                    if level == Level::Field {
+                        return Err(meta.error("`separator` goes on the type or a variant"));
+                    }
+                    parsed.separator = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    if level != Level::Field {
+                        return Err(meta.error("`skip` only goes on fields"));
+                    }
+                    parsed.skip = true;
+                } else if let Some(style) = meta.path.get_ident() {
+                    parsed.styles.push(style.clone());
+                } else {
+                    return Err(meta.error("expected `fg`, `bg`, `skip`, `separator`, or a style"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+
+    /// An expression building the `Formatting`, or `None` if there's nothing to style.
+    fn formatting(&self) -> Option<TokenStream> {
+        if self.fg.is_none() && self.bg.is_none() && self.styles.is_empty() {
+            return None;
+        }
+        // Built in a `const`, so a color that doesn't parse fails the build, and nothing is
+        // parsed when the value is displayed.
+        let mut steps = TokenStream::new();
+        let colors = [(&self.fg, quote!(with_foreground)), (&self.bg, quote!(with_background))];
+        for (color, method) in colors {
+            if let Some(color) = color {
+                steps.extend(quote_spanned!(color.span()=> .#method(::coloring::__color(#color))));
+            }
+        }
+        for style in &self.styles {
+            let variant = Ident::new(&style_variant(&style.to_string()), style.span());
+            steps.extend(quote!(.with_style(::coloring::Styles::#variant)));
+        }
+        Some(quote!({
+            const FORMATTING: ::coloring::Formatting = ::coloring::Formatting::new()#steps;
+            FORMATTING
+        }))
+    }
+}
+
+/// The `Styles` variant for a style name, e.g. `CurlyUnderline` for `curly_underline`.
+fn style_variant(name: &str) -> String {
+    let name = match name {
+        "dim" => "faint",
+        "reverse" | "inverse" => "invert",
+        "hidden" => "invisible",
+        "strike" => "strikethrough",
+        name => name,
+    };
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().map(|first| first.to_ascii_uppercase());
+            first.into_iter().chain(chars).collect::<String>()
+        })
+        .collect()
+}
@@ -0,0 +1,149 @@
+//! A global accessibility mode, adjusting styles and colors as they're written.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+#[cfg(feature = "std")]
+use std::env;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "std")]
+use crate::detect::Background;
+use crate::{Color, StyleSet, Styles};
+
+/// The contrast ratio [`Accessibility::high_contrast`] aims for, WCAG's minimum for normal text.
+#[cfg(feature = "std")]
+const MIN_CONTRAST: f32 = 4.5;
+
+const NO_BLINK: u8 = 1;
+const HIGH_CONTRAST: u8 = 2;
+
+static SETTINGS: AtomicU8 = AtomicU8::new(0);
+
+/// Adjustments made to all output, for [`set_accessibility`].
+/// 
+/// Everything is off by default, so set the ones you want and fill in the rest with
+/// `..Default::default()`. A program might turn these on from a `--accessible` flag or a setting.
+/// 
+/// [`set_accessibility`]: fn.set_accessibility.html
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub struct Accessibility {
+    /// Leave out `Blink` and `RapidBlink`, for people with photosensitivity (or who just find
+    /// blinking text distracting).
+    pub no_blink: bool,
+    /// Make text easier to read: leave out `Faint`, and swap a foreground color that's hard to
+    /// read on its background for one that isn't.
+    /// 
+    /// With a background color set, a foreground with a contrast ratio under 4.5 (the WCAG
+    /// minimum) becomes black or white, see [`Color::contrast_fg`]. Without one, the foreground is
+    /// compared with the terminal's background, and lightened or darkened until it's readable.
+    /// The terminal's background comes from `COLORFGBG`, or is assumed to be dark. The terminal
+    /// is never asked (like [`detect::background`] does with the `probe` feature), since that
+    /// would mean reading from it in the middle of writing output.
+    /// 
+    /// Without the `std` feature the contrast can't be worked out, so this only leaves out
+    /// `Faint`.
+    /// 
+    /// [`Color::contrast_fg`]: enum.Color.html#method.contrast_fg
+    /// [`detect::background`]: detect/fn.background.html
+    pub high_contrast: bool,
+}
+
+impl Accessibility {
+    fn to_bits(self) -> u8 {
+        (if self.no_blink { NO_BLINK } else { 0 }) | (if self.high_contrast { HIGH_CONTRAST } else { 0 })
+    }
+
+    fn from_bits(bits: u8) -> Accessibility {
+        Accessibility { no_blink: bits & NO_BLINK != 0, high_contrast: bits & HIGH_CONTRAST != 0 }
+    }
+}
+
+/// Turn accessibility adjustments on or off, for the whole program.
+/// 
+/// They apply to everything written from then on, including `apply_to`, `paint`, the
+/// [`Colorize`] methods, and [`render::html`], and to conversions to other crates' styles.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// set_accessibility(Accessibility { no_blink: true, ..Default::default() });
+/// assert_eq!("NEW".red().blink().to_string(), "\x1B[31mNEW\x1B[0m");
+/// 
+/// set_accessibility(Accessibility { high_contrast: true, ..Default::default() });
+/// let badge = Formatting::new().foreground(Color::Yellow).background(Color::White).build();
+/// assert_eq!(badge.apply_to(" beta "), "\x1B[30;47m beta \x1B[0m");
+/// 
+/// set_accessibility(Accessibility::default());
+/// ```
+/// 
+/// [`Colorize`]: trait.Colorize.html
+/// [`render::html`]: render/fn.html.html
+pub fn set_accessibility(settings: Accessibility) {
+    SETTINGS.store(settings.to_bits(), Ordering::Relaxed);
+}
+
+/// The current [`Accessibility`] settings, all off unless they've been set.
+/// 
+/// [`Accessibility`]: struct.Accessibility.html
+pub fn accessibility() -> Accessibility {
+    Accessibility::from_bits(SETTINGS.load(Ordering::Relaxed))
+}
+
+/// `styles` with the ones the current settings leave out removed.
+pub(crate) fn adjust_styles(styles: StyleSet) -> StyleSet {
+    let settings = accessibility();
+    let mut styles = styles;
+    if settings.no_blink {
+        styles = styles.without(Styles::Blink).without(Styles::RapidBlink);
+    }
+    if settings.high_contrast {
+        styles = styles.without(Styles::Faint);
+    }
+    styles
+}
+
+/// `fg` and `bg` with the foreground made readable, if the current settings ask for it.
+#[cfg(feature = "std")]
+pub(crate) fn adjust_colors(fg: Color, bg: Color) -> (Color, Color) {
+    if !accessibility().high_contrast || !fg.is_set() {
+        return (fg, bg);
+    }
+    if bg.is_set() {
+        return match fg.contrast_ratio(&bg) {
+            Some(ratio) if ratio < MIN_CONTRAST => (bg.contrast_fg(), bg),
+            _ => (fg, bg),
+        };
+    }
+
+    let dark = terminal_background() == Background::Dark;
+    let terminal = if dark { Color::Black } else { Color::RGB { r: 255, g: 255, b: 255 } };
+    let mut boosted = fg;
+    for step in 1..=10 {
+        match boosted.contrast_ratio(&terminal) {
+            Some(ratio) if ratio < MIN_CONTRAST => {
+                let amount = step as f32 / 10.0;
+                boosted = if dark { fg.lighten(amount) } else { fg.darken(amount) };
+            }
+            _ => break,
+        }
+    }
+    (boosted, bg)
+}
+
+/// The terminal's background going by `COLORFGBG`, or dark. It's only read once.
+#[cfg(feature = "std")]
+fn terminal_background() -> Background {
+    static BACKGROUND: OnceLock<Background> = OnceLock::new();
+    *BACKGROUND.get_or_init(|| {
+        let colorfgbg = env::var("COLORFGBG").ok();
+        colorfgbg.and_then(|value| Background::from_colorfgbg(&value)).unwrap_or(Background::Dark)
+    })
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn adjust_colors(fg: Color, bg: Color) -> (Color, Color) {
+    (fg, bg)
+}
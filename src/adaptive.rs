@@ -0,0 +1,77 @@
+//! `AdaptiveColor`, a color that depends on whether the terminal's background is light or dark.
+
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::detect::Background;
+use crate::Color;
+
+/// A color with one version for light backgrounds and one for dark ones.
+/// 
+/// Yellow text is easy to read on black and nearly invisible on white. An `AdaptiveColor` lets
+/// you pick both versions up front, and [`resolve`] picks whichever suits the terminal.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// const WARNING: AdaptiveColor = AdaptiveColor::new(Color::RGB { r: 135, g: 95, b: 0 }, Color::Yellow);
+/// 
+/// println!("{}", Formatting::new().foreground(WARNING.resolve()).apply_to("careful!"));
+/// assert_eq!(WARNING.for_background(detect::Background::Dark), Color::Yellow);
+/// ```
+/// 
+/// [`resolve`]: struct.AdaptiveColor.html#method.resolve
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdaptiveColor {
+    /// The color to use on light backgrounds.
+    pub light: Color,
+    /// The color to use on dark backgrounds.
+    pub dark: Color,
+}
+
+impl AdaptiveColor {
+    /// Create an `AdaptiveColor` from its light and dark versions.
+    pub const fn new(light: Color, dark: Color) -> AdaptiveColor {
+        AdaptiveColor { light, dark }
+    }
+
+    /// The version for `background`.
+    pub const fn for_background(&self, background: Background) -> Color {
+        match background {
+            Background::Light => self.light,
+            Background::Dark => self.dark,
+        }
+    }
+
+    /// The version for the current terminal's background, using [`detect::background`].
+    /// 
+    /// The background is only checked the first time. If it can't be worked out (or without the
+    /// `std` feature), this is the dark version, since dark backgrounds are the most common.
+    /// 
+    /// [`detect::background`]: detect/fn.background.html
+    pub fn resolve(&self) -> Color {
+        self.for_background(current_background())
+    }
+}
+
+/// Same as [`AdaptiveColor::resolve`].
+/// 
+/// [`AdaptiveColor::resolve`]: struct.AdaptiveColor.html#method.resolve
+impl From<AdaptiveColor> for Color {
+    fn from(color: AdaptiveColor) -> Color {
+        color.resolve()
+    }
+}
+
+#[cfg(feature = "std")]
+fn current_background() -> Background {
+    static BACKGROUND: OnceLock<Background> = OnceLock::new();
+    *BACKGROUND.get_or_init(|| crate::detect::background().unwrap_or(Background::Dark))
+}
+
+#[cfg(not(feature = "std"))]
+fn current_background() -> Background {
+    Background::Dark
+}
@@ -0,0 +1,78 @@
+//! Splitting text into plain text and escape sequences.
+
+/// A piece of text, as split up by [`tokens`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    /// Plain text without any escape sequences.
+    Text(&'a str),
+    /// One whole escape sequence, e.g. `\x1B[31m`.
+    Escape(&'a str),
+}
+
+/// Split `text` into plain text and escape sequences.
+/// 
+/// This understands CSI sequences (`\x1B[...`, which includes colors and styles), OSC sequences
+/// (`\x1B]...`, e.g. hyperlinks), and the two-character escapes. An escape sequence that's cut off
+/// at the end of the text is still returned as an escape.
+pub(crate) fn tokens(text: &str) -> Tokens<'_> {
+    Tokens { rest: text }
+}
+
+pub(crate) struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let len = if self.rest.starts_with('\x1B') {
+            escape_len(self.rest.as_bytes())
+        } else {
+            self.rest.find('\x1B').unwrap_or(self.rest.len())
+        };
+        let (token, rest) = self.rest.split_at(len);
+        let is_escape = self.rest.starts_with('\x1B');
+        self.rest = rest;
+        Some(if is_escape { Token::Escape(token) } else { Token::Text(token) })
+    }
+}
+
+/// The length of the escape sequence at the start of `bytes`, which starts with ESC.
+fn escape_len(bytes: &[u8]) -> usize {
+    complete_escape_len(bytes).unwrap_or(bytes.len())
+}
+
+/// The length of the escape sequence at the start of `bytes`, which starts with ESC, or `None` if
+/// it's cut off, i.e. more bytes could still be part of it.
+pub(crate) fn complete_escape_len(bytes: &[u8]) -> Option<usize> {
+    match bytes.get(1) {
+        None => None,
+        Some(b'[') => {
+            // Parameters and intermediates, then one final byte.
+            let i = bytes[2..].iter().position(|b| !(0x20..=0x3F).contains(b))?;
+            if (0x40..=0x7E).contains(&bytes[2 + i]) {
+                Some(2 + i + 1)
+            } else {
+                Some(2 + i)
+            }
+        }
+        Some(b']') => {
+            // Ends with BEL or ST (`ESC \`).
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return Some(i + 1),
+                    0x1B if bytes.get(i + 1) == Some(&b'\\') => return Some(i + 2),
+                    _ => i += 1,
+                }
+            }
+            None
+        }
+        Some(b) if b.is_ascii() => Some(2),
+        Some(_) => Some(1),
+    }
+}
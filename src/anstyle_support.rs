@@ -0,0 +1,122 @@
+//! Conversions to and from [`anstyle`](https://docs.rs/anstyle) types, so a `Formatting` can be
+//! handed to clap's styling API or anything else in the anstream ecosystem.
+//! 
+//! anstyle has no equivalent for `Styles::Reset`, `Styles::Overline`, `Styles::Frame`,
+//! `Styles::Encircle`, or the fonts, so those are dropped when converting to an `anstyle::Style`.
+//! `Styles::RapidBlink` becomes a plain blink. anstyle's underline color is ignored, and
+//! `downgrade_to` is applied on the way out.
+
+use anstyle::{Ansi256Color, AnsiColor, Effects, RgbColor, Style};
+
+use crate::{quantize, Color, Formatting, Styles};
+
+/// Which anstyle effect each style maps to, if any.
+const EFFECTS: [(Styles, Effects); 13] = [
+    (Styles::Bold, Effects::BOLD),
+    (Styles::Faint, Effects::DIMMED),
+    (Styles::Italic, Effects::ITALIC),
+    (Styles::Underline, Effects::UNDERLINE),
+    (Styles::DoubleUnderline, Effects::DOUBLE_UNDERLINE),
+    (Styles::CurlyUnderline, Effects::CURLY_UNDERLINE),
+    (Styles::DottedUnderline, Effects::DOTTED_UNDERLINE),
+    (Styles::DashedUnderline, Effects::DASHED_UNDERLINE),
+    (Styles::Blink, Effects::BLINK),
+    (Styles::RapidBlink, Effects::BLINK),
+    (Styles::Invert, Effects::INVERT),
+    (Styles::Invisible, Effects::HIDDEN),
+    (Styles::Strikethrough, Effects::STRIKETHROUGH),
+];
+
+impl From<anstyle::Color> for Color {
+    fn from(color: anstyle::Color) -> Color {
+        match color {
+            anstyle::Color::Ansi(ansi) => match ansi {
+                AnsiColor::Black => Color::Black,
+                AnsiColor::Red => Color::Red,
+                AnsiColor::Green => Color::Green,
+                AnsiColor::Yellow => Color::Yellow,
+                AnsiColor::Blue => Color::Blue,
+                AnsiColor::Magenta => Color::Magenta,
+                AnsiColor::Cyan => Color::Cyan,
+                AnsiColor::White => Color::White,
+                AnsiColor::BrightBlack => Color::BrightBlack,
+                AnsiColor::BrightRed => Color::BrightRed,
+                AnsiColor::BrightGreen => Color::BrightGreen,
+                AnsiColor::BrightYellow => Color::BrightYellow,
+                AnsiColor::BrightBlue => Color::BrightBlue,
+                AnsiColor::BrightMagenta => Color::BrightMagenta,
+                AnsiColor::BrightCyan => Color::BrightCyan,
+                AnsiColor::BrightWhite => Color::BrightWhite,
+            },
+            anstyle::Color::Ansi256(Ansi256Color(index)) => Color::Colors256(index),
+            anstyle::Color::Rgb(RgbColor(r, g, b)) => Color::RGB { r, g, b },
+        }
+    }
+}
+
+/// `Color::Default` has no anstyle equivalent, so it becomes `None`.
+impl From<Color> for Option<anstyle::Color> {
+    fn from(color: Color) -> Option<anstyle::Color> {
+        let ansi = |ansi: AnsiColor| Some(anstyle::Color::Ansi(ansi));
+        match color {
+            Color::Default => None,
+            Color::Black => ansi(AnsiColor::Black),
+            Color::Red => ansi(AnsiColor::Red),
+            Color::Green => ansi(AnsiColor::Green),
+            Color::Yellow => ansi(AnsiColor::Yellow),
+            Color::Blue => ansi(AnsiColor::Blue),
+            Color::Magenta => ansi(AnsiColor::Magenta),
+            Color::Cyan => ansi(AnsiColor::Cyan),
+            Color::White => ansi(AnsiColor::White),
+            Color::BrightBlack => ansi(AnsiColor::BrightBlack),
+            Color::BrightRed => ansi(AnsiColor::BrightRed),
+            Color::BrightGreen => ansi(AnsiColor::BrightGreen),
+            Color::BrightYellow => ansi(AnsiColor::BrightYellow),
+            Color::BrightBlue => ansi(AnsiColor::BrightBlue),
+            Color::BrightMagenta => ansi(AnsiColor::BrightMagenta),
+            Color::BrightCyan => ansi(AnsiColor::BrightCyan),
+            Color::BrightWhite => ansi(AnsiColor::BrightWhite),
+            Color::Colors256(index) => Some(anstyle::Color::Ansi256(Ansi256Color(index))),
+            Color::Grayscale(level) => Some(anstyle::Color::Ansi256(Ansi256Color(quantize::grayscale_index(level)))),
+            Color::RGB { r, g, b } => Some(anstyle::Color::Rgb(RgbColor(r, g, b))),
+        }
+    }
+}
+
+impl From<Style> for Formatting {
+    fn from(style: Style) -> Formatting {
+        let mut formatting = Formatting::new();
+        if let Some(fg) = style.get_fg_color() {
+            formatting.foreground(fg.into());
+        }
+        if let Some(bg) = style.get_bg_color() {
+            formatting.background(bg.into());
+        }
+        let effects = style.get_effects();
+        for &(style, effect) in &EFFECTS {
+            // Rapid blink would double up with plain blink on the way back.
+            if style != Styles::RapidBlink && effects.contains(effect) {
+                formatting.add_style(style);
+            }
+        }
+        formatting
+    }
+}
+
+impl From<&Formatting> for Style {
+    fn from(formatting: &Formatting) -> Style {
+        let (fg, bg) = formatting.effective_colors();
+        let styles = formatting.effective_styles();
+        let effects = EFFECTS
+            .iter()
+            .filter(|&&(style, _)| styles.contains(style))
+            .fold(Effects::new(), |effects, &(_, effect)| effects | effect);
+        Style::new().fg_color(fg.into()).bg_color(bg.into()).effects(effects)
+    }
+}
+
+impl From<Formatting> for Style {
+    fn from(formatting: Formatting) -> Style {
+        Style::from(&formatting)
+    }
+}
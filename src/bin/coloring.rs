@@ -0,0 +1,114 @@
+//! Print styled text from the command line, e.g. in shell scripts.
+//! 
+//! ```text
+//! coloring --fg red --style bold "build failed"
+//! make 2>&1 | coloring --fg yellow
+//! ```
+
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use coloring::{set_color_choice, ColorChoice, Color, Formatting, Stream};
+
+const USAGE: &str = "\
+Usage: coloring [OPTIONS] [TEXT]...
+
+Print TEXT (or stdin, if there's no TEXT) with colors and styles.
+
+Options:
+  --fg COLOR       Foreground color, e.g. red, bright_blue, 208, or #ff8700
+  --bg COLOR       Background color
+  --style STYLE    A style, e.g. bold or italic. Can be given more than once
+  --color WHEN     auto, always, or never [default: auto]
+  -n               Don't print a newline at the end
+  -h, --help       Show this help";
+
+struct Options {
+    formatting: Formatting,
+    newline: bool,
+    text: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("coloring: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let text = if options.text.is_empty() {
+        let mut input = String::new();
+        if let Err(error) = io::stdin().read_to_string(&mut input) {
+            eprintln!("coloring: couldn't read stdin: {}", error);
+            return ExitCode::FAILURE;
+        }
+        // The input's own final newline is printed (or not) like any other text's.
+        let trimmed = input.strip_suffix('\n').unwrap_or(&input);
+        trimmed.strip_suffix('\r').unwrap_or(trimmed).to_string()
+    } else {
+        options.text.join(" ")
+    };
+
+    let mut stdout = io::stdout().lock();
+    let styled = options.formatting.paint_for(Stream::Stdout, &text);
+    let result = if options.newline { writeln!(stdout, "{}", styled) } else { write!(stdout, "{}", styled) };
+    match result.and_then(|()| stdout.flush()) {
+        Ok(()) => ExitCode::SUCCESS,
+        // e.g. `coloring ... | head`
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("coloring: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The options in `args`, or `None` if help was asked for.
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Options>, String> {
+    let mut formatting = Formatting::new();
+    // Multi-line text gets each line colored on its own, which survives pagers better.
+    formatting.per_line(true);
+    let mut newline = true;
+    let mut text = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or_else(|| format!("{} needs a value", name));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-n" => newline = false,
+            "--fg" => {
+                formatting.foreground(parse_color(&value("--fg")?)?);
+            }
+            "--bg" => {
+                formatting.background(parse_color(&value("--bg")?)?);
+            }
+            "--style" => {
+                let style = value("--style")?;
+                let parsed = Formatting::from_spec(&style).map_err(|error| error.to_string())?;
+                formatting = formatting.merge(&parsed);
+            }
+            "--color" => set_color_choice(match value("--color")?.as_str() {
+                "auto" => ColorChoice::Auto,
+                "always" => ColorChoice::Always,
+                "never" => ColorChoice::Never,
+                other => return Err(format!("unknown --color {:?}, expected auto, always, or never", other)),
+            }),
+            "--" => {
+                text.extend(args.by_ref());
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+            _ => text.push(arg),
+        }
+    }
+    Ok(Some(Options { formatting, newline, text }))
+}
+
+fn parse_color(s: &str) -> Result<Color, String> {
+    s.parse().map_err(|error: coloring::ParseColorError| error.to_string())
+}
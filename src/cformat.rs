@@ -0,0 +1,294 @@
+//! `format!`-style macros with styles in the placeholders, like `cformat!("{:red+bold}", "HI")`.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::{Formatting, Stream};
+
+/// Like `format!`, but a placeholder's spec can be a style, e.g. `{:red+bold}` or `{:bold on_blue}`.
+/// 
+/// The style is anything [`Formatting::from_spec`] understands, with `+` allowed between words
+/// and `on_` in front of a color meaning the background. Placeholders can also be plain `{}`, and
+/// either kind can pick an argument by position, e.g. `{0:red}`. `{{` and `}}` are literal braces.
+/// Every argument needs to be `Display`. With the `std` feature, a spec can also be a name added
+/// with [`theme::register`], e.g. `{:heading}`.
+/// 
+/// The format string has to be a literal, and it's checked when it's compiled: unmatched braces,
+/// placeholders without an argument, arguments without a placeholder, and specs meant for
+/// `format!` (width, alignment, precision, and `?`) are all compile errors. Named arguments
+/// aren't supported.
+/// 
+/// # Panics
+/// 
+/// Panics if a style isn't a color, a style, or a registered name. Names can be registered at any
+/// time, so this can only be checked when the macro runs.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let msg = "file not found";
+/// let text = cformat!("{:red+bold}: {}", "ERROR", msg);
+/// assert_eq!(text, "\x1B[31;1mERROR\x1B[0m: file not found");
+/// ```
+/// 
+/// Padding isn't a style, so this doesn't compile:
+/// 
+/// ```compile_fail
+/// use coloring::*;
+/// 
+/// let text = cformat!("{:>10}", "right");
+/// ```
+/// 
+/// [`Formatting::from_spec`]: struct.Formatting.html#method.from_spec
+/// [`theme::register`]: theme/fn.register.html
+#[macro_export]
+macro_rules! cformat {
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {
+        $crate::__cformat_to!(::core::option::Option::None, $fmt $(, $arg)*)
+    };
+}
+
+/// Like `print!`, but with styled placeholders. See [`cformat!`].
+/// 
+/// Escape codes are left out if stdout isn't a terminal (with `ColorChoice::Auto`).
+/// 
+/// [`cformat!`]: macro.cformat.html
+#[macro_export]
+macro_rules! cprint {
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {
+        ::std::print!("{}", $crate::__cformat_to!(
+            ::core::option::Option::Some($crate::Stream::Stdout),
+            $fmt
+            $(, $arg)*
+        ))
+    };
+}
+
+/// Like `println!`, but with styled placeholders. See [`cformat!`].
+/// 
+/// Escape codes are left out if stdout isn't a terminal (with `ColorChoice::Auto`).
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// cprintln!("{:green}: {} files copied", "OK", 3);
+/// ```
+/// 
+/// [`cformat!`]: macro.cformat.html
+#[macro_export]
+macro_rules! cprintln {
+    () => {
+        ::std::println!()
+    };
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {
+        ::std::println!("{}", $crate::__cformat_to!(
+            ::core::option::Option::Some($crate::Stream::Stdout),
+            $fmt
+            $(, $arg)*
+        ))
+    };
+}
+
+/// Like `eprint!`, but with styled placeholders. See [`cformat!`].
+/// 
+/// Escape codes are left out if stderr isn't a terminal (with `ColorChoice::Auto`).
+/// 
+/// [`cformat!`]: macro.cformat.html
+#[macro_export]
+macro_rules! ceprint {
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {
+        ::std::eprint!("{}", $crate::__cformat_to!(
+            ::core::option::Option::Some($crate::Stream::Stderr),
+            $fmt
+            $(, $arg)*
+        ))
+    };
+}
+
+/// Like `eprintln!`, but with styled placeholders. See [`cformat!`].
+/// 
+/// Escape codes are left out if stderr isn't a terminal (with `ColorChoice::Auto`).
+/// 
+/// [`cformat!`]: macro.cformat.html
+#[macro_export]
+macro_rules! ceprintln {
+    () => {
+        ::std::eprintln!()
+    };
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {
+        ::std::eprintln!("{}", $crate::__cformat_to!(
+            ::core::option::Option::Some($crate::Stream::Stderr),
+            $fmt
+            $(, $arg)*
+        ))
+    };
+}
+
+/// Check the format string at compile time, then format at runtime. Shared by the `cformat!`
+/// family.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cformat_to {
+    ($stream:expr, $fmt:literal $(, $arg:expr)*) => {{
+        const _: () = $crate::__check_cformat($fmt, <[&str]>::len(&[$(::core::stringify!($arg)),*]));
+        $crate::__cformat($stream, $fmt, &[$(&$arg as &dyn ::core::fmt::Display),*])
+    }};
+}
+
+/// The compile-time half of the `cformat!` family: panic (failing the build, since it's called
+/// in a `const`) if `fmt` is malformed or doesn't match the `args` arguments.
+#[doc(hidden)]
+pub const fn check(fmt: &str, args: usize) {
+    let bytes = fmt.as_bytes();
+    // Which arguments have a placeholder, for the first 128.
+    let mut used: u128 = 0;
+    let mut next_arg = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if (bytes[i] == b'{' || bytes[i] == b'}') && i + 1 < bytes.len() && bytes[i + 1] == bytes[i] {
+            i += 2;
+            continue;
+        }
+        if bytes[i] == b'}' {
+            panic!("invalid format string: unmatched `}}`, use `}}}}` for a literal `}}`");
+        }
+        if bytes[i] != b'{' {
+            i += 1;
+            continue;
+        }
+        i += 1;
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+        let mut index = 0;
+        let mut has_position = false;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            index = index * 10 + (bytes[i] - b'0') as usize;
+            has_position = true;
+            i += 1;
+        }
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+        if !has_position {
+            index = next_arg;
+            next_arg += 1;
+        }
+        if i < bytes.len() && bytes[i] == b':' {
+            i += 1;
+            while i < bytes.len() && bytes[i] != b'}' {
+                match bytes[i] {
+                    b'{' => panic!("invalid format string: unmatched `{{`, use `{{{{` for a literal `{{`"),
+                    b'<' | b'^' | b'>' | b'.' | b'?' | b'$' => {
+                        panic!("invalid format string: only styles go after the `:`, not width, alignment, precision, or `?`")
+                    }
+                    _ => i += 1,
+                }
+            }
+        }
+        if i == bytes.len() {
+            panic!("invalid format string: unmatched `{{`, use `{{{{` for a literal `{{`");
+        }
+        if bytes[i] != b'}' {
+            panic!("invalid format string: a placeholder's position has to be a number");
+        }
+        if index >= args {
+            panic!("invalid format string: a placeholder refers to an argument that isn't there");
+        }
+        if index < 128 {
+            used |= 1 << index;
+        }
+        i += 1;
+    }
+    let mut arg = 0;
+    while arg < args && arg < 128 {
+        if used & (1 << arg) == 0 {
+            panic!("invalid format string: an argument is never used");
+        }
+        arg += 1;
+    }
+}
+
+/// The runtime half of the `cformat!` family.
+#[doc(hidden)]
+pub fn cformat(stream: Option<Stream>, fmt: &str, args: &[&dyn fmt::Display]) -> String {
+    let mut formatted = String::with_capacity(fmt.len());
+    let mut next_arg = 0;
+    let mut rest = fmt;
+    while let Some(i) = rest.find(['{', '}']) {
+        formatted.push_str(&rest[..i]);
+        let brace = &rest[i..];
+        if brace.starts_with("{{") || brace.starts_with("}}") {
+            formatted.push_str(&brace[..1]);
+            rest = &brace[2..];
+            continue;
+        }
+        let end = match brace.strip_prefix('{').and_then(|b| b.find('}')) {
+            Some(end) => end + 1,
+            None => panic!("invalid format string {:?}: unmatched brace", fmt),
+        };
+        let (position, spec) = match brace[1..end].split_once(':') {
+            Some((position, spec)) => (position, Some(spec)),
+            None => (&brace[1..end], None),
+        };
+        let index = if position.trim().is_empty() {
+            next_arg += 1;
+            next_arg - 1
+        } else {
+            position
+                .trim()
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid format string {:?}: bad argument {:?}", fmt, position))
+        };
+        let arg = args
+            .get(index)
+            .unwrap_or_else(|| panic!("invalid format string {:?}: no argument {}", fmt, index));
+        match spec.filter(|spec| !spec.trim().is_empty()) {
+            Some(spec) => {
+                let formatting = style_spec(spec)
+                    .unwrap_or_else(|| panic!("invalid format string {:?}: bad style {:?}", fmt, spec));
+                let text = arg.to_string();
+                let styled = match stream {
+                    Some(stream) => formatting.paint_for(stream, &text),
+                    None => formatting.paint(&text),
+                };
+                // Writing to a `String` can't fail.
+                let _ = write!(formatted, "{}", styled);
+            }
+            None => {
+                let _ = write!(formatted, "{}", arg);
+            }
+        }
+        rest = &brace[end + 1..];
+    }
+    formatted.push_str(rest);
+    formatted
+}
+
+/// Turn `red+bold+on_blue` into the `Formatting` for `"red bold on blue"`, or a registered name
+/// into its `Formatting`.
+fn style_spec(spec: &str) -> Option<Formatting> {
+    #[cfg(feature = "std")]
+    if let Some(formatting) = crate::theme::registered(spec) {
+        return Some(formatting);
+    }
+    let words: Vec<String> = spec
+        .split(['+', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| match word.strip_prefix("on_") {
+            Some(color) => format!("on {}", color),
+            None => word.to_string(),
+        })
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    Formatting::from_spec(&words.join(" ")).ok()
+}
@@ -0,0 +1,158 @@
+//! Little charts drawn with Unicode block characters: [`sparkline`]s for a series of numbers,
+//! [`hbar`]s for one number out of a maximum, and labeled [`bar_chart`]s.
+//! 
+//! The colors come from [`heatmap`], so they're RGB and need a terminal with truecolor support
+//! to look right. Widths only count the visible text, so styled labels line up too.
+//! 
+//! [`sparkline`]: fn.sparkline.html
+//! [`hbar`]: fn.hbar.html
+//! [`bar_chart`]: fn.bar_chart.html
+//! [`heatmap`]: ../heatmap/fn.heatmap.html
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::heatmap::{self, default_ramp, positions};
+use crate::text::{pad_right, visible_width};
+use crate::{control, Formatting};
+
+/// The block characters [`sparkline`] draws with, from lowest to highest.
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// The block characters [`hbar`] ends a bar with, from an eighth of a column to seven eighths.
+const PARTIAL_BLOCKS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// `values` as a line of block characters, one per value, taller and hotter for bigger values,
+/// e.g. `▁▃▅█▂`.
+/// 
+/// The blocks are scaled from the smallest value to the biggest, and colored like
+/// [`heatmap::heatmap`]. Values that aren't finite are left as spaces.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let requests = [3.0, 5.0, 9.0, 14.0, 8.0, 2.0];
+/// println!("requests/s {}", charts::sparkline(&requests));
+/// 
+/// let _plain = override_scope(ColorChoice::Never);
+/// assert_eq!(charts::sparkline(&[1.0, 8.0, f64::NAN, 4.5]), "▁█ ▅");
+/// ```
+/// 
+/// [`heatmap::heatmap`]: ../heatmap/fn.heatmap.html
+pub fn sparkline(values: &[f64]) -> String {
+    let colorize = control::should_colorize(None);
+    let ramp = default_ramp();
+    let mut line = String::with_capacity(values.len() * if colorize { 24 } else { 3 });
+    for t in positions(values) {
+        let Some(t) = t else {
+            line.push(' ');
+            continue;
+        };
+        if colorize {
+            // Writing to a `String` can't fail.
+            let _ = Formatting::new().foreground(ramp.at(t as f32)).write_prefix(&mut line);
+        }
+        line.push(BLOCKS[round(t * (BLOCKS.len() - 1) as f64)]);
+    }
+    if colorize && line.contains('\x1B') {
+        line.push_str("\x1B[0m");
+    }
+    line
+}
+
+/// A bar `width` columns wide showing `value` out of `max`, styled with `formatting`, e.g.
+/// `█████▍    ` for 0.54 out of 1.
+/// 
+/// The end of the bar is drawn to an eighth of a column. The rest is filled with spaces, so the
+/// result is always `width` columns and bars line up. Values are clamped to `0.0..=max`, and if
+/// either isn't finite or `max` isn't positive, the bar is empty.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let used = Formatting::new().foreground(Color::Green).build();
+/// println!("disk [{}] 54%", charts::hbar(54.0, 100.0, 20, &used));
+/// 
+/// let plain = Formatting::new();
+/// assert_eq!(charts::hbar(0.54, 1.0, 10, &plain), "█████▍    ");
+/// assert_eq!(charts::hbar(3.0, 2.0, 4, &plain), "████");
+/// ```
+pub fn hbar(value: f64, max: f64, width: usize, formatting: &Formatting) -> String {
+    let fraction = if value.is_finite() && max.is_finite() && max > 0.0 {
+        (value / max).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let eighths = round(fraction * (width * 8) as f64);
+    let mut bar: String = core::iter::repeat_n('█', eighths / 8).collect();
+    if let Some(partial) = (eighths % 8).checked_sub(1) {
+        bar.push(PARTIAL_BLOCKS[partial]);
+    }
+    let columns = bar.chars().count();
+    if formatting.has_codes() && !bar.is_empty() {
+        bar = formatting.apply_to(&bar);
+    }
+    bar.extend(core::iter::repeat_n(' ', width - columns));
+    bar
+}
+
+/// A labeled bar for each of `rows`, `width` columns wide in total, with a newline after every
+/// line.
+/// 
+/// Each line is the label (padded to line up with the widest one), an [`hbar`], and the value.
+/// The bars are scaled so the biggest value fills its bar, and colored like
+/// [`heatmap::heatmap`]. Labels can be styled.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let rows = [("parse", 120.0), ("check", 480.0), ("codegen", 960.0)];
+/// print!("{}", charts::bar_chart(rows, 40));
+/// 
+/// let _plain = override_scope(ColorChoice::Never);
+/// assert_eq!(
+///     charts::bar_chart([("a", 1.0), ("bb", 4.5)], 16),
+///     "a  ██        1\nbb █████████ 4.5\n"
+/// );
+/// ```
+/// 
+/// [`hbar`]: fn.hbar.html
+/// [`heatmap::heatmap`]: ../heatmap/fn.heatmap.html
+pub fn bar_chart<I, L>(rows: I, width: usize) -> String
+where
+    I: IntoIterator<Item = (L, f64)>,
+    L: AsRef<str>,
+{
+    let (labels, values): (Vec<L>, Vec<f64>) = rows.into_iter().unzip();
+    let numbers: Vec<String> = values.iter().map(f64::to_string).collect();
+    let label_width = labels.iter().map(|label| visible_width(label.as_ref())).max().unwrap_or(0);
+    let number_width = numbers.iter().map(String::len).max().unwrap_or(0);
+    let bar_width = width.saturating_sub(label_width + number_width + 2).max(1);
+    let max = values.iter().copied().filter(|value| value.is_finite()).fold(0.0, f64::max);
+
+    let mut chart = String::new();
+    for ((label, value), (number, color)) in
+        labels.iter().zip(&values).zip(numbers.iter().zip(heatmap::heatmap(&values)))
+    {
+        let formatting = Formatting::new().with_foreground(color);
+        chart.push_str(&format!(
+            "{} {} {}\n",
+            pad_right(label.as_ref(), label_width),
+            hbar(*value, max, bar_width, &formatting),
+            number
+        ));
+    }
+    chart
+}
+
+/// Round a non-negative number without `f64::round`, which needs `std`.
+fn round(x: f64) -> usize {
+    (x + 0.5) as usize
+}
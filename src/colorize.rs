@@ -0,0 +1,253 @@
+//! The `Colorize` extension trait, for styling values in place.
+
+use core::fmt::{self, Write};
+
+use crate::{Color, Formatting, Nested, Styles};
+
+/// A value with a `Formatting` attached, returned by the [`Colorize`] methods.
+/// 
+/// The escape codes are written when this is displayed. Call more [`Colorize`]-style methods on it
+/// to keep adding colors and styles.
+/// 
+/// Width, fill, alignment, and precision are applied to the value before the escape codes go
+/// around it, so padding lines up the way it would without colors:
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// set_color_choice(ColorChoice::Always);
+/// assert_eq!(format!("[{:>6}]", "ok".green()), "[\x1B[32m    ok\x1B[0m]");
+/// assert_eq!(format!("[{:<5.1}]", 3.14159.red()), "[\x1B[31m3.1  \x1B[0m]");
+/// set_color_choice(ColorChoice::Auto);
+/// ```
+/// 
+/// [`Colorize`]: trait.Colorize.html
+#[derive(Debug)]
+pub struct Styled<T> {
+    value: T,
+    formatting: Formatting,
+}
+
+impl<T> Styled<T> {
+    /// Attach `formatting` to `value`.
+    pub fn new(value: T, formatting: Formatting) -> Styled<T> {
+        Styled { value, formatting }
+    }
+
+    /// The `Formatting` that will be applied.
+    pub fn formatting(&self) -> &Formatting {
+        &self.formatting
+    }
+
+    /// Throw away the styling and get the value back.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Set the foreground color to `Color`.
+    pub fn color(mut self, fg: Color) -> Styled<T> {
+        self.formatting.foreground(fg);
+        self
+    }
+
+    /// Set the background color to `Color`.
+    pub fn on_color(mut self, bg: Color) -> Styled<T> {
+        self.formatting.background(bg);
+        self
+    }
+
+    /// Add a style to the ones already set.
+    pub fn style(mut self, style: Styles) -> Styled<T> {
+        self.formatting.add_style(style);
+        self
+    }
+
+    /// Only write escape codes when `enabled` is true, like [`Formatting::when`].
+    /// 
+    /// [`Formatting::when`]: struct.Formatting.html#method.when
+    pub fn when(mut self, enabled: bool) -> Styled<T> {
+        self.formatting.when(enabled);
+        self
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_some() || f.precision().is_some() {
+            return self.formatting.write_padded(f, &self.value, self.formatting.colorize(None));
+        }
+        if !self.formatting.colorize(None) {
+            return write!(f, "{}", self.value);
+        }
+        self.formatting.write_prefix(f)?;
+        let mut nested = Nested::new(f, &self.formatting);
+        write!(nested, "{}", self.value)?;
+        nested.finish()
+    }
+}
+
+macro_rules! colorize_methods {
+    (
+        foreground: [$($fg:ident => $fg_color:ident),* $(,)?],
+        background: [$($bg:ident => $bg_color:ident),* $(,)?],
+        styles: [$($style:ident => $style_variant:ident),* $(,)?],
+    ) => {
+        impl<T> Styled<T> {
+            $(
+                #[doc = concat!("Set the foreground color to `Color::", stringify!($fg_color), "`.")]
+                pub fn $fg(self) -> Styled<T> {
+                    self.color(Color::$fg_color)
+                }
+            )*
+            $(
+                #[doc = concat!("Set the background color to `Color::", stringify!($bg_color), "`.")]
+                pub fn $bg(self) -> Styled<T> {
+                    self.on_color(Color::$bg_color)
+                }
+            )*
+            $(
+                #[doc = concat!("Add `Styles::", stringify!($style_variant), "`.")]
+                pub fn $style(self) -> Styled<T> {
+                    self.style(Styles::$style_variant)
+                }
+            )*
+        }
+
+        /// Style values directly, e.g. `"error".red().bold().on_black()`.
+        /// 
+        /// This is implemented for everything that is `Display`, including `&str` and `String`.
+        /// Each method wraps the value in a [`Styled`], which has the same methods for chaining.
+        /// 
+        /// # Example
+        /// 
+        /// ```
+        /// use coloring::*;
+        /// 
+        /// println!("{} {}", "error:".red().bold(), 404.yellow());
+        /// 
+        /// let formatting = Formatting::new().foreground(Color::Green).build();
+        /// println!("{}", "HI MOM".paint_with(formatting).underline());
+        /// ```
+        /// 
+        /// [`Styled`]: struct.Styled.html
+        pub trait Colorize: Sized {
+            /// Wrap the value with an existing `Formatting`.
+            fn paint_with(self, formatting: Formatting) -> Styled<Self> {
+                Styled::new(self, formatting)
+            }
+
+            /// Set the foreground color to `Color`.
+            fn color(self, fg: Color) -> Styled<Self> {
+                Styled::new(self, Formatting::new()).color(fg)
+            }
+
+            /// Set the background color to `Color`.
+            fn on_color(self, bg: Color) -> Styled<Self> {
+                Styled::new(self, Formatting::new()).on_color(bg)
+            }
+
+            /// Add a style.
+            fn style(self, style: Styles) -> Styled<Self> {
+                Styled::new(self, Formatting::new()).style(style)
+            }
+
+            $(
+                #[doc = concat!("Set the foreground color to `Color::", stringify!($fg_color), "`.")]
+                fn $fg(self) -> Styled<Self> {
+                    self.color(Color::$fg_color)
+                }
+            )*
+            $(
+                #[doc = concat!("Set the background color to `Color::", stringify!($bg_color), "`.")]
+                fn $bg(self) -> Styled<Self> {
+                    self.on_color(Color::$bg_color)
+                }
+            )*
+            $(
+                #[doc = concat!("Add `Styles::", stringify!($style_variant), "`.")]
+                fn $style(self) -> Styled<Self> {
+                    self.style(Styles::$style_variant)
+                }
+            )*
+        }
+    };
+}
+
+colorize_methods! {
+    foreground: [
+        black => Black,
+        red => Red,
+        green => Green,
+        yellow => Yellow,
+        blue => Blue,
+        magenta => Magenta,
+        cyan => Cyan,
+        white => White,
+        bright_black => BrightBlack,
+        bright_red => BrightRed,
+        bright_green => BrightGreen,
+        bright_yellow => BrightYellow,
+        bright_blue => BrightBlue,
+        bright_magenta => BrightMagenta,
+        bright_cyan => BrightCyan,
+        bright_white => BrightWhite,
+    ],
+    background: [
+        on_black => Black,
+        on_red => Red,
+        on_green => Green,
+        on_yellow => Yellow,
+        on_blue => Blue,
+        on_magenta => Magenta,
+        on_cyan => Cyan,
+        on_white => White,
+        on_bright_black => BrightBlack,
+        on_bright_red => BrightRed,
+        on_bright_green => BrightGreen,
+        on_bright_yellow => BrightYellow,
+        on_bright_blue => BrightBlue,
+        on_bright_magenta => BrightMagenta,
+        on_bright_cyan => BrightCyan,
+        on_bright_white => BrightWhite,
+    ],
+    styles: [
+        bold => Bold,
+        faint => Faint,
+        dim => Faint,
+        italic => Italic,
+        underline => Underline,
+        blink => Blink,
+        invert => Invert,
+        invisible => Invisible,
+        strikethrough => Strikethrough,
+        double_underline => DoubleUnderline,
+        curly_underline => CurlyUnderline,
+        dotted_underline => DottedUnderline,
+        dashed_underline => DashedUnderline,
+        rapid_blink => RapidBlink,
+        overline => Overline,
+        frame => Frame,
+        encircle => Encircle,
+    ],
+}
+
+impl<T: fmt::Display> Colorize for T {}
+
+/// A closure that writes to a formatter, as a `Display` value. Used by `#[derive(Styled)]` to
+/// style a whole struct or variant at once.
+#[cfg(feature = "derive")]
+pub struct DisplayFn<F>(F);
+
+#[cfg(feature = "derive")]
+impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> DisplayFn<F> {
+    pub fn new(f: F) -> DisplayFn<F> {
+        DisplayFn(f)
+    }
+}
+
+#[cfg(feature = "derive")]
+impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> fmt::Display for DisplayFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
@@ -0,0 +1,286 @@
+//! Global switches deciding whether escape codes get written at all.
+
+use alloc::string::String;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU8, Ordering};
+#[cfg(feature = "std")]
+use std::env;
+#[cfg(feature = "std")]
+use std::io::{self, IsTerminal};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::detect::ColorDepth;
+
+/// Whether to emit escape codes, for [`set_color_choice`].
+/// 
+/// [`set_color_choice`]: fn.set_color_choice.html
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub enum ColorChoice {
+    /// Always emit escape codes.
+    Always,
+    /// Never emit escape codes.
+    Never,
+    /// Follow the usual environment variables, see [`colors_enabled_with`] for which ones and in
+    /// what order. If none of them decide, emit escape codes only if the output goes to a
+    /// terminal, so pipes and redirected output stay clean. That's stdout, unless the output is
+    /// tied to a [`Stream`] (e.g. with `paint_for` or `ceprintln!`).
+    /// 
+    /// Without the `std` feature there's nothing to check, so this is the same as `Always`.
+    /// 
+    /// [`colors_enabled_with`]: fn.colors_enabled_with.html
+    /// [`Stream`]: enum.Stream.html
+    #[default]
+    Auto,
+}
+
+/// The standard output streams, for checking whether each one gets colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl ColorChoice {
+    /// The reverse of `as u8`, for choices kept in an `AtomicU8`.
+    pub(crate) fn from_u8(choice: u8) -> ColorChoice {
+        match choice {
+            c if c == ColorChoice::Always as u8 => ColorChoice::Always,
+            c if c == ColorChoice::Never as u8 => ColorChoice::Never,
+            _ => ColorChoice::Auto,
+        }
+    }
+}
+
+static CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+
+/// Set whether escape codes are emitted, for the whole program.
+/// 
+/// `apply_to`, `paint`, and the [`Colorize`] methods all respect this. They don't know where their
+/// output is going though, so with `ColorChoice::Auto` they assume it's stdout, and leave out
+/// escape codes if stdout isn't a terminal. Use [`paint_for`] (or [`colors_enabled_for`]) for
+/// output going to stderr, and [`override_scope`] to change the choice for just one thread.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// set_color_choice(ColorChoice::Never);
+/// assert_eq!(Formatting::new().foreground(Color::Red).apply_to("HI MOM"), "HI MOM");
+/// set_color_choice(ColorChoice::Auto);
+/// ```
+/// 
+/// [`Colorize`]: trait.Colorize.html
+/// [`paint_for`]: struct.Formatting.html#method.paint_for
+/// [`colors_enabled_for`]: fn.colors_enabled_for.html
+/// [`override_scope`]: fn.override_scope.html
+pub fn set_color_choice(choice: ColorChoice) {
+    CHOICE.store(choice as u8, Ordering::Relaxed);
+}
+
+/// The current [`ColorChoice`], `ColorChoice::Auto` unless it's been set.
+/// 
+/// [`ColorChoice`]: enum.ColorChoice.html
+pub fn color_choice() -> ColorChoice {
+    ColorChoice::from_u8(CHOICE.load(Ordering::Relaxed))
+}
+
+/// Force coloring on (`true`) or off (`false`) for the whole program, ignoring `NO_COLOR`.
+/// 
+/// Shorthand for [`set_color_choice`] with `ColorChoice::Always` or `ColorChoice::Never`.
+/// Handy for `--color` / `--no-color` flags.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// set_override(false);
+/// assert_eq!(Formatting::new().foreground(Color::Red).apply_to("HI MOM"), "HI MOM");
+/// unset_override();
+/// ```
+/// 
+/// [`set_color_choice`]: fn.set_color_choice.html
+pub fn set_override(colorize: bool) {
+    set_color_choice(if colorize { ColorChoice::Always } else { ColorChoice::Never });
+}
+
+/// Undo [`set_override`], going back to `ColorChoice::Auto`.
+/// 
+/// [`set_override`]: fn.set_override.html
+pub fn unset_override() {
+    set_color_choice(ColorChoice::Auto);
+}
+
+/// Whether output to `stream` should have escape codes, given the current [`ColorChoice`].
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// if colors_enabled_for(Stream::Stderr) {
+///     eprintln!("\x1B[31merror\x1B[0m: oh no");
+/// } else {
+///     eprintln!("error: oh no");
+/// }
+/// ```
+/// 
+/// [`ColorChoice`]: enum.ColorChoice.html
+pub fn colors_enabled_for(stream: Stream) -> bool {
+    should_colorize(Some(stream))
+}
+
+/// Temporarily use `choice` on the current thread, until the returned guard is dropped.
+/// 
+/// This beats [`set_color_choice`] and doesn't affect other threads, so it's handy in tests
+/// (which run in parallel) or when one thread renders output for a file while others print in
+/// color. Scopes can be nested; dropping a guard brings back whatever was in effect before it.
+/// 
+/// Needs the `std` feature.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// {
+///     let _plain = override_scope(ColorChoice::Never);
+///     assert_eq!("HI MOM".red().to_string(), "HI MOM");
+/// }
+/// let _colored = override_scope(ColorChoice::Always);
+/// assert_eq!("HI MOM".red().to_string(), "\x1B[31mHI MOM\x1B[0m");
+/// ```
+/// 
+/// [`set_color_choice`]: fn.set_color_choice.html
+#[cfg(feature = "std")]
+pub fn override_scope(choice: ColorChoice) -> OverrideGuard {
+    let previous = SCOPED_CHOICE.with(|scoped| scoped.replace(Some(choice)));
+    OverrideGuard { previous, _not_send: PhantomData }
+}
+
+/// Returned by [`override_scope`]. Dropping it ends the override.
+/// 
+/// [`override_scope`]: fn.override_scope.html
+#[cfg(feature = "std")]
+#[must_use = "the override ends as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct OverrideGuard {
+    previous: Option<ColorChoice>,
+    // The override belongs to one thread, so the guard has to be dropped there too.
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for OverrideGuard {
+    fn drop(&mut self) {
+        SCOPED_CHOICE.with(|scoped| scoped.set(self.previous));
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SCOPED_CHOICE: Cell<Option<ColorChoice>> = const { Cell::new(None) };
+}
+
+/// The choice in effect on this thread: an [`override_scope`]'s, or else the global one.
+#[cfg(feature = "std")]
+fn effective_choice() -> ColorChoice {
+    SCOPED_CHOICE.with(Cell::get).unwrap_or_else(color_choice)
+}
+
+#[cfg(not(feature = "std"))]
+fn effective_choice() -> ColorChoice {
+    color_choice()
+}
+
+/// Whether escape codes should currently be emitted, for output going to `stream` (stdout if it
+/// isn't known).
+pub(crate) fn should_colorize(stream: Option<Stream>) -> bool {
+    match effective_choice() {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => auto_colorize(stream),
+    }
+}
+
+#[cfg(feature = "std")]
+fn auto_colorize(stream: Option<Stream>) -> bool {
+    env_choice().unwrap_or_else(|| is_terminal(stream.unwrap_or(Stream::Stdout)))
+}
+
+#[cfg(not(feature = "std"))]
+fn auto_colorize(_stream: Option<Stream>) -> bool {
+    true
+}
+
+/// What the environment says, if anything. It's only read once.
+#[cfg(feature = "std")]
+fn env_choice() -> Option<bool> {
+    static CHOICE: OnceLock<Option<bool>> = OnceLock::new();
+    *CHOICE.get_or_init(|| env_choice_with(|name| env::var(name).ok()))
+}
+
+/// `Some` if one of the variables decides, `None` to leave it to whether there's a terminal.
+fn env_choice_with<F: Fn(&str) -> Option<String>>(var: F) -> Option<bool> {
+    if let Some(force) = var("FORCE_COLOR") {
+        return Some(ColorDepth::from_force_color(&force) != ColorDepth::NoColor);
+    }
+    if var("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0") {
+        return Some(true);
+    }
+    if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return Some(false);
+    }
+    if var("CLICOLOR").as_deref() == Some("0") {
+        return Some(false);
+    }
+    None
+}
+
+/// Whether `ColorChoice::Auto` means colors, given environment variables looked up with `var`
+/// and whether the output goes to a terminal.
+/// 
+/// The variables are checked in this order, and the first one that's set decides:
+/// 
+///   1. `FORCE_COLOR`: colors on, even if the output isn't a terminal, unless it's `0` or
+///      `false`. Its level (`1` to `3`) also feeds into [`TerminalCaps::detect`].
+///   2. `CLICOLOR_FORCE`: colors on if it's anything but empty or `0`.
+///   3. `NO_COLOR`: colors off if it's anything but empty, see [no-color.org](https://no-color.org).
+///   4. `CLICOLOR`: colors off if it's `0`, see [bixense.com/clicolors](https://bixense.com/clicolors/).
+/// 
+/// If none of them decide, it's colors for a terminal and none otherwise. An explicit
+/// [`set_color_choice`] (e.g. from a `--color` flag) beats all of these. This is what
+/// `ColorChoice::Auto` uses with the real environment, exposed for testing and for deciding
+/// about streams other than stdout and stderr.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let vars = |name: &str| (name == "CLICOLOR_FORCE").then(|| "1".to_string());
+/// assert!(colors_enabled_with(vars, false));
+/// assert!(!colors_enabled_with(|_| None, false));
+/// ```
+/// 
+/// [`TerminalCaps::detect`]: detect/struct.TerminalCaps.html#method.detect
+/// [`set_color_choice`]: fn.set_color_choice.html
+pub fn colors_enabled_with<F: Fn(&str) -> Option<String>>(var: F, is_terminal: bool) -> bool {
+    env_choice_with(var).unwrap_or(is_terminal)
+}
+
+/// Only checked once per stream.
+#[cfg(feature = "std")]
+fn is_terminal(stream: Stream) -> bool {
+    static STDOUT: OnceLock<bool> = OnceLock::new();
+    static STDERR: OnceLock<bool> = OnceLock::new();
+    match stream {
+        Stream::Stdout => *STDOUT.get_or_init(|| io::stdout().is_terminal()),
+        Stream::Stderr => *STDERR.get_or_init(|| io::stderr().is_terminal()),
+    }
+}
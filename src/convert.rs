@@ -0,0 +1,337 @@
+//! Converting colored output that's already been captured, e.g. a CI log, from a reader to a
+//! writer: [`to_plain`] strips the escape codes, and [`to_html`] turns them into HTML.
+//! 
+//! These work on streams of any size: they read a piece at a time and never hold the whole input
+//! in memory. Escape sequences are recognized even when they're split between two reads.
+//! 
+//! Needs the `std` feature.
+//! 
+//! [`to_plain`]: fn.to_plain.html
+//! [`to_html`]: fn.to_html.html
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write as _;
+use std::io::{self, Read, Write};
+
+use crate::ansi::complete_escape_len;
+use crate::quantize::{grayscale_index, BASIC_COLORS};
+use crate::{parse, render, Color, Formatting, Styles};
+
+/// How much is read at a time.
+const CHUNK: usize = 8 * 1024;
+
+/// An escape sequence that's still unfinished after this many bytes is given up on, so a stray
+/// `\x1B]` can't make the whole rest of the input pile up in memory.
+const MAX_ESCAPE: usize = 4 * 1024;
+
+/// Copy everything from `reader` to `writer`, leaving out the escape sequences, like [`strip`]
+/// but for files and pipes.
+/// 
+/// Bytes that aren't valid UTF-8 are copied as they are.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let log = format!("{} build ok\n", "✓".green());
+/// let mut plain = Vec::new();
+/// convert::to_plain(log.as_bytes(), &mut plain).unwrap();
+/// assert_eq!(plain, "✓ build ok\n".as_bytes());
+/// ```
+/// 
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::{BufReader, BufWriter};
+/// 
+/// let log = BufReader::new(File::open("build.log").unwrap());
+/// let plain = BufWriter::new(File::create("build.txt").unwrap());
+/// coloring::convert::to_plain(log, plain).unwrap();
+/// ```
+/// 
+/// [`strip`]: ../fn.strip.html
+pub fn to_plain<R: Read, W: Write>(reader: R, mut writer: W) -> io::Result<()> {
+    scan(reader, |piece| match piece {
+        Piece::Text(text) => writer.write_all(text),
+        Piece::Escape(_) => Ok(()),
+    })?;
+    writer.flush()
+}
+
+/// Settings for [`to_html`].
+/// 
+/// [`to_html`]: fn.to_html.html
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct HtmlOptions {
+    classes: bool,
+    pre: bool,
+}
+
+impl HtmlOptions {
+    /// Inline styles, and no `<pre>` around the output.
+    pub fn new() -> HtmlOptions {
+        HtmlOptions::default()
+    }
+
+    /// Use CSS classes like `ansi-fg-red` and `ansi-bold` instead of inline styles, so the colors
+    /// can be changed with a stylesheet, e.g. to match a site's theme. See [`stylesheet`] for
+    /// the default one.
+    /// 
+    /// 256 colors get classes like `ansi-fg-256-208`. RGB colors have no class, so they're still
+    /// inline.
+    /// 
+    /// [`stylesheet`]: fn.stylesheet.html
+    pub fn classes(&mut self, classes: bool) -> &mut HtmlOptions {
+        self.classes = classes;
+        self
+    }
+
+    /// Put the output in a `<pre>`, so the spacing and line breaks are kept.
+    pub fn pre(&mut self, pre: bool) -> &mut HtmlOptions {
+        self.pre = pre;
+        self
+    }
+}
+
+/// Copy everything from `reader` to `writer` as HTML, with the colors and styles set by escape
+/// codes turned into `<span>`s, like [`render::html`] but for files and pipes.
+/// 
+/// Colors and styles build up the way they do in a terminal, until something turns them off or
+/// resets them. The text is HTML-escaped, and other escape sequences are dropped. Palette colors
+/// use xterm's default values, and bytes that aren't valid UTF-8 become `�`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::convert::{self, HtmlOptions};
+/// 
+/// let log = "\x1B[1mtests:\x1B[32m ok\x1B[0m <3";
+/// let mut html = Vec::new();
+/// convert::to_html(log.as_bytes(), &mut html, &HtmlOptions::new()).unwrap();
+/// assert_eq!(
+///     String::from_utf8(html).unwrap(),
+///     "<span style=\"font-weight:bold\">tests:</span><span style=\"color:#00cd00;font-weight:bold\"> ok</span> &lt;3"
+/// );
+/// 
+/// let mut html = Vec::new();
+/// convert::to_html(log.as_bytes(), &mut html, HtmlOptions::new().classes(true).pre(true)).unwrap();
+/// assert_eq!(
+///     String::from_utf8(html).unwrap(),
+///     "<pre><span class=\"ansi-bold\">tests:</span><span class=\"ansi-fg-green ansi-bold\"> ok</span> &lt;3</pre>"
+/// );
+/// ```
+/// 
+/// [`render::html`]: ../render/fn.html.html
+pub fn to_html<R: Read, W: Write>(reader: R, mut writer: W, options: &HtmlOptions) -> io::Result<()> {
+    if options.pre {
+        writer.write_all(b"<pre>")?;
+    }
+    let mut formatting = Formatting::new();
+    // The attributes of the `<span>` that's open, if any.
+    let mut open = String::new();
+    let mut html = String::new();
+    scan(reader, |piece| {
+        match piece {
+            Piece::Text(text) => {
+                let attributes = span_attributes(&formatting, options);
+                if attributes != open {
+                    if !open.is_empty() {
+                        writer.write_all(b"</span>")?;
+                    }
+                    if !attributes.is_empty() {
+                        write!(writer, "<span {}>", attributes)?;
+                    }
+                    open = attributes;
+                }
+                html.clear();
+                render::escape_html(&mut html, &String::from_utf8_lossy(text));
+                writer.write_all(html.as_bytes())?;
+            }
+            Piece::Escape(escape) => {
+                let params = escape.strip_prefix(b"\x1B[").and_then(|e| e.strip_suffix(b"m"));
+                if let Some(params) = params.and_then(|params| core::str::from_utf8(params).ok()) {
+                    parse::apply_sgr(&mut formatting, params);
+                }
+            }
+        }
+        Ok(())
+    })?;
+    if !open.is_empty() {
+        writer.write_all(b"</span>")?;
+    }
+    if options.pre {
+        writer.write_all(b"</pre>")?;
+    }
+    writer.flush()
+}
+
+/// The CSS for the classes [`to_html`] uses with [`HtmlOptions::classes`], with xterm's default
+/// colors.
+/// 
+/// # Example
+/// 
+/// ```
+/// let css = coloring::convert::stylesheet();
+/// assert!(css.contains(".ansi-fg-bright-red{color:#ff0000}"));
+/// assert!(css.contains(".ansi-bg-256-208{background-color:#ff8700}"));
+/// assert!(css.contains(".ansi-italic{font-style:italic}"));
+/// ```
+/// 
+/// [`to_html`]: fn.to_html.html
+/// [`HtmlOptions::classes`]: struct.HtmlOptions.html#method.classes
+pub fn stylesheet() -> String {
+    let mut css = String::new();
+    let colors = BASIC_COLORS.iter().copied().chain((16..=255).map(Color::Colors256));
+    for color in colors {
+        let Some(name) = color_class(color) else { continue };
+        let fg = render::css(&Formatting::new().with_foreground(color));
+        let bg = render::css(&Formatting::new().with_background(color));
+        let _ = writeln!(css, ".ansi-fg-{}{{{}}}\n.ansi-bg-{}{{{}}}", name, fg, name, bg);
+    }
+    for style in Styles::ALL {
+        let rules = render::css(&Formatting::new().with_style(style));
+        if !rules.is_empty() {
+            let _ = writeln!(css, ".ansi-{}{{{}}}", style_class(style), rules);
+        }
+    }
+    css
+}
+
+/// The attributes of the `<span>` for `formatting`, or nothing if it doesn't need one.
+fn span_attributes(formatting: &Formatting, options: &HtmlOptions) -> String {
+    if !options.classes {
+        let css = render::css(formatting);
+        return if css.is_empty() { css } else { format!("style=\"{}\"", css) };
+    }
+
+    let (mut fg, mut bg) = formatting.effective_colors();
+    let mut classes = Vec::new();
+    if formatting.effective_styles().contains(Styles::Invert) {
+        if fg != Color::Default && bg != Color::Default {
+            core::mem::swap(&mut fg, &mut bg);
+        } else {
+            classes.push("ansi-invert".to_string());
+        }
+    }
+    // RGB colors don't have classes, so they stay inline.
+    let mut inline = Formatting::new();
+    match color_class(fg) {
+        Some(name) => classes.push(format!("ansi-fg-{}", name)),
+        None => {
+            inline.foreground(fg);
+        }
+    }
+    match color_class(bg) {
+        Some(name) => classes.push(format!("ansi-bg-{}", name)),
+        None => {
+            inline.background(bg);
+        }
+    }
+    for style in formatting.effective_styles().iter() {
+        if !matches!(style, Styles::Reset | Styles::Invert) {
+            classes.push(format!("ansi-{}", style_class(style)));
+        }
+    }
+
+    let mut attributes = String::new();
+    if !classes.is_empty() {
+        let _ = write!(attributes, "class=\"{}\"", classes.join(" "));
+    }
+    let css = render::css(&inline);
+    if !css.is_empty() {
+        if !attributes.is_empty() {
+            attributes.push(' ');
+        }
+        let _ = write!(attributes, "style=\"{}\"", css);
+    }
+    attributes
+}
+
+/// The name a color has in class names, e.g. `bright-red` or `256-208`.
+fn color_class(color: Color) -> Option<String> {
+    match color {
+        Color::Default | Color::RGB { .. } => None,
+        Color::Colors256(index) => Some(format!("256-{}", index)),
+        Color::Grayscale(level) => Some(format!("256-{}", grayscale_index(level))),
+        basic => Some(basic.to_string().replace(' ', "-")),
+    }
+}
+
+/// The name a style has in class names, e.g. `curly-underline`.
+fn style_class(style: Styles) -> String {
+    style.to_string().replace(' ', "-")
+}
+
+/// A piece of the input, as split up by [`scan`].
+pub(crate) enum Piece<'a> {
+    /// Plain text. It never ends partway through a UTF-8 character, unless the input does.
+    Text(&'a [u8]),
+    /// One whole escape sequence.
+    Escape(&'a [u8]),
+}
+
+/// Read `reader` to the end, calling `emit` with each run of plain text and each escape sequence.
+pub(crate) fn scan<R: Read>(mut reader: R, mut emit: impl FnMut(Piece<'_>) -> io::Result<()>) -> io::Result<()> {
+    let mut buffer = Vec::with_capacity(CHUNK);
+    let mut chunk = [0; CHUNK];
+    loop {
+        let read = match reader.read(&mut chunk) {
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        let done = read == 0;
+        buffer.extend_from_slice(&chunk[..read]);
+
+        let mut start = 0;
+        while start < buffer.len() {
+            let rest = &buffer[start..];
+            if rest[0] == 0x1B {
+                let len = match complete_escape_len(rest) {
+                    Some(len) => len,
+                    None if done || rest.len() > MAX_ESCAPE => rest.len(),
+                    // Wait for the rest of it.
+                    None => break,
+                };
+                emit(Piece::Escape(&rest[..len]))?;
+                start += len;
+            } else {
+                let mut len = rest.iter().position(|&b| b == 0x1B).unwrap_or(rest.len());
+                if !done && len == rest.len() {
+                    // Hold back a character that's cut off, for the next read.
+                    len -= cut_off_char(rest);
+                }
+                if len == 0 {
+                    break;
+                }
+                emit(Piece::Text(&rest[..len]))?;
+                start += len;
+            }
+        }
+        buffer.drain(..start);
+        if done {
+            return Ok(());
+        }
+    }
+}
+
+/// How many bytes at the end of `bytes` are the start of a UTF-8 character that's cut off.
+fn cut_off_char(bytes: &[u8]) -> usize {
+    for back in 1..=bytes.len().min(3) {
+        let byte = bytes[bytes.len() - back];
+        // Skip over continuation bytes to the character's first byte.
+        if byte & 0xC0 != 0x80 {
+            let len = match byte {
+                0xF0.. => 4,
+                0xE0.. => 3,
+                0xC0.. => 2,
+                _ => 1,
+            };
+            return if len > back { back } else { 0 };
+        }
+    }
+    0
+}
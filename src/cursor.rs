@@ -0,0 +1,275 @@
+//! Moving the cursor and erasing parts of the screen, for progress bars, spinners, and the like.
+//! 
+//! Each function returns a [`Command`], which you can print (it's `Display`), turn into a
+//! `String`, or [`write_to`] an `io::Write`. Unlike colors, these are never left out because of
+//! `NO_COLOR` or the color choice, since leaving them out would mess up the output.
+//! 
+//! [`StatusLine`] builds on them to keep a status line at the bottom of the screen.
+//! 
+//! [`Command`]: enum.Command.html
+//! [`StatusLine`]: struct.StatusLine.html
+//! [`write_to`]: enum.Command.html#method.write_to
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{Formatting, Styles};
+
+/// One cursor or screen control sequence.
+/// 
+/// Rows and columns start at 0, in the top-left corner.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::cursor;
+/// 
+/// print!("working...");
+/// print!("{}{}done", cursor::move_to_column(0), cursor::erase_line());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Command {
+    /// Move up this many rows.
+    Up(u16),
+    /// Move down this many rows.
+    Down(u16),
+    /// Move right this many columns.
+    Right(u16),
+    /// Move left this many columns.
+    Left(u16),
+    /// Move to a row and column.
+    To { row: u16, column: u16 },
+    /// Move to a column in the current row.
+    ToColumn(u16),
+    /// Erase the whole current row.
+    EraseLine,
+    /// Erase from the cursor to the end of the row.
+    EraseToEndOfLine,
+    /// Erase the whole screen.
+    EraseScreen,
+    /// Remember where the cursor is, for `Restore`.
+    Save,
+    /// Go back to where the cursor was at the last `Save`.
+    Restore,
+    /// Stop showing the cursor.
+    Hide,
+    /// Show the cursor again.
+    Show,
+}
+
+impl Command {
+    /// Write the escape sequence to an `io::Write`, e.g. a stdout lock.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::cursor;
+    /// 
+    /// let mut buffer = Vec::new();
+    /// cursor::hide().write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"\x1B[?25l");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: io::Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "{}", self)
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            // A count of 0 would be read as 1, so write nothing at all instead.
+            Command::Up(0) | Command::Down(0) | Command::Right(0) | Command::Left(0) => Ok(()),
+            Command::Up(n) => write!(f, "\x1B[{}A", n),
+            Command::Down(n) => write!(f, "\x1B[{}B", n),
+            Command::Right(n) => write!(f, "\x1B[{}C", n),
+            Command::Left(n) => write!(f, "\x1B[{}D", n),
+            Command::To { row, column } => write!(f, "\x1B[{};{}H", row as u32 + 1, column as u32 + 1),
+            Command::ToColumn(column) => write!(f, "\x1B[{}G", column as u32 + 1),
+            Command::EraseLine => f.write_str("\x1B[2K"),
+            Command::EraseToEndOfLine => f.write_str("\x1B[K"),
+            Command::EraseScreen => f.write_str("\x1B[2J"),
+            Command::Save => f.write_str("\x1B7"),
+            Command::Restore => f.write_str("\x1B8"),
+            Command::Hide => f.write_str("\x1B[?25l"),
+            Command::Show => f.write_str("\x1B[?25h"),
+        }
+    }
+}
+
+/// Move the cursor up `n` rows.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::cursor;
+/// 
+/// assert_eq!(cursor::move_up(3).to_string(), "\x1B[3A");
+/// ```
+pub fn move_up(n: u16) -> Command {
+    Command::Up(n)
+}
+
+/// Move the cursor down `n` rows.
+pub fn move_down(n: u16) -> Command {
+    Command::Down(n)
+}
+
+/// Move the cursor right `n` columns.
+pub fn move_right(n: u16) -> Command {
+    Command::Right(n)
+}
+
+/// Move the cursor left `n` columns.
+pub fn move_left(n: u16) -> Command {
+    Command::Left(n)
+}
+
+/// Move the cursor to `row` and `column`, counting from 0 in the top-left corner.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::cursor;
+/// 
+/// assert_eq!(cursor::move_to(0, 4).to_string(), "\x1B[1;5H");
+/// ```
+pub fn move_to(row: u16, column: u16) -> Command {
+    Command::To { row, column }
+}
+
+/// Move the cursor to `column` in the current row, counting from 0.
+pub fn move_to_column(column: u16) -> Command {
+    Command::ToColumn(column)
+}
+
+/// Erase the whole row the cursor is on. The cursor doesn't move.
+pub fn erase_line() -> Command {
+    Command::EraseLine
+}
+
+/// Erase from the cursor to the end of its row.
+pub fn erase_to_end_of_line() -> Command {
+    Command::EraseToEndOfLine
+}
+
+/// Erase the whole screen. The cursor doesn't move, so you'll usually want a `move_to(0, 0)` too.
+pub fn erase_screen() -> Command {
+    Command::EraseScreen
+}
+
+/// Remember where the cursor is, to go back to with [`restore_cursor`].
+/// 
+/// [`restore_cursor`]: fn.restore_cursor.html
+pub fn save_cursor() -> Command {
+    Command::Save
+}
+
+/// Go back to where the cursor was at the last [`save_cursor`].
+/// 
+/// [`save_cursor`]: fn.save_cursor.html
+pub fn restore_cursor() -> Command {
+    Command::Restore
+}
+
+/// Stop showing the cursor. Remember to [`show`] it again before your program exits.
+/// 
+/// [`show`]: fn.show.html
+pub fn hide() -> Command {
+    Command::Hide
+}
+
+/// Show the cursor again after [`hide`].
+/// 
+/// [`hide`]: fn.hide.html
+pub fn show() -> Command {
+    Command::Show
+}
+
+/// A colored status line pinned to the bottom row of the terminal, while normal output scrolls
+/// above it.
+/// 
+/// It works by limiting scrolling to the rows above the status line, then saving the cursor,
+/// jumping down to redraw the status, and jumping back. Every method returns the escape sequences
+/// to print, so it works with whatever you use for output. Print [`begin`] once at the start,
+/// [`update`] whenever the status changes, and [`end`] before exiting, or the terminal is left
+/// with a shortened scrolling area.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::cursor::StatusLine;
+/// 
+/// let (_, rows) = terminal_size().unwrap_or((80, 24));
+/// let status = StatusLine::new(rows);
+/// print!("{}", status.begin());
+/// for i in 1..=3 {
+///     println!("compiled crate {}", i);
+///     print!("{}", status.update(&format!("building: {}/3", i)));
+/// }
+/// print!("{}", status.end());
+/// ```
+/// 
+/// [`begin`]: struct.StatusLine.html#method.begin
+/// [`update`]: struct.StatusLine.html#method.update
+/// [`end`]: struct.StatusLine.html#method.end
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusLine {
+    rows: u16,
+    formatting: Formatting,
+}
+
+impl StatusLine {
+    /// A status line for a terminal `rows` rows high, shown in inverted colors.
+    pub fn new(rows: u16) -> StatusLine {
+        StatusLine { rows: rows.max(2), formatting: Formatting::new().styles([Styles::Invert]).build() }
+    }
+
+    /// Set the formatting of the status text.
+    pub fn formatting(&mut self, formatting: Formatting) -> &mut StatusLine {
+        self.formatting = formatting;
+        self
+    }
+
+    /// Reserve the bottom row for the status line.
+    /// 
+    /// After a resize, print this again with the new number of rows.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::cursor::StatusLine;
+    /// 
+    /// assert_eq!(StatusLine::new(24).begin(), "\n\x1B[1A\x1B7\x1B[1;23r\x1B8");
+    /// ```
+    pub fn begin(&self) -> String {
+        // The newline scrolls everything up a row if the cursor is already on the bottom one.
+        // Setting the scrolling area moves the cursor to the top, hence the save and restore.
+        format!("\n{}{}\x1B[1;{}r{}", move_up(1), save_cursor(), self.rows - 1, restore_cursor())
+    }
+
+    /// Show `text` on the status line, replacing what was there.
+    /// 
+    /// `text` should fit on one line, see [`truncate_visible`].
+    /// 
+    /// [`truncate_visible`]: ../fn.truncate_visible.html
+    pub fn update(&self, text: &str) -> String {
+        format!(
+            "{}{}{}{}{}",
+            save_cursor(),
+            move_to(self.rows - 1, 0),
+            erase_line(),
+            self.formatting.apply_to(text),
+            restore_cursor()
+        )
+    }
+
+    /// Clear the status line and give the bottom row back to normal output.
+    pub fn end(&self) -> String {
+        format!("{}\x1B[r{}{}{}", save_cursor(), move_to(self.rows - 1, 0), erase_line(), restore_cursor())
+    }
+}
@@ -0,0 +1,193 @@
+//! A readable form of escape codes, for test assertions and golden files.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::ansi::{self, Token};
+use crate::quantize::BASIC_COLORS;
+use crate::{parse, spec, Color};
+
+/// Codes that turn something off, and what they're called.
+const OFF_NAMES: [(&str, &str); 9] = [
+    ("22", "no_bold"),
+    ("23", "no_italic"),
+    ("24", "no_underline"),
+    ("25", "no_blink"),
+    ("27", "no_invert"),
+    ("28", "no_invisible"),
+    ("29", "no_strikethrough"),
+    ("54", "no_frame"),
+    ("55", "no_overline"),
+];
+
+/// Replace each color/style escape sequence in `text` with a readable tag, e.g. `\x1B[31;1m`
+/// becomes `<fg:red bold>` and `\x1B[0m` becomes `<reset>`.
+/// 
+/// Colors use the same names as [`Formatting::from_spec`] (`fg:256:120`, `bg:#ff00aa`), styles are
+/// written by name, and the codes that turn styles off are `no_bold`, `no_italic`, etc. Codes with
+/// no name are kept as numbers. `\x1B[m`, which also resets, becomes `<>`. A `<` in the text
+/// itself is doubled, and other escape sequences are left alone, so [`debug_parse`] can turn the
+/// result back into exactly the original text.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let text = format!("{} ok", "HI".red().bold());
+/// assert_eq!(debug_render(&text), "<fg:red bold>HI<reset> ok");
+/// assert_eq!(debug_parse(&debug_render(&text)), text);
+/// ```
+/// 
+/// [`Formatting::from_spec`]: struct.Formatting.html#method.from_spec
+/// [`debug_parse`]: fn.debug_parse.html
+pub fn debug_render(text: &str) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    for token in ansi::tokens(text) {
+        match token {
+            Token::Text(text) => rendered.push_str(&text.replace('<', "<<")),
+            Token::Escape(escape) => match escape.strip_prefix("\x1B[").and_then(|e| e.strip_suffix('m')) {
+                Some(params) => {
+                    rendered.push('<');
+                    rendered.push_str(&sgr_names(params).join(" "));
+                    rendered.push('>');
+                }
+                None => rendered.push_str(escape),
+            },
+        }
+    }
+    rendered
+}
+
+/// The reverse of [`debug_render`], turning tags back into escape sequences.
+/// 
+/// Tags it doesn't understand are left as they are.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// assert_eq!(debug_parse("<bg:blue italic>HI<reset>"), "\x1B[44;3mHI\x1B[0m");
+/// assert_eq!(debug_parse("a << b <nonsense>"), "a < b <nonsense>");
+/// ```
+/// 
+/// [`debug_render`]: fn.debug_render.html
+pub fn debug_parse(text: &str) -> String {
+    let mut parsed = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        parsed.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(after) = rest.strip_prefix("<<") {
+            parsed.push('<');
+            rest = after;
+            continue;
+        }
+        let tag = rest.find('>').map(|end| (&rest[1..end], end));
+        match tag.and_then(|(names, end)| Some((sgr_codes(names)?, end))) {
+            Some((codes, end)) => {
+                parsed.push_str("\x1B[");
+                parsed.push_str(&codes);
+                parsed.push('m');
+                rest = &rest[end + 1..];
+            }
+            None => {
+                parsed.push('<');
+                rest = &rest[1..];
+            }
+        }
+    }
+    parsed.push_str(rest);
+    parsed
+}
+
+/// The names for one SGR sequence's parameters, e.g. `["fg:red", "bold"]` for `"31;1"`.
+fn sgr_names(params: &str) -> Vec<String> {
+    if params.is_empty() {
+        return Vec::new();
+    }
+    let params: Vec<&str> = params.split(';').collect();
+    let mut names = Vec::new();
+    let mut i = 0;
+    while i < params.len() {
+        let (name, used) = param_name(&params[i..]);
+        names.push(name);
+        i += used;
+    }
+    names
+}
+
+/// The name for the parameter at the start of `params`, and how many parameters it used up.
+fn param_name(params: &[&str]) -> (String, usize) {
+    let param = params[0];
+    let extended = |target: &str| {
+        let number = |i: usize| params.get(i).and_then(|p| p.parse::<u8>().ok());
+        match params.get(1) {
+            Some(&"5") => number(2).map(|n| (format!("{}:256:{}", target, n), 3)),
+            Some(&"2") => match (number(2), number(3), number(4)) {
+                (Some(r), Some(g), Some(b)) => Some((format!("{}:#{:02x}{:02x}{:02x}", target, r, g, b), 5)),
+                _ => None,
+            },
+            _ => None,
+        }
+    };
+    let basic = |target: &str, index: usize| {
+        let mut name = format!("{}:", target);
+        let _ = spec::write_color(&mut name, BASIC_COLORS[index]);
+        Some((name, 1))
+    };
+
+    let named = match param.parse::<u8>() {
+        Ok(0) => Some(("reset".to_string(), 1)),
+        Ok(38) => extended("fg"),
+        Ok(48) => extended("bg"),
+        // Underline colors have no name, but their arguments mustn't be read as codes of their own.
+        Ok(58) => match params.get(1) {
+            Some(&"5") if params.len() >= 3 => Some((params[..3].join(";"), 3)),
+            Some(&"2") if params.len() >= 5 => Some((params[..5].join(";"), 5)),
+            _ => None,
+        },
+        Ok(39) => Some(("fg:default".to_string(), 1)),
+        Ok(49) => Some(("bg:default".to_string(), 1)),
+        Ok(code @ 30..=37) => basic("fg", (code - 30) as usize),
+        Ok(code @ 90..=97) => basic("fg", (code - 90 + 8) as usize),
+        Ok(code @ 40..=47) => basic("bg", (code - 40) as usize),
+        Ok(code @ 100..=107) => basic("bg", (code - 100 + 8) as usize),
+        _ => None,
+    };
+    named
+        .or_else(|| OFF_NAMES.iter().find(|(code, _)| *code == param).map(|(_, name)| (name.to_string(), 1)))
+        .or_else(|| parse::style_for(param).map(|style| (spec::style_name(style).to_string(), 1)))
+        .unwrap_or_else(|| (param.to_string(), 1))
+}
+
+/// The parameters for a tag's names, e.g. `"31;1"` for `"fg:red bold"`, or `None` if any of them
+/// isn't understood.
+fn sgr_codes(names: &str) -> Option<String> {
+    let codes: Option<Vec<String>> = names.split_whitespace().map(param_code).collect();
+    Some(codes?.join(";"))
+}
+
+fn param_code(name: &str) -> Option<String> {
+    if name == "reset" {
+        return Some("0".to_string());
+    }
+    if let Some(color) = name.strip_prefix("fg:") {
+        return color.parse::<Color>().ok().map(|color| color.to_ansi_code());
+    }
+    if let Some(color) = name.strip_prefix("bg:") {
+        return color.parse::<Color>().ok().map(|color| color.to_ansi_bg_code());
+    }
+    if let Some((code, _)) = OFF_NAMES.iter().find(|(_, n)| *n == name) {
+        return Some(code.to_string());
+    }
+    if let Some(style) = spec::parse_style(name) {
+        return Some(style.to_ansi_code().to_string());
+    }
+    // Codes without names were written as numbers.
+    let numeric = !name.is_empty() && name.bytes().all(|b| b.is_ascii_digit() || b == b':' || b == b';');
+    numeric.then(|| name.to_string())
+}
@@ -0,0 +1,169 @@
+//! Banners, rules, and boxes for setting parts of the output apart.
+//! 
+//! Banners and rules fill the whole terminal width (or 80 columns if there's no terminal to ask or
+//! the `terminal-size` feature is off, see [`terminal_size`]). The `_with_width` versions take the
+//! width instead.
+//! 
+//! [`terminal_size`]: ../fn.terminal_size.html
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::text::{self, char_width};
+use crate::Formatting;
+
+/// The width used when there's no terminal to ask.
+const FALLBACK_WIDTH: usize = 80;
+
+/// `text` centered in a bar as wide as the terminal, e.g. for a section header.
+/// 
+/// The padding is part of the formatted text, so a background color fills the whole bar, even
+/// when the output is redirected.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::decor;
+/// 
+/// let header = Formatting::new().foreground(Color::Black).background(Color::Cyan).bold().build();
+/// println!("{}", decor::banner("RESULTS", &header));
+/// ```
+pub fn banner(text: &str, formatting: &Formatting) -> String {
+    banner_with_width(text, formatting, terminal_width())
+}
+
+/// Like [`banner`], but `width` columns wide.
+/// 
+/// Text that doesn't fit is left as it is rather than cut off.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::decor;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let header = Formatting::new().background(Color::Blue).build();
+/// assert_eq!(decor::banner_with_width("HI", &header, 8), "\x1B[44m   HI   \x1B[0m");
+/// ```
+/// 
+/// [`banner`]: fn.banner.html
+pub fn banner_with_width(text: &str, formatting: &Formatting, width: usize) -> String {
+    formatting.apply_to(&text::center(text, width))
+}
+
+/// A horizontal line of `c` as wide as the terminal.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::decor;
+/// 
+/// println!("{}", decor::hr('─', &Formatting::new().foreground(Color::BrightBlack)));
+/// ```
+pub fn hr(c: char, formatting: &Formatting) -> String {
+    hr_with_width(c, formatting, terminal_width())
+}
+
+/// Like [`hr`], but `width` columns wide.
+/// 
+/// Wide characters are repeated as often as fit, so the line may come up one column short.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::decor;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let rule = Formatting::new().foreground(Color::Red).build();
+/// assert_eq!(decor::hr_with_width('=', &rule, 5), "\x1B[31m=====\x1B[0m");
+/// ```
+/// 
+/// [`hr`]: fn.hr.html
+pub fn hr_with_width(c: char, formatting: &Formatting, width: usize) -> String {
+    let count = width / char_width(c).max(1);
+    let line: String = core::iter::repeat_n(c, count).collect();
+    formatting.apply_to(&line)
+}
+
+/// Which characters [`boxed`] draws its border with.
+/// 
+/// [`boxed`]: fn.boxed.html
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub enum BoxStyle {
+    /// `┌─┐`
+    #[default]
+    Light,
+    /// `┏━┓`
+    Heavy,
+    /// `╔═╗`
+    Double,
+    /// `╭─╮`
+    Rounded,
+    /// `+-+`, for terminals without box-drawing characters.
+    Ascii,
+}
+
+impl BoxStyle {
+    /// The top left, top right, bottom left, and bottom right corners, then the horizontal and
+    /// vertical lines.
+    fn chars(self) -> [char; 6] {
+        match self {
+            BoxStyle::Light => ['┌', '┐', '└', '┘', '─', '│'],
+            BoxStyle::Heavy => ['┏', '┓', '┗', '┛', '━', '┃'],
+            BoxStyle::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+            BoxStyle::Rounded => ['╭', '╮', '╰', '╯', '─', '│'],
+            BoxStyle::Ascii => ['+', '+', '+', '+', '-', '|'],
+        }
+    }
+}
+
+/// Draw a border around `text`, e.g. for a warning that shouldn't be missed.
+/// 
+/// `text` can have several lines and its own colors and styles. The box is as wide as the widest
+/// line, plus a space of padding on each side. `formatting` is only applied to the border.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::decor::{self, BoxStyle};
+/// 
+/// let text = format!("{}\nrun `cargo update`", "update available".bold());
+/// let boxed = decor::boxed(&text, BoxStyle::Rounded, &Formatting::new());
+/// assert_eq!(
+///     strip(&boxed),
+///     "╭────────────────────╮\n\
+///      │ update available   │\n\
+///      │ run `cargo update` │\n\
+///      ╰────────────────────╯"
+/// );
+/// ```
+pub fn boxed(text: &str, style: BoxStyle, formatting: &Formatting) -> String {
+    let [top_left, top_right, bottom_left, bottom_right, horizontal, vertical] = style.chars();
+    let border = |s: &str| if formatting.has_codes() { formatting.apply_to(s) } else { s.into() };
+    let lines = text::styled_lines(text);
+    let width = lines.iter().map(|line| text::visible_width(line)).max().unwrap_or(0);
+    let edge: String = core::iter::repeat_n(horizontal, width + 2).collect();
+    let vertical = border(vertical.encode_utf8(&mut [0; 4]));
+
+    let mut rows = Vec::with_capacity(lines.len() + 2);
+    rows.push(border(&format!("{}{}{}", top_left, edge, top_right)));
+    for line in &lines {
+        rows.push(format!("{} {} {}", vertical, text::pad_right(line, width), vertical));
+    }
+    rows.push(border(&format!("{}{}{}", bottom_left, edge, bottom_right)));
+    rows.join("\n")
+}
+
+pub(crate) fn terminal_width() -> usize {
+    #[cfg(feature = "terminal-size")]
+    if let Some((columns, _)) = crate::terminal_size() {
+        return columns as usize;
+    }
+    FALLBACK_WIDTH
+}
@@ -0,0 +1,76 @@
+//! A preview of every color and style, to see what your terminal can show.
+//! 
+//! This does programmatically what the `printf` advice in the [`Color`] and [`Styles`] docs does
+//! by hand.
+//! 
+//! [`Color`]: ../enum.Color.html
+//! [`Styles`]: ../enum.Styles.html
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+
+use crate::quantize::BASIC_COLORS;
+use crate::{text, Color, Formatting, Styles};
+
+/// The 16 basic colors, the 256 color palette, and samples of the styles, as one string to print.
+/// 
+/// Like everything else, this leaves out the escape codes when colors are turned off, so use
+/// `set_color_choice(ColorChoice::Always)` first if you want to pipe it into e.g. `less -R`.
+/// 
+/// # Example
+/// 
+/// ```
+/// println!("{}", coloring::demo::palette());
+/// ```
+pub fn palette() -> String {
+    let mut demo = String::new();
+    let heading = Formatting::new().styles([Styles::Bold, Styles::Underline]).build();
+
+    // Writing to a `String` can't fail.
+    let _ = writeln!(demo, "{}", heading.apply_to("Basic colors"));
+    for color in BASIC_COLORS {
+        let _ = writeln!(
+            demo,
+            "{:<15}{}  {}",
+            color,
+            Formatting::new().foreground(color).apply_to("Sample"),
+            swatch(color, "      ")
+        );
+    }
+
+    let _ = writeln!(demo, "\n{}", heading.apply_to("256 colors"));
+    for index in 0..16 {
+        demo.push_str(&swatch(Color::Colors256(index), &format!("{:>3} ", index)));
+    }
+    demo.push('\n');
+    // The 6x6x6 color cube, one row per level of red.
+    for row in 0..6 {
+        for column in 0..36 {
+            demo.push_str(&swatch(Color::Colors256(16 + row * 36 + column), "  "));
+        }
+        demo.push('\n');
+    }
+    for index in 232..=255 {
+        demo.push_str(&swatch(Color::Colors256(index), "  "));
+    }
+    demo.push('\n');
+
+    let _ = writeln!(demo, "\n{}", heading.apply_to("Styles"));
+    // Leave out `Reset`, which would show nothing, and the fonts, which almost nothing supports.
+    let samples: String = Styles::ALL[1..17]
+        .iter()
+        .map(|&style| format!("{} ", Formatting::new().add_style(style).apply_to(&style.to_string())))
+        .collect();
+    demo.push_str(&text::wrap(&samples, 80));
+    demo.push('\n');
+    demo
+}
+
+/// `text` on a `color` background, in black or white, whichever is easier to read.
+fn swatch(color: Color, text: &str) -> String {
+    let (r, g, b) = color.rgb().unwrap_or((0, 0, 0));
+    let light = 299 * r as u32 + 587 * g as u32 + 114 * b as u32 > 128_000;
+    let fg = if light { Color::Black } else { Color::BrightWhite };
+    Formatting::new().foreground(fg).background(color).apply_to(text)
+}
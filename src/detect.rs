@@ -0,0 +1,315 @@
+//! Figuring out what the current terminal can display.
+//! 
+//! This is a best guess based on `TERM`, `COLORTERM`, `COLORFGBG`, and whether stdout is a
+//! terminal at all.
+//! Terminals don't have to tell the truth about themselves, so if it matters, let your users
+//! override it.
+
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::env;
+#[cfg(feature = "std")]
+use std::io::{self, IsTerminal};
+
+#[cfg(feature = "std")]
+use crate::quantize::BASIC_COLORS;
+#[cfg(feature = "std")]
+use crate::Color;
+
+/// How many colors a terminal can show, from least to most capable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// No colors at all, e.g. output piped to a file or `TERM=dumb`.
+    NoColor,
+    /// The basic 16 colors (`Color::Red`, `Color::BrightRed`, etc.).
+    Basic,
+    /// The 256 color palette (`Color::Colors256`).
+    Ansi256,
+    /// 24-bit color (`Color::RGB`).
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// The depth a `FORCE_COLOR` value asks for, the way Node.js and the `supports-color` family
+    /// read it: `0` or `false` is `NoColor`, `2` is `Ansi256`, `3` (or more) is `TrueColor`, and
+    /// anything else (including `1`, `true`, and empty) is `Basic`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::detect::ColorDepth;
+    /// 
+    /// assert_eq!(ColorDepth::from_force_color("3"), ColorDepth::TrueColor);
+    /// assert_eq!(ColorDepth::from_force_color(""), ColorDepth::Basic);
+    /// ```
+    pub fn from_force_color(value: &str) -> ColorDepth {
+        match value.trim() {
+            "0" | "false" => ColorDepth::NoColor,
+            "2" => ColorDepth::Ansi256,
+            level if level.parse::<u32>().is_ok_and(|level| level >= 3) => ColorDepth::TrueColor,
+            _ => ColorDepth::Basic,
+        }
+    }
+}
+
+/// What the current terminal supports.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::detect::{ColorDepth, TerminalCaps};
+/// 
+/// let color = match TerminalCaps::detect().depth {
+///     ColorDepth::TrueColor => Color::RGB { r: 255, g: 135, b: 0 },
+///     ColorDepth::Ansi256 => Color::Colors256(208),
+///     _ => Color::Yellow,
+/// };
+/// println!("{}", Formatting::new().foreground(color).apply_to("HI MOM"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TerminalCaps {
+    /// Whether stdout is a terminal.
+    pub is_terminal: bool,
+    /// The richest colors the terminal claims to support.
+    pub depth: ColorDepth,
+}
+
+impl TerminalCaps {
+    /// Inspect the environment and stdout.
+    /// 
+    /// `FORCE_COLOR` wins over everything else, see [`ColorDepth::from_force_color`].
+    /// 
+    /// With the `windows` feature on Windows, this also tries [`enable_ansi`]. If that fails the
+    /// console can't show escape codes, so you get `ColorDepth::NoColor`. If it works but `TERM`
+    /// isn't set (the usual case there), you get `ColorDepth::TrueColor`, which Windows 10
+    /// consoles support.
+    /// 
+    /// [`enable_ansi`]: ../windows/fn.enable_ansi.html
+    /// [`ColorDepth::from_force_color`]: enum.ColorDepth.html#method.from_force_color
+    #[cfg(feature = "std")]
+    pub fn detect() -> TerminalCaps {
+        let term = env::var("TERM").ok();
+        let colorterm = env::var("COLORTERM").ok();
+        let is_terminal = io::stdout().is_terminal();
+
+        if let Ok(force) = env::var("FORCE_COLOR") {
+            return TerminalCaps { is_terminal, depth: ColorDepth::from_force_color(&force) };
+        }
+
+        #[cfg(all(windows, feature = "windows"))]
+        {
+            if crate::windows::enable_ansi().is_err() {
+                return TerminalCaps { is_terminal, depth: ColorDepth::NoColor };
+            }
+            if term.is_none() {
+                let depth = if is_terminal { ColorDepth::TrueColor } else { ColorDepth::NoColor };
+                return TerminalCaps { is_terminal, depth };
+            }
+        }
+
+        TerminalCaps::from_vars(term.as_deref(), colorterm.as_deref(), is_terminal)
+    }
+
+    /// Work out the capabilities from the given values of `TERM` and `COLORTERM`,
+    /// rather than reading them from the environment.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::detect::{ColorDepth, TerminalCaps};
+    /// 
+    /// let caps = TerminalCaps::from_vars(Some("xterm-256color"), None, true);
+    /// assert_eq!(caps.depth, ColorDepth::Ansi256);
+    /// ```
+    pub fn from_vars(term: Option<&str>, colorterm: Option<&str>, is_terminal: bool) -> TerminalCaps {
+        let depth = if is_terminal {
+            TerminalCaps::depth_from_vars(term, colorterm)
+        } else {
+            ColorDepth::NoColor
+        };
+        TerminalCaps { is_terminal, depth }
+    }
+
+    /// Whether colors of the given depth will display properly.
+    pub fn supports(&self, depth: ColorDepth) -> bool {
+        depth <= self.depth
+    }
+
+    fn depth_from_vars(term: Option<&str>, colorterm: Option<&str>) -> ColorDepth {
+        let term = term.unwrap_or("");
+        if term.is_empty() || term == "dumb" {
+            return ColorDepth::NoColor;
+        }
+        if matches!(colorterm, Some("truecolor") | Some("24bit")) {
+            return ColorDepth::TrueColor;
+        }
+        if term.contains("truecolor") || term.contains("24bit") || term.contains("direct") {
+            ColorDepth::TrueColor
+        } else if term.contains("256") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Basic
+        }
+    }
+}
+
+/// Whether a terminal's background is light or dark, e.g. for picking an [`AdaptiveColor`].
+/// 
+/// [`AdaptiveColor`]: ../struct.AdaptiveColor.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Background {
+    Light,
+    Dark,
+}
+
+impl Background {
+    /// Read a `COLORFGBG` value, like `"15;0"` or `"0;default;15"`, which some terminals (rxvt,
+    /// Konsole, iTerm2 and others) set to the palette indexes of their text and background.
+    /// 
+    /// The background is the last index. 7 (white) and 9 to 15 (the bright colors but bright
+    /// black) are light, and the rest are dark. Returns `None` if the background isn't a number.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::detect::Background;
+    /// 
+    /// assert_eq!(Background::from_colorfgbg("15;0"), Some(Background::Dark));
+    /// assert_eq!(Background::from_colorfgbg("0;default;15"), Some(Background::Light));
+    /// assert_eq!(Background::from_colorfgbg("default;default"), None);
+    /// ```
+    pub fn from_colorfgbg(value: &str) -> Option<Background> {
+        match colorfgbg_background(value)? {
+            7 | 9..=15 => Some(Background::Light),
+            _ => Some(Background::Dark),
+        }
+    }
+
+    /// Whether `color` is a light or a dark background, by whether black or white text is easier
+    /// to read on it (see [`Color::contrast_fg`]). `None` for `Color::Default`.
+    /// 
+    /// Needs the `std` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// use coloring::detect::Background;
+    /// 
+    /// assert_eq!(Background::from_color(Color::RGB { r: 253, g: 246, b: 227 }), Some(Background::Light));
+    /// assert_eq!(Background::from_color(Color::Blue), Some(Background::Dark));
+    /// ```
+    /// 
+    /// [`Color::contrast_fg`]: ../enum.Color.html#method.contrast_fg
+    #[cfg(feature = "std")]
+    pub fn from_color(color: Color) -> Option<Background> {
+        match color.contrast_fg() {
+            Color::Black => Some(Background::Light),
+            Color::Default => None,
+            _ => Some(Background::Dark),
+        }
+    }
+}
+
+/// The background's palette index in a `COLORFGBG` value.
+fn colorfgbg_background(value: &str) -> Option<u8> {
+    value.rsplit(';').next()?.trim().parse().ok()
+}
+
+/// Guess whether the terminal's background is light or dark.
+/// 
+/// With the `probe` feature, this first asks the terminal for its background color (see
+/// [`background_color`]). Otherwise, or if it doesn't answer, it goes by `COLORFGBG` (see
+/// [`Background::from_colorfgbg`]). Returns `None` when there's no way to tell. Dark is the safer
+/// guess in that case.
+/// 
+/// [`background_color`]: fn.background_color.html
+/// [`Background::from_colorfgbg`]: enum.Background.html#method.from_colorfgbg
+#[cfg(feature = "std")]
+pub fn background() -> Option<Background> {
+    #[cfg(feature = "probe")]
+    if let Some(color) = probed_background() {
+        return Background::from_color(color);
+    }
+    env::var("COLORFGBG").ok().and_then(|value| Background::from_colorfgbg(&value))
+}
+
+/// The terminal's background color.
+/// 
+/// With the `probe` feature, this asks the terminal with OSC 11, waiting up to 100ms for an
+/// answer (see [`probe::background_color`]). Otherwise, or if it doesn't answer, this is the
+/// palette color `COLORFGBG` names, if it's set. Nothing is cached, so each call asks again.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let background = detect::background_color().unwrap_or(Color::Black);
+/// let text = Color::Yellow.darken(0.3);
+/// if text.contrast_ratio(&background).is_some_and(|ratio| ratio < 3.0) {
+///     println!("that won't be easy to read");
+/// }
+/// ```
+/// 
+/// [`probe::background_color`]: ../probe/fn.background_color.html
+#[cfg(feature = "std")]
+pub fn background_color() -> Option<Color> {
+    #[cfg(feature = "probe")]
+    if let Some(color) = probed_background() {
+        return Some(color);
+    }
+    let index = colorfgbg_background(&env::var("COLORFGBG").ok()?)?;
+    Some(BASIC_COLORS.get(index as usize).copied().unwrap_or(Color::Colors256(index)))
+}
+
+#[cfg(feature = "probe")]
+fn probed_background() -> Option<Color> {
+    crate::probe::background_color(core::time::Duration::from_millis(100)).ok().flatten()
+}
+
+/// Whether the terminal on stdout is likely to show [OSC 8 hyperlinks] as clickable links.
+/// 
+/// Terminals don't advertise this, so it's a guess based on environment variables that known
+/// terminals set (iTerm2, WezTerm, kitty, Windows Terminal, VTE-based ones like GNOME Terminal,
+/// and others). `FORCE_HYPERLINK=1` or `FORCE_HYPERLINK=0` overrides the guess.
+/// 
+/// [OSC 8 hyperlinks]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+#[cfg(feature = "std")]
+pub fn supports_hyperlinks() -> bool {
+    io::stdout().is_terminal() && supports_hyperlinks_with(|name| env::var(name).ok())
+}
+
+/// Like [`supports_hyperlinks`], but getting environment variables from `var` instead of the
+/// environment, and without checking whether stdout is a terminal.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::detect::supports_hyperlinks_with;
+/// 
+/// assert!(supports_hyperlinks_with(|name| (name == "TERM_PROGRAM").then(|| "WezTerm".to_string())));
+/// assert!(!supports_hyperlinks_with(|_| None));
+/// ```
+/// 
+/// [`supports_hyperlinks`]: fn.supports_hyperlinks.html
+pub fn supports_hyperlinks_with<F: Fn(&str) -> Option<String>>(var: F) -> bool {
+    if let Some(force) = var("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+    if var("WT_SESSION").is_some() || var("KITTY_WINDOW_ID").is_some() || var("DOMTERM").is_some() {
+        return true;
+    }
+    if var("VTE_VERSION").and_then(|version| version.parse::<u32>().ok()).is_some_and(|version| version >= 5000) {
+        return true;
+    }
+    if let Some(program) = var("TERM_PROGRAM") {
+        if matches!(program.as_str(), "iTerm.app" | "WezTerm" | "vscode" | "Hyper" | "ghostty") {
+            return true;
+        }
+    }
+    var("TERM").is_some_and(|term| {
+        ["kitty", "alacritty", "foot", "ghostty", "wezterm"].iter().any(|name| term.contains(name))
+    })
+}
@@ -0,0 +1,77 @@
+//! Multicolored effects, like rainbows.
+//! 
+//! These color each character separately with `Color::RGB`, so they need a terminal with
+//! truecolor support to look right.
+
+use alloc::string::{String, ToString};
+
+use crate::ansi::{self, Token};
+use crate::{control, quantize, Color, Formatting};
+
+/// Color each character with a hue that cycles around the color wheel.
+/// 
+/// `frequency` is how many degrees the hue turns per character, so `10.0` takes 36 characters to
+/// go all the way around. Each line starts back at red. Whitespace isn't colored, and escape
+/// sequences already in the text are passed through as is.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::effects;
+/// 
+/// println!("{}", effects::rainbow("HI MOM! HOW ARE YOU?", 18.0));
+/// ```
+pub fn rainbow(text: &str, frequency: f32) -> String {
+    lolcat(text, frequency, 0.0)
+}
+
+/// Like [`rainbow`], but each line starts `spread` characters further along the color wheel than
+/// the previous one, giving the diagonal stripes that `lolcat` is known for.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::effects;
+/// 
+/// println!("{}", effects::lolcat("HI MOM!\nHOW ARE YOU?\nI AM FINE.", 12.0, 3.0));
+/// ```
+/// 
+/// [`rainbow`]: fn.rainbow.html
+pub fn lolcat(text: &str, frequency: f32, spread: f32) -> String {
+    if !control::should_colorize(None) {
+        return text.to_string();
+    }
+    let mut colored = String::with_capacity(text.len() * 20);
+    let mut line = 0;
+    let mut column = 0;
+    let mut any_colored = false;
+    for token in ansi::tokens(text) {
+        let text = match token {
+            Token::Escape(escape) => {
+                colored.push_str(escape);
+                continue;
+            }
+            Token::Text(text) => text,
+        };
+        for c in text.chars() {
+            if c == '\n' {
+                line += 1;
+                column = 0;
+            } else {
+                if !c.is_whitespace() {
+                    let hue = (column as f32 + line as f32 * spread) * frequency;
+                    let (r, g, b) = quantize::hsv_to_rgb(hue, 1.0, 1.0);
+                    // Writing to a `String` can't fail.
+                    let _ = Formatting::new().foreground(Color::RGB { r, g, b }).write_prefix(&mut colored);
+                    any_colored = true;
+                }
+                column += 1;
+            }
+            colored.push(c);
+        }
+    }
+    if any_colored {
+        colored.push_str("\x1B[0m");
+    }
+    colored
+}
@@ -0,0 +1,66 @@
+//! `Error`, for the `try_*` functions.
+
+use core::error;
+use core::fmt;
+
+use crate::{ParseColorError, ParseSpecError, Styles};
+
+/// Everything that can go wrong in the crate's fallible functions, like [`Color::try_cube`] and
+/// [`Formatting::try_from_spec`].
+/// 
+/// The parse errors convert into this with `?`, so one `Result<_, coloring::Error>` can cover
+/// reading colors, specs, and checking the result. For those, `Display` says what was being
+/// parsed and [`source`] has the details.
+/// 
+/// [`source`]: #method.source
+/// [`Color::try_cube`]: enum.Color.html#method.try_cube
+/// [`Formatting::try_from_spec`]: struct.Formatting.html#method.try_from_spec
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A string isn't a color.
+    ParseColor(ParseColorError),
+    /// A string isn't a spec [`Formatting::from_spec`] understands.
+    /// 
+    /// [`Formatting::from_spec`]: struct.Formatting.html#method.from_spec
+    ParseSpec(ParseSpecError),
+    /// A number is bigger than it's allowed to be, e.g. a color cube channel of 6.
+    OutOfRange { what: &'static str, value: u8, max: u8 },
+    /// Two styles that can't both show at once, see [`Formatting::validate`].
+    /// 
+    /// [`Formatting::validate`]: struct.Formatting.html#method.validate
+    ConflictingStyles(Styles, Styles),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ParseColor(_) => f.write_str("couldn't parse a color"),
+            Error::ParseSpec(_) => f.write_str("couldn't parse a style spec"),
+            Error::OutOfRange { what, value, max } => write!(f, "{} {} is out of range (0 to {})", what, value, max),
+            Error::ConflictingStyles(a, b) => write!(f, "conflicting styles: {} and {}", a, b),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::ParseColor(error) => Some(error),
+            Error::ParseSpec(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseColorError> for Error {
+    fn from(error: ParseColorError) -> Error {
+        Error::ParseColor(error)
+    }
+}
+
+impl From<ParseSpecError> for Error {
+    fn from(error: ParseSpecError) -> Error {
+        Error::ParseSpec(error)
+    }
+}
@@ -0,0 +1,96 @@
+//! A fixed-size buffer for a single escape sequence.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// Enough for the longest prefix: two RGB colors plus every style.
+const CAPACITY: usize = 128;
+
+/// An escape sequence built without allocating, returned by [`prefix`] and [`suffix`].
+/// 
+/// Derefs to `&str`, and displays as the escape sequence itself.
+/// 
+/// [`prefix`]: struct.Formatting.html#method.prefix
+/// [`suffix`]: struct.Formatting.html#method.suffix
+#[derive(Clone, Copy)]
+pub struct Escape {
+    buffer: [u8; CAPACITY],
+    len: usize,
+}
+
+impl Escape {
+    pub(crate) const fn new() -> Escape {
+        Escape { buffer: [0; CAPACITY], len: 0 }
+    }
+
+    /// The escape sequence, empty if there's nothing to write.
+    pub fn as_str(&self) -> &str {
+        // Only whole `&str`s are ever copied in, so this can't split a character.
+        core::str::from_utf8(&self.buffer[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for Escape {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > CAPACITY {
+            return Err(fmt::Error);
+        }
+        self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl Deref for Escape {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Escape {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Escape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Escape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for Escape {
+    fn eq(&self, other: &Escape) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Escape {}
+
+impl PartialEq<str> for Escape {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Escape {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Hash for Escape {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
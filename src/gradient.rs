@@ -0,0 +1,64 @@
+//! Smoothly blending from one color to another across text.
+
+use alloc::string::{String, ToString};
+
+use crate::{control, quantize, Color, Formatting};
+
+/// A foreground color gradient, interpolated in RGB one character at a time.
+/// 
+/// Palette colors (e.g. `Color::Red`) are converted to RGB using xterm's default values, and
+/// `Color::Default` counts as white. This needs a terminal with truecolor support to look right.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::gradient::Gradient;
+/// 
+/// let gradient = Gradient::new(Color::RGB { r: 255, g: 0, b: 128 }, Color::RGB { r: 0, g: 128, b: 255 });
+/// println!("{}", gradient.apply_to("HI MOM! HOW ARE YOU?"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Gradient {
+    start: (u8, u8, u8),
+    end: (u8, u8, u8),
+}
+
+impl Gradient {
+    /// Create a gradient going from `start` to `end`.
+    pub fn new(start: Color, end: Color) -> Gradient {
+        let rgb = |color: Color| color.rgb().unwrap_or((255, 255, 255));
+        Gradient { start: rgb(start), end: rgb(end) }
+    }
+
+    /// The color at `t`, where `0.0` is the start and `1.0` is the end.
+    pub fn at(&self, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| quantize::round_u8(a as f32 + (b as f32 - a as f32) * t);
+        Color::RGB {
+            r: lerp(self.start.0, self.end.0),
+            g: lerp(self.start.1, self.end.1),
+            b: lerp(self.start.2, self.end.2),
+        }
+    }
+
+    /// Color each character of `text` with its position along the gradient.
+    /// 
+    /// Whitespace isn't colored, but still counts towards the position.
+    pub fn apply_to(&self, text: &str) -> String {
+        if !control::should_colorize(None) {
+            return text.to_string();
+        }
+        let steps = text.chars().count().saturating_sub(1).max(1) as f32;
+        let mut colored = String::with_capacity(text.len() * 20);
+        for (i, c) in text.chars().enumerate() {
+            if !c.is_whitespace() {
+                // Writing to a `String` can't fail.
+                let _ = Formatting::new().foreground(self.at(i as f32 / steps)).write_prefix(&mut colored);
+            }
+            colored.push(c);
+        }
+        colored.push_str("\x1B[0m");
+        colored
+    }
+}
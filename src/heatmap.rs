@@ -0,0 +1,93 @@
+//! Coloring numbers by how big they are, from cool for the smallest to hot for the biggest.
+//! 
+//! [`heatmap`] turns a series of numbers into colors, and [`sparkline`] draws them as a tiny bar
+//! chart of block characters, like the other charts in the [`charts`] module. Like [`Gradient`],
+//! the colors are RGB, so they need a terminal with truecolor support to look right.
+//! 
+//! [`heatmap`]: fn.heatmap.html
+//! [`sparkline`]: fn.sparkline.html
+//! [`charts`]: ../charts/index.html
+//! [`Gradient`]: ../gradient/struct.Gradient.html
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::gradient::Gradient;
+use crate::Color;
+
+/// A color for each of `values`, from green for the smallest to red for the biggest, see
+/// [`heatmap_with`].
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let latencies = [12.0, 48.0, 30.0];
+/// for (ms, color) in latencies.iter().zip(heatmap::heatmap(&latencies)) {
+///     println!("{}", Formatting::new().foreground(color).apply_to(&format!("{}ms", ms)));
+/// }
+/// ```
+/// 
+/// [`heatmap_with`]: fn.heatmap_with.html
+pub fn heatmap(values: &[f64]) -> Vec<Color> {
+    heatmap_with(values, &default_ramp())
+}
+
+/// A color for each of `values`, along `ramp`: the smallest value gets the start of the ramp, the
+/// biggest gets the end, and the rest are in between.
+/// 
+/// If all the values are the same, they all get the start. Values that aren't finite, like
+/// `f64::NAN`, don't count towards the smallest and biggest, and get `Color::Default`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::gradient::Gradient;
+/// 
+/// let ramp = Gradient::new(Color::RGB { r: 0, g: 0, b: 0 }, Color::RGB { r: 200, g: 100, b: 0 });
+/// assert_eq!(
+///     heatmap::heatmap_with(&[1.0, 3.0, 2.0, f64::NAN], &ramp),
+///     [
+///         Color::RGB { r: 0, g: 0, b: 0 },
+///         Color::RGB { r: 200, g: 100, b: 0 },
+///         Color::RGB { r: 100, g: 50, b: 0 },
+///         Color::Default,
+///     ]
+/// );
+/// ```
+pub fn heatmap_with(values: &[f64], ramp: &Gradient) -> Vec<Color> {
+    positions(values).map(|t| t.map_or(Color::Default, |t| ramp.at(t as f32))).collect()
+}
+
+/// `values` as a line of block characters, one per value, taller and hotter for bigger values,
+/// e.g. `▁▃▅█▂`. The same as [`charts::sparkline`].
+/// 
+/// [`charts::sparkline`]: ../charts/fn.sparkline.html
+pub fn sparkline(values: &[f64]) -> String {
+    crate::charts::sparkline(values)
+}
+
+/// Green for the smallest values, through olive, to red for the biggest.
+pub(crate) fn default_ramp() -> Gradient {
+    Gradient::new(Color::RGB { r: 0, g: 175, b: 0 }, Color::RGB { r: 215, g: 0, b: 0 })
+}
+
+/// Where each of `values` is between the smallest and biggest of them, from `0.0` to `1.0`, or
+/// `None` if it isn't finite.
+pub(crate) fn positions(values: &[f64]) -> impl Iterator<Item = Option<f64>> + '_ {
+    let finite = values.iter().copied().filter(|value| value.is_finite());
+    let min = finite.clone().fold(f64::INFINITY, f64::min);
+    let max = finite.fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    values.iter().map(move |&value| {
+        if !value.is_finite() {
+            None
+        } else if range > 0.0 {
+            Some((value - min) / range)
+        } else {
+            Some(0.0)
+        }
+    })
+}
@@ -0,0 +1,90 @@
+//! Ready-made coloring for common kinds of text.
+
+use alloc::string::String;
+
+use crate::{Color, Formatting, Styles};
+
+const ADDED: Formatting = Formatting::new().with_foreground(Color::Green);
+const REMOVED: Formatting = Formatting::new().with_foreground(Color::Red);
+const HUNK: Formatting = Formatting::new().with_foreground(Color::Cyan);
+const HEADER: Formatting = Formatting::new().with_style(Styles::Bold);
+
+/// Color a unified diff (e.g. the output of `git diff`) the way `git` does.
+/// 
+/// Added lines (starting with `+`) are green, removed lines (`-`) are red, and hunk headers
+/// (`@@`) are cyan. File headers (`diff`, `index`, `+++`, and `---` lines) are bold. Everything
+/// else is left alone.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::helpers;
+/// 
+/// # let _color = coloring::override_scope(coloring::ColorChoice::Always);
+/// let diff = "@@ -1 +1 @@\n-old\n+new\n";
+/// assert_eq!(
+///     helpers::colorize_diff(diff),
+///     "\x1B[36m@@ -1 +1 @@\x1B[0m\n\x1B[31m-old\x1B[0m\n\x1B[32m+new\x1B[0m\n"
+/// );
+/// ```
+pub fn colorize_diff(text: &str) -> String {
+    let mut colored = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let content = line.strip_suffix('\n').unwrap_or(line);
+        let formatting = if ["diff ", "index ", "+++ ", "--- "].iter().any(|p| content.starts_with(p)) {
+            Some(&HEADER)
+        } else if content.starts_with("@@") {
+            Some(&HUNK)
+        } else if content.starts_with('+') {
+            Some(&ADDED)
+        } else if content.starts_with('-') {
+            Some(&REMOVED)
+        } else {
+            None
+        };
+        match formatting {
+            Some(formatting) if !content.is_empty() => {
+                colored.push_str(&formatting.apply_to(content));
+                colored.push_str(&line[content.len()..]);
+            }
+            _ => colored.push_str(line),
+        }
+    }
+    colored
+}
+
+/// Pick a `Formatting` by which band `value` falls in, e.g. to color latencies green, yellow, or
+/// red.
+/// 
+/// `thresholds` go from lowest to highest, and split the numbers into bands: below the first
+/// threshold gets `formats[0]`, below the second gets `formats[1]`, and so on, with anything at or
+/// above the last threshold getting the format after it. So there should be one more format than
+/// thresholds. If there are fewer, the bands past the end get the last format (or no formatting,
+/// if `formats` is empty).
+/// 
+/// Values that can't be compared, like `f64::NAN`, fall in the last band, so they stand out.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let latency = [
+///     Formatting::new().foreground(Color::Green).build(),
+///     Formatting::new().foreground(Color::Yellow).build(),
+///     Formatting::new().foreground(Color::Red).build(),
+/// ];
+/// for ms in [42, 180, 950] {
+///     let formatting = helpers::by_threshold(ms, &[100, 500], &latency);
+///     println!("GET /health {}", formatting.apply_to(&format!("{}ms", ms)));
+/// }
+/// assert_eq!(helpers::by_threshold(100, &[100, 500], &latency), latency[1]);
+/// assert_eq!(helpers::by_threshold(0.25, &[0.5], &latency), latency[0]);
+/// ```
+pub fn by_threshold<T: PartialOrd>(value: T, thresholds: &[T], formats: &[Formatting]) -> Formatting {
+    let band = thresholds.iter().position(|threshold| value < *threshold).unwrap_or(thresholds.len());
+    match formats.get(band).or_else(|| formats.last()) {
+        Some(formatting) => formatting.clone(),
+        None => Formatting::new(),
+    }
+}
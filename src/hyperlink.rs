@@ -0,0 +1,82 @@
+//! Clickable links in terminal output, with OSC 8 escape sequences.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::sync::atomic::{AtomicU8, Ordering};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::ColorChoice;
+#[cfg(feature = "std")]
+use crate::{control, detect, Stream};
+
+static CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+
+/// Turn `text` into a link to `url`, for terminals that support [OSC 8 hyperlinks].
+/// 
+/// Terminals that don't know about them are supposed to just show the text, but some older ones
+/// show garbage instead. So by default (`ColorChoice::Auto`, see [`set_hyperlink_choice`]) links
+/// are only written when [`detect::supports_hyperlinks`] thinks the terminal can handle them and
+/// colors are enabled for stdout. Otherwise you just get `text` back. Without the `std` feature
+/// there's no way to tell, so links are left out unless you choose `ColorChoice::Always`.
+/// 
+/// `text` can have colors and styles of its own.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// set_hyperlink_choice(ColorChoice::Always);
+/// let link = hyperlink("docs", "https://docs.rs");
+/// assert_eq!(link, "\x1B]8;;https://docs.rs\x1B\\docs\x1B]8;;\x1B\\");
+/// set_hyperlink_choice(ColorChoice::Auto);
+/// ```
+/// 
+/// [OSC 8 hyperlinks]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+/// [`set_hyperlink_choice`]: fn.set_hyperlink_choice.html
+/// [`detect::supports_hyperlinks`]: detect/fn.supports_hyperlinks.html
+pub fn hyperlink(text: &str, url: &str) -> String {
+    if !hyperlinks_enabled() {
+        return text.to_string();
+    }
+    // Control characters in the URL would end the escape sequence early.
+    let url: String = url.chars().filter(|c| !c.is_control()).collect();
+    format!("\x1B]8;;{}\x1B\\{}\x1B]8;;\x1B\\", url, text)
+}
+
+/// Set whether [`hyperlink`] writes links, for the whole program.
+/// 
+/// `ColorChoice::Never` turns them off for terminals that show them wrong, and
+/// `ColorChoice::Always` turns them on even when they don't look supported.
+/// 
+/// [`hyperlink`]: fn.hyperlink.html
+pub fn set_hyperlink_choice(choice: ColorChoice) {
+    CHOICE.store(choice as u8, Ordering::Relaxed);
+}
+
+/// The current choice for [`hyperlink`], `ColorChoice::Auto` unless it's been set.
+/// 
+/// [`hyperlink`]: fn.hyperlink.html
+pub fn hyperlink_choice() -> ColorChoice {
+    ColorChoice::from_u8(CHOICE.load(Ordering::Relaxed))
+}
+
+fn hyperlinks_enabled() -> bool {
+    match hyperlink_choice() {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => auto_hyperlinks(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn auto_hyperlinks() -> bool {
+    static SUPPORTED: OnceLock<bool> = OnceLock::new();
+    control::colors_enabled_for(Stream::Stdout) && *SUPPORTED.get_or_init(detect::supports_hyperlinks)
+}
+
+#[cfg(not(feature = "std"))]
+fn auto_hyperlinks() -> bool {
+    false
+}
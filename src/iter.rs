@@ -0,0 +1,131 @@
+//! Styling each item of an iterator as it goes by.
+//! 
+//! [`StyleWith::style_with`] picks a `Formatting` for each line and yields the styled lines one
+//! at a time, so a huge file or a long-running command's output never has to be held in memory
+//! all at once.
+//! 
+//! [`StyleWith::style_with`]: trait.StyleWith.html#method.style_with
+
+use alloc::string::String;
+use core::iter::FusedIterator;
+
+use crate::Formatting;
+
+/// The iterator returned by [`StyleWith::style_with`].
+/// 
+/// [`StyleWith::style_with`]: trait.StyleWith.html#method.style_with
+#[derive(Clone, Debug)]
+pub struct StyledLines<I, F> {
+    lines: I,
+    formatting_for: F,
+}
+
+impl<I, F> Iterator for StyledLines<I, F>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    F: FnMut(&str) -> Formatting,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let line = self.lines.next()?;
+        Some(style(&mut self.formatting_for, line.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for StyledLines<I, F>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<str>,
+    F: FnMut(&str) -> Formatting,
+{
+    fn next_back(&mut self) -> Option<String> {
+        let line = self.lines.next_back()?;
+        Some(style(&mut self.formatting_for, line.as_ref()))
+    }
+}
+
+impl<I, F> ExactSizeIterator for StyledLines<I, F>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+    F: FnMut(&str) -> Formatting,
+{
+}
+
+impl<I, F> FusedIterator for StyledLines<I, F>
+where
+    I: FusedIterator,
+    I::Item: AsRef<str>,
+    F: FnMut(&str) -> Formatting,
+{
+}
+
+fn style(formatting_for: &mut impl FnMut(&str) -> Formatting, line: &str) -> String {
+    let formatting = formatting_for(line);
+    if formatting.has_codes() {
+        formatting.apply_to(line)
+    } else {
+        String::from(line)
+    }
+}
+
+/// Adds [`style_with`] to every iterator over strings.
+/// 
+/// [`style_with`]: trait.StyleWith.html#method.style_with
+pub trait StyleWith: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Style each item with the `Formatting` that `formatting_for` picks for it.
+    /// 
+    /// Nothing happens until the items are asked for. Items given a `Formatting` with no colors or
+    /// styles, like `Formatting::new()`, are left plain.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// use coloring::iter::StyleWith;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let log = "INFO started\nERROR disk full\nINFO done";
+    /// let error = Formatting::new().foreground(Color::Red).build();
+    /// let mut lines = log.lines().style_with(|line| {
+    ///     if line.starts_with("ERROR") { error.clone() } else { Formatting::new() }
+    /// });
+    /// assert_eq!(lines.nth(1).unwrap(), "\x1B[31mERROR disk full\x1B[0m");
+    /// ```
+    /// 
+    /// With a file, skip over (or handle) read errors first:
+    /// 
+    /// ```no_run
+    /// use std::io::{BufRead, BufReader};
+    /// use coloring::*;
+    /// use coloring::iter::StyleWith;
+    /// 
+    /// let file = BufReader::new(std::fs::File::open("build.log").unwrap());
+    /// let warning = Formatting::new().foreground(Color::Yellow).build();
+    /// let lines = file.lines().map_while(Result::ok).style_with(|line| {
+    ///     if line.contains("warning") { warning.clone() } else { Formatting::new() }
+    /// });
+    /// for line in lines {
+    ///     println!("{}", line);
+    /// }
+    /// ```
+    fn style_with<F: FnMut(&str) -> Formatting>(self, formatting_for: F) -> StyledLines<Self, F> {
+        StyledLines { lines: self, formatting_for }
+    }
+}
+
+impl<I> StyleWith for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+}
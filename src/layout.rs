@@ -0,0 +1,143 @@
+//! Arranging styled text on the screen: cells in a grid of columns, like `ls` does with file
+//! names, or two texts side by side.
+//! 
+//! Widths only count the visible text, not the escape codes, so colored cells line up. The
+//! functions without `_with_width` fill the terminal width (or 80 columns if there's no terminal
+//! to ask or the `terminal-size` feature is off, see [`terminal_size`]).
+//! 
+//! [`terminal_size`]: ../fn.terminal_size.html
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::decor::terminal_width;
+use crate::text::{self, visible_width};
+
+/// What goes between columns.
+const GAP: usize = 2;
+
+/// What goes between the two sides of [`side_by_side`].
+const DIVIDER: &str = " │ ";
+
+/// `cells` in as many columns as fit in the terminal, see [`grid_with_width`].
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let files = ["Cargo.toml".to_string(), "src".blue().bold().to_string(), "README.md".to_string()];
+/// print!("{}", layout::grid(&files));
+/// ```
+/// 
+/// [`grid_with_width`]: fn.grid_with_width.html
+pub fn grid<I, S>(cells: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    grid_with_width(cells, terminal_width())
+}
+
+/// `cells` in as many columns as fit in `width` columns, with a newline after every row.
+/// 
+/// Like `ls`, the cells go down the first column, then the next, and so on. Each column is as
+/// wide as its widest cell, with two spaces between columns. A cell wider than `width` gets a
+/// line of its own.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let tags = ["a", "bb", "ccc", "d", "ee"];
+/// assert_eq!(layout::grid_with_width(tags, 10), "a    d\nbb   ee\nccc\n");
+/// 
+/// let tags = ["ok".green().to_string(), "slow".yellow().to_string()];
+/// assert_eq!(layout::grid_with_width(&tags, 20), "\x1B[32mok\x1B[0m  \x1B[33mslow\x1B[0m\n");
+/// ```
+pub fn grid_with_width<I, S>(cells: I, width: usize) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let cells: Vec<S> = cells.into_iter().collect();
+    let widths: Vec<usize> = cells.iter().map(|cell| visible_width(cell.as_ref())).collect();
+    let (rows, column_widths) = fit_columns(&widths, width);
+
+    let mut grid = String::new();
+    for row in 0..rows {
+        let mut column = 0;
+        let mut cells_in_row = (row..cells.len()).step_by(rows.max(1)).peekable();
+        while let Some(i) = cells_in_row.next() {
+            grid.push_str(cells[i].as_ref());
+            // Don't leave trailing spaces at the end of the line.
+            if cells_in_row.peek().is_some() {
+                grid.extend(core::iter::repeat_n(' ', column_widths[column] - widths[i] + GAP));
+            }
+            column += 1;
+        }
+        grid.push('\n');
+    }
+    grid
+}
+
+/// The fewest rows that fit cells of `widths` into `width` columns, and how wide each column is.
+fn fit_columns(widths: &[usize], width: usize) -> (usize, Vec<usize>) {
+    for rows in 1..widths.len() {
+        let column_widths: Vec<usize> =
+            widths.chunks(rows).map(|column| column.iter().copied().max().unwrap_or(0)).collect();
+        let total = column_widths.iter().sum::<usize>() + GAP * (column_widths.len() - 1);
+        if total <= width {
+            return (rows, column_widths);
+        }
+    }
+    let widest = widths.iter().copied().max().unwrap_or(0);
+    (widths.len(), Vec::from([widest]))
+}
+
+/// `left` and `right` next to each other, `width` columns wide in total, e.g. to compare expected
+/// and actual output.
+/// 
+/// Each side gets half of the width (minus a `│` divider between them) and is [`wrap`]ped to
+/// fit, so colors and styles carry over from line to line without bleeding into the other side.
+/// Lines line up from the top, and the shorter side is padded with blank lines. There's a newline
+/// after every line.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let expected = "name = \"app\"\nversion = 1";
+/// let actual = format!("name = \"app\"\nversion = {}", "2".red());
+/// assert_eq!(
+///     layout::side_by_side(expected, &actual, 31),
+///     "name = \"app\"   │ name = \"app\"\nversion = 1    │ version = \x1B[31m2\x1B[0m\n"
+/// );
+/// ```
+/// 
+/// [`wrap`]: ../fn.wrap.html
+pub fn side_by_side(left: &str, right: &str, width: usize) -> String {
+    let column_width = (width.saturating_sub(visible_width(DIVIDER)) / 2).max(1);
+    let left = text::styled_lines(&text::wrap(left, column_width));
+    let right = text::styled_lines(&text::wrap(right, column_width));
+
+    let mut output = String::new();
+    for i in 0..left.len().max(right.len()) {
+        let left = left.get(i).map_or("", String::as_str);
+        let right = right.get(i).map_or("", String::as_str);
+        output.push_str(&text::pad_right(left, column_width));
+        if right.is_empty() {
+            // Don't leave trailing spaces at the end of the line.
+            output.push_str(DIVIDER.trim_end());
+        } else {
+            output.push_str(DIVIDER);
+            output.push_str(right);
+        }
+        output.push('\n');
+    }
+    output
+}
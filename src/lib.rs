@@ -18,9 +18,147 @@
 //! ```
 //! use coloring::*;
 //! 
-//! let formatted_text = Formatting::new().foreground(Color::Green).styles(vec![Styles::Bold, Styles::Blink]).apply_to("HI MOM!");
+//! let formatted_text = Formatting::new().foreground(Color::Green).styles([Styles::Bold, Styles::Blink]).apply_to("HI MOM!");
 //! println!("{}", formatted_text);
 //! ```
+//! 
+//! For quick one-offs there's also the [`Colorize`] trait, which works on anything `Display`:
+//! 
+//! ```
+//! use coloring::*;
+//! 
+//! println!("{}", "HI MOM!".green().bold().on_black());
+//! ```
+//! 
+//! If the `NO_COLOR` environment variable is set to anything non-empty, no escape codes are
+//! emitted and you just get your text back. `FORCE_COLOR`, `CLICOLOR_FORCE`, and `CLICOLOR` are
+//! respected too (see [`colors_enabled_with`] for the order). Use [`set_color_choice`] to force it
+//! either way. Escape codes are also left out when stdout isn't a terminal, e.g. when it's piped
+//! or redirected to a file. Use [`Formatting::paint_for`] for text going to stderr instead.
+//! 
+//! Everything that needs the standard library (reading `NO_COLOR`, checking for terminals and their
+//! size, writing to an `io::Write`) is behind the `std` feature, which is on by default. Turn it
+//! off and the crate is `no_std`, needing only `alloc`, for e.g. embedded devices writing to a
+//! serial console. Without `std`, `ColorChoice::Auto` can't check anything and just means colors
+//! are on. The contrast math (`Color::luminance` and friends) needs `std` too, for `f32::powf`.
+//! 
+//! [`Colorize`]: trait.Colorize.html
+//! [`colors_enabled_with`]: fn.colors_enabled_with.html
+//! [`set_color_choice`]: fn.set_color_choice.html
+//! [`Formatting::paint_for`]: struct.Formatting.html#method.paint_for
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod accessibility;
+mod adaptive;
+mod ansi;
+#[cfg(feature = "anstyle")]
+mod anstyle_support;
+mod cformat;
+pub mod charts;
+mod colorize;
+mod control;
+#[cfg(feature = "std")]
+pub mod convert;
+pub mod cursor;
+mod debug_render;
+pub mod decor;
+pub mod demo;
+pub mod detect;
+pub mod effects;
+mod error;
+mod escape;
+pub mod gradient;
+pub mod heatmap;
+pub mod helpers;
+mod hyperlink;
+pub mod iter;
+pub mod layout;
+#[cfg(feature = "logger")]
+pub mod logger;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod markup;
+pub mod palette;
+pub mod parse;
+pub mod presets;
+#[cfg(feature = "probe")]
+pub mod probe;
+pub mod progress;
+mod quantize;
+pub mod render;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "regex")]
+pub mod rules;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod spec;
+#[cfg(feature = "regex")]
+pub mod stream;
+pub mod style_set;
+mod styled_string;
+pub mod table;
+#[cfg(feature = "termcolor")]
+mod termcolor_support;
+#[cfg(feature = "terminal-size")]
+mod terminal_size;
+pub mod testing;
+mod text;
+pub mod theme;
+#[cfg(feature = "toml")]
+mod theme_file;
+mod to_styled;
+#[cfg(feature = "tracing")]
+pub mod tracing_format;
+#[cfg(feature = "windows")]
+pub mod windows;
+
+pub use accessibility::{accessibility, set_accessibility, Accessibility};
+pub use adaptive::AdaptiveColor;
+#[doc(hidden)]
+pub use cformat::{check as __check_cformat, cformat as __cformat};
+pub use colorize::{Colorize, Styled};
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use colorize::DisplayFn as __DisplayFn;
+#[cfg(feature = "derive")]
+pub use coloring_derive::Styled;
+pub use control::{color_choice, colors_enabled_for, colors_enabled_with, set_color_choice, set_override, unset_override, ColorChoice, Stream};
+#[cfg(feature = "std")]
+pub use control::{override_scope, OverrideGuard};
+pub use debug_render::{debug_parse, debug_render};
+pub use error::Error;
+pub use escape::Escape;
+pub use hyperlink::{hyperlink, hyperlink_choice, set_hyperlink_choice};
+pub use spec::{ParseColorError, ParseSpecError};
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use spec::color_literal as __color;
+pub use style_set::StyleSet;
+pub use styled_string::StyledString;
+#[cfg(feature = "terminal-size")]
+pub use terminal_size::terminal_size;
+pub use text::{center, highlight, pad_left, pad_right, strip, strip_into, truncate_visible, visible_width, wrap};
+#[cfg(feature = "regex")]
+pub use text::highlight_regex;
+pub use theme::Theme;
+#[cfg(feature = "toml")]
+pub use theme_file::ThemeFileError;
+pub use to_styled::{ToStyled, WithTheme};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+use core::ops::{Add, AddAssign};
+#[cfg(feature = "std")]
+use std::io;
+
+use detect::ColorDepth;
 
 /// Color options to pass to either [`foreground`] or [`background`].
 /// 
@@ -30,6 +168,8 @@
 /// 
 /// `printf "\x1B[34;3mtest\x1B[0ming"`
 /// 
+/// Or print [`demo::palette`] to see them all at once.
+/// 
 /// See the links in the module-level documentation for details.
 /// 
 /// Handy reference: [Color chart](https://upload.wikimedia.org/wikipedia/commons/1/15/Xterm_256color_chart.svg) for Colors256.
@@ -37,7 +177,18 @@
 /// [`foreground`]: struct.Formatting.html#method.foreground
 /// 
 /// [`background`]: struct.Formatting.html#method.background
-#[derive(Clone, Copy, Default, Debug)]
+/// 
+/// [`demo::palette`]: demo/fn.palette.html
+/// 
+/// With the `serde` feature, colors (de)serialize as strings like `"red"`, `"bright_cyan"`,
+/// `"256:120"`, or `"#ff00aa"`.
+/// 
+/// Colors compare (and hash) by what they say, not by what they look like: `Color::Red`,
+/// `Color::Colors256(1)`, and `Color::RGB {r: 205, g: 0, b: 0}` are all different. Use
+/// [`to_rgb`] on both sides first if you want to compare how they look.
+/// 
+/// [`to_rgb`]: enum.Color.html#method.to_rgb
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Color {
     #[default]
     Default,
@@ -59,6 +210,9 @@ pub enum Color {
     BrightWhite,
     Colors256(u8),
     RGB {r: u8, g: u8, b: u8},
+    /// One of the 24 shades of gray at the end of the 256 color palette (232 - 255), from 0
+    /// (nearly black) to 23 (nearly white). Anything above 23 counts as 23.
+    Grayscale(u8),
 }
 
 /// Style options to pass to [`styles`].
@@ -68,13 +222,33 @@ pub enum Color {
 /// 
 /// `printf "\x1B[34;3mtest\x1B[0ming"`.
 /// 
+/// Or print [`demo::palette`] to see them all at once.
+/// 
 /// See the links in the module-level documentation for details.
 /// 
 /// **WARNING** `Styles::Reset` resets all preceding styles *and* colors, which you probably don't want.
 /// 
+/// The extended underlines (`DoubleUnderline`, `CurlyUnderline`, etc.) use the newer `4:x` codes,
+/// e.g. `\x1B[4:3m` for curly. Terminals that don't know them may ignore them or, worse, misread them,
+/// so turn on [`compat`] to have them fall back to a plain underline. `compat` also turns
+/// `RapidBlink` into `Blink`, since few terminals blink at two different speeds.
+/// 
+/// `Frame`, `Encircle`, and the fonts (`DefaultFont`, `AltFont1` to `AltFont9`) are part of the
+/// standard but hardly any terminals show them. Only one font applies at a time, so if you set
+/// more than one, the terminal uses whichever code comes last.
+/// 
+/// `Styles::Bold as u8` and so on is the style's SGR code, e.g. 1 for bold, 7 for invert, and 21
+/// for a double underline. The curly, dotted, and dashed underlines don't have a code like that,
+/// so theirs are numbers no terminal gives a meaning to. [`to_ansi_code`] has the code that's
+/// actually written, for every style.
+/// 
+/// With the `serde` feature, styles (de)serialize as lowercase names like `"bold"`.
+/// 
 /// [`styles`]: struct.Formatting.html#method.styles
-
-#[derive(Clone, Copy, Debug)]
+/// [`to_ansi_code`]: enum.Styles.html#method.to_ansi_code
+/// [`compat`]: struct.Formatting.html#method.compat
+/// [`demo::palette`]: demo/fn.palette.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Styles {
     Reset = 0,
     Bold = 1,
@@ -85,6 +259,277 @@ pub enum Styles {
     Invert = 7,
     Invisible = 8,
     Strikethrough = 9,
+    DoubleUnderline = 21,
+    // Not SGR codes: the curly, dotted, and dashed underlines only have `4:x` codes.
+    CurlyUnderline = 76,
+    DottedUnderline = 77,
+    DashedUnderline = 78,
+    RapidBlink = 6,
+    Overline = 53,
+    Frame = 51,
+    Encircle = 52,
+    DefaultFont = 10,
+    AltFont1 = 11,
+    AltFont2 = 12,
+    AltFont3 = 13,
+    AltFont4 = 14,
+    AltFont5 = 15,
+    AltFont6 = 16,
+    AltFont7 = 17,
+    AltFont8 = 18,
+    AltFont9 = 19,
+}
+
+impl Color {
+    fn is_set(&self) -> bool {
+        !matches!(self, Color::Default)
+    }
+
+    /// The raw SGR parameter that sets this as the foreground color, e.g. `"31"` or `"38;5;120"`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert_eq!(Color::BrightRed.to_ansi_code(), "91");
+    /// assert_eq!(Color::RGB { r: 255, g: 0, b: 128 }.to_ansi_code(), "38;2;255;0;128");
+    /// assert_eq!(Color::Default.to_ansi_code(), "39");
+    /// ```
+    pub fn to_ansi_code(&self) -> String {
+        Color::ansi_code(*self, TensDigit::FG)
+    }
+
+    /// The raw SGR parameter that sets this as the background color, e.g. `"41"` or `"48;5;120"`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert_eq!(Color::BrightRed.to_ansi_bg_code(), "101");
+    /// assert_eq!(Color::Colors256(120).to_ansi_bg_code(), "48;5;120");
+    /// ```
+    pub fn to_ansi_bg_code(&self) -> String {
+        Color::ansi_code(*self, TensDigit::BG)
+    }
+
+    fn ansi_code(color: Color, tens_digit: TensDigit) -> String {
+        let mut code = String::new();
+        if color.is_set() {
+            // Writing to a `String` can't fail.
+            let _ = Formatting::translate_color(&mut code, color, tens_digit);
+        } else {
+            code = format!("{}9", tens_digit as u8);
+        }
+        code
+    }
+}
+
+impl Styles {
+    /// Every style, in declaration order.
+    const ALL: [Styles; 27] = [
+        Styles::Reset,
+        Styles::Bold,
+        Styles::Faint,
+        Styles::Italic,
+        Styles::Underline,
+        Styles::Blink,
+        Styles::Invert,
+        Styles::Invisible,
+        Styles::Strikethrough,
+        Styles::DoubleUnderline,
+        Styles::CurlyUnderline,
+        Styles::DottedUnderline,
+        Styles::DashedUnderline,
+        Styles::RapidBlink,
+        Styles::Overline,
+        Styles::Frame,
+        Styles::Encircle,
+        Styles::DefaultFont,
+        Styles::AltFont1,
+        Styles::AltFont2,
+        Styles::AltFont3,
+        Styles::AltFont4,
+        Styles::AltFont5,
+        Styles::AltFont6,
+        Styles::AltFont7,
+        Styles::AltFont8,
+        Styles::AltFont9,
+    ];
+
+    /// Where this style is in `Styles::ALL`, for the bit flags in `StyleSet`.
+    const fn index(self) -> u32 {
+        match self {
+            Styles::Reset => 0,
+            Styles::Bold => 1,
+            Styles::Faint => 2,
+            Styles::Italic => 3,
+            Styles::Underline => 4,
+            Styles::Blink => 5,
+            Styles::Invert => 6,
+            Styles::Invisible => 7,
+            Styles::Strikethrough => 8,
+            Styles::DoubleUnderline => 9,
+            Styles::CurlyUnderline => 10,
+            Styles::DottedUnderline => 11,
+            Styles::DashedUnderline => 12,
+            Styles::RapidBlink => 13,
+            Styles::Overline => 14,
+            Styles::Frame => 15,
+            Styles::Encircle => 16,
+            Styles::DefaultFont => 17,
+            Styles::AltFont1 => 18,
+            Styles::AltFont2 => 19,
+            Styles::AltFont3 => 20,
+            Styles::AltFont4 => 21,
+            Styles::AltFont5 => 22,
+            Styles::AltFont6 => 23,
+            Styles::AltFont7 => 24,
+            Styles::AltFont8 => 25,
+            Styles::AltFont9 => 26,
+        }
+    }
+
+    /// The raw SGR parameter that turns this style on, e.g. `"1"` for bold or `"4:3"` for curly
+    /// underline.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert_eq!(Styles::Bold.to_ansi_code(), "1");
+    /// assert_eq!(Styles::CurlyUnderline.to_ansi_code(), "4:3");
+    /// ```
+    pub fn to_ansi_code(self) -> &'static str {
+        match self {
+            Styles::Reset => "0",
+            Styles::Bold => "1",
+            Styles::Faint => "2",
+            Styles::Italic => "3",
+            Styles::Underline => "4",
+            Styles::Blink => "5",
+            Styles::Invert => "7",
+            Styles::Invisible => "8",
+            Styles::Strikethrough => "9",
+            Styles::DoubleUnderline => "4:2",
+            Styles::CurlyUnderline => "4:3",
+            Styles::DottedUnderline => "4:4",
+            Styles::DashedUnderline => "4:5",
+            Styles::RapidBlink => "6",
+            Styles::Overline => "53",
+            Styles::Frame => "51",
+            Styles::Encircle => "52",
+            Styles::DefaultFont => "10",
+            Styles::AltFont1 => "11",
+            Styles::AltFont2 => "12",
+            Styles::AltFont3 => "13",
+            Styles::AltFont4 => "14",
+            Styles::AltFont5 => "15",
+            Styles::AltFont6 => "16",
+            Styles::AltFont7 => "17",
+            Styles::AltFont8 => "18",
+            Styles::AltFont9 => "19",
+        }
+    }
+
+    /// The closest style that (nearly) every terminal understands.
+    fn compat(self) -> Styles {
+        match self {
+            Styles::DoubleUnderline
+            | Styles::CurlyUnderline
+            | Styles::DottedUnderline
+            | Styles::DashedUnderline => Styles::Underline,
+            Styles::RapidBlink => Styles::Blink,
+            style => style,
+        }
+    }
+
+    /// The escape sequence that turns just this style off, leaving colors and other styles alone.
+    /// 
+    /// Handy for turning something off partway through a line without a full `\x1B[0m` reset.
+    /// Some styles share an off code: bold and faint are both turned off by `\x1B[22m`, and
+    /// every underline by `\x1B[24m`. `Styles::Reset` and `Styles::DefaultFont` don't have one,
+    /// so you get an empty string.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let text = format!("\x1B[31;{}mHI{} MOM\x1B[0m", Styles::Bold.to_ansi_code(), Styles::Bold.off());
+    /// assert_eq!(text, "\x1B[31;1mHI\x1B[22m MOM\x1B[0m");
+    /// ```
+    pub fn off(self) -> &'static str {
+        match self.off_code() {
+            Some(10) => "\x1B[10m",
+            Some(22) => "\x1B[22m",
+            Some(23) => "\x1B[23m",
+            Some(24) => "\x1B[24m",
+            Some(25) => "\x1B[25m",
+            Some(27) => "\x1B[27m",
+            Some(28) => "\x1B[28m",
+            Some(29) => "\x1B[29m",
+            Some(54) => "\x1B[54m",
+            Some(55) => "\x1B[55m",
+            _ => "",
+        }
+    }
+
+    /// The raw SGR parameter that turns just this style off, if there is one. See [`off`].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert_eq!(Styles::Faint.off_code(), Some(22));
+    /// assert_eq!(Styles::CurlyUnderline.off_code(), Some(24));
+    /// assert_eq!(Styles::Reset.off_code(), None);
+    /// ```
+    /// 
+    /// [`off`]: enum.Styles.html#method.off
+    pub fn off_code(self) -> Option<u8> {
+        match self {
+            Styles::Reset | Styles::DefaultFont => None,
+            Styles::Bold | Styles::Faint => Some(22),
+            Styles::Italic => Some(23),
+            Styles::Underline
+            | Styles::DoubleUnderline
+            | Styles::CurlyUnderline
+            | Styles::DottedUnderline
+            | Styles::DashedUnderline => Some(24),
+            Styles::Blink | Styles::RapidBlink => Some(25),
+            Styles::Invert => Some(27),
+            Styles::Invisible => Some(28),
+            Styles::Strikethrough => Some(29),
+            Styles::Frame | Styles::Encircle => Some(54),
+            Styles::Overline => Some(55),
+            Styles::AltFont1
+            | Styles::AltFont2
+            | Styles::AltFont3
+            | Styles::AltFont4
+            | Styles::AltFont5
+            | Styles::AltFont6
+            | Styles::AltFont7
+            | Styles::AltFont8
+            | Styles::AltFont9 => Some(10),
+        }
+    }
+}
+
+/// How the end of formatted text gets reset, for [`reset_mode`].
+/// 
+/// [`reset_mode`]: struct.Formatting.html#method.reset_mode
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResetMode {
+    /// Reset everything with `\x1B[0m`.
+    #[default]
+    Full,
+    /// Only undo the colors and styles that were set, e.g. `\x1B[39;22m` after red bold text,
+    /// leaving any formatting from the surrounding text alone.
+    Targeted,
 }
 
 enum TensDigit {
@@ -92,17 +537,190 @@ enum TensDigit {
     BG = 4,
 }
 
-#[derive(Default, Debug)]
+/// Colors and styles to apply to text.
+/// 
+/// Two `Formatting`s are equal when they would produce the same escape codes, so they can be
+/// compared in tests or used as `HashMap` keys. The order of `styles` doesn't matter.
+/// 
+/// Everything is stored inline, so creating, cloning, and changing a `Formatting` never touches
+/// the allocator.
+/// 
+/// With the `serde` feature this (de)serializes as e.g.
+/// `{ "fg": "red", "bg": "#ff00aa", "styles": ["bold"] }`, with every field optional.
+/// 
+/// With the `anstyle` feature it converts to and from `anstyle::Style`, e.g. for clap's `Styles`.
+/// With the `termcolor` feature it converts to a `termcolor::ColorSpec`, and `write_color` writes
+/// through a `termcolor::WriteColor`.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Formatting {
     fg: Color,
     bg: Color,
-    styles: Option<Vec<Styles>>
+    styles: StyleSet,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    depth: Option<ColorDepth>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    reset_mode: ResetMode,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    compat: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    per_line: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    fill_line: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    disabled: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cached_prefix: CachedPrefix,
+}
+
+/// The prefix worked out by `build`, along with what it was worked out from.
+/// 
+/// Setters don't have to clear it: it's only used while the fields it came from are unchanged,
+/// and checking that is much cheaper than formatting the numbers again. It never takes part in
+/// comparisons or hashing, since it only repeats what the other fields say.
+#[derive(Clone, Copy, Default)]
+struct CachedPrefix(Option<(PrefixKey, Escape)>);
+
+type PrefixKey = (Color, Color, StyleSet, Option<ColorDepth>, ResetMode, bool, Accessibility);
+
+impl CachedPrefix {
+    const EMPTY: CachedPrefix = CachedPrefix(None);
+}
+
+impl PartialEq for CachedPrefix {
+    fn eq(&self, _other: &CachedPrefix) -> bool {
+        true
+    }
+}
+
+impl Eq for CachedPrefix {}
+
+impl PartialOrd for CachedPrefix {
+    fn partial_cmp(&self, other: &CachedPrefix) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CachedPrefix {
+    fn cmp(&self, _other: &CachedPrefix) -> core::cmp::Ordering {
+        core::cmp::Ordering::Equal
+    }
+}
+
+impl core::hash::Hash for CachedPrefix {
+    fn hash<H: core::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+impl fmt::Debug for Formatting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Formatting")
+            .field("fg", &self.fg)
+            .field("bg", &self.bg)
+            .field("styles", &self.styles)
+            .field("depth", &self.depth)
+            .field("reset_mode", &self.reset_mode)
+            .field("compat", &self.compat)
+            .field("per_line", &self.per_line)
+            .field("fill_line", &self.fill_line)
+            .field("disabled", &self.disabled)
+            .finish()
+    }
 }
 
 impl Formatting {
     /// Create a new, default `Formatting` object.
-    pub fn new() -> Formatting {
-        Default::default()
+    /// 
+    /// This works in `const` contexts too, see [`with_foreground`].
+    /// 
+    /// [`with_foreground`]: struct.Formatting.html#method.with_foreground
+    pub const fn new() -> Formatting {
+        Formatting {
+            fg: Color::Default,
+            bg: Color::Default,
+            styles: StyleSet::new(),
+            depth: None,
+            reset_mode: ResetMode::Full,
+            compat: false,
+            per_line: false,
+            fill_line: false,
+            disabled: false,
+            cached_prefix: CachedPrefix::EMPTY,
+        }
+    }
+
+    /// Set the foreground color, taking and returning the `Formatting` by value.
+    /// 
+    /// The `with_*` methods are `const`, so you can build a `Formatting` in a `const` or `static`
+    /// without needing `lazy_static` or the like.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// const ERROR: Formatting = Formatting::new().with_foreground(Color::Red).with_style(Styles::Bold);
+    /// 
+    /// println!("{}", ERROR.apply_to("HI MOM"));
+    /// ```
+    pub const fn with_foreground(mut self, fg: Color) -> Formatting {
+        self.fg = fg;
+        self
+    }
+
+    /// Set the background color, taking and returning the `Formatting` by value.
+    /// 
+    /// See [`with_foreground`].
+    /// 
+    /// [`with_foreground`]: struct.Formatting.html#method.with_foreground
+    pub const fn with_background(mut self, bg: Color) -> Formatting {
+        self.bg = bg;
+        self
+    }
+
+    /// Add a style, taking and returning the `Formatting` by value.
+    /// 
+    /// See [`with_foreground`].
+    /// 
+    /// [`with_foreground`]: struct.Formatting.html#method.with_foreground
+    pub const fn with_style(mut self, style: Styles) -> Formatting {
+        self.styles = self.styles.with(style);
+        self
+    }
+
+    /// Add several styles, taking and returning the `Formatting` by value.
+    /// 
+    /// See [`with_foreground`].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// static HEADING: Formatting = Formatting::new().with_styles(&[Styles::Bold, Styles::Underline]);
+    /// 
+    /// println!("{}", HEADING.apply_to("HI MOM"));
+    /// ```
+    /// 
+    /// [`with_foreground`]: struct.Formatting.html#method.with_foreground
+    pub const fn with_styles(mut self, styles: &[Styles]) -> Formatting {
+        let mut i = 0;
+        while i < styles.len() {
+            self.styles = self.styles.with(styles[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Set the [`ResetMode`], taking and returning the `Formatting` by value.
+    /// 
+    /// See [`with_foreground`].
+    /// 
+    /// [`ResetMode`]: enum.ResetMode.html
+    /// [`with_foreground`]: struct.Formatting.html#method.with_foreground
+    pub const fn with_reset_mode(mut self, reset_mode: ResetMode) -> Formatting {
+        self.reset_mode = reset_mode;
+        self
     }
 
     /// Set the foreground color to `Color`.
@@ -135,101 +753,887 @@ impl Formatting {
         self
     }
 
-    /// Set the styles to a vector of `Styles`.
+    /// Set the styles to an array (or vector, or [`StyleSet`], ...) of `Styles`, replacing any
+    /// that were already set.
+    /// 
+    /// Each style is only applied once, and they always come out in the same order (the order
+    /// they're declared in `Styles`), no matter what order they're given in.
+    /// 
+    /// The styles are kept in a `StyleSet`, which is a bitset stored inline, so setting them
+    /// never allocates. Pass an array rather than a `vec!` and neither does building the list.
     /// 
     /// # Example
     /// 
     /// ```
     /// use coloring::*;
     /// 
-    /// let formatted_text = Formatting::new().styles(vec![Styles::Bold, Styles::Blink]).apply_to("HI MOM");
+    /// let formatted_text = Formatting::new().styles([Styles::Bold, Styles::Blink]).apply_to("HI MOM");
     /// println!("{}", formatted_text);
     /// ```
-    pub fn styles(&mut self, styles: Vec<Styles>) -> &mut Formatting {
-        self.styles = Some(styles);
+    /// 
+    /// [`StyleSet`]: struct.StyleSet.html
+    pub fn styles<I: IntoIterator<Item = Styles>>(&mut self, styles: I) -> &mut Formatting {
+        self.styles = styles.into_iter().collect();
+        self
+    }
+
+    /// Add a style to the ones already set.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let mut formatting = Formatting::new().styles([Styles::Bold]).build();
+    /// formatting.add_style(Styles::Italic);
+    /// assert_eq!(formatting.apply_to("HI MOM"), "\x1B[1;3mHI MOM\x1B[0m");
+    /// ```
+    pub fn add_style(&mut self, style: Styles) -> &mut Formatting {
+        self.styles.insert(style);
+        self
+    }
+
+    /// Remove a style, if it's set.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let mut formatting = Formatting::new().styles([Styles::Bold, Styles::Italic]).build();
+    /// formatting.remove_style(Styles::Bold);
+    /// assert_eq!(formatting.apply_to("HI MOM"), "\x1B[3mHI MOM\x1B[0m");
+    /// ```
+    pub fn remove_style(&mut self, style: Styles) -> &mut Formatting {
+        self.styles.remove(style);
+        self
+    }
+
+    /// The styles that are set.
+    pub fn style_set(&self) -> StyleSet {
+        self.styles
+    }
+
+    /// Go back to the terminal's own foreground color.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let base = Formatting::new().foreground(Color::Red).styles([Styles::Bold]).build();
+    /// let formatted_text = base.build().clear_foreground().apply_to("HI MOM");
+    /// assert_eq!(formatted_text, "\x1B[1mHI MOM\x1B[0m");
+    /// ```
+    pub fn clear_foreground(&mut self) -> &mut Formatting {
+        self.fg = Color::Default;
+        self
+    }
+
+    /// Go back to the terminal's own background color.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let base = Formatting::new().foreground(Color::Red).background(Color::White).build();
+    /// let formatted_text = base.build().clear_background().apply_to("HI MOM");
+    /// assert_eq!(formatted_text, "\x1B[31mHI MOM\x1B[0m");
+    /// ```
+    pub fn clear_background(&mut self) -> &mut Formatting {
+        self.bg = Color::Default;
+        self
+    }
+
+    /// Remove all the styles.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let base = Formatting::new().foreground(Color::Red).styles([Styles::Bold, Styles::Italic]).build();
+    /// let formatted_text = base.build().clear_styles().apply_to("HI MOM");
+    /// assert_eq!(formatted_text, "\x1B[31mHI MOM\x1B[0m");
+    /// ```
+    pub fn clear_styles(&mut self) -> &mut Formatting {
+        self.styles = StyleSet::new();
+        self
+    }
+
+    /// Get an owned copy of the `Formatting` at the end of a chain of builder calls.
+    /// 
+    /// The builder methods return `&mut Formatting`, which can't outlive the statement that
+    /// created it. `build` gives you a `Formatting` you can keep around, e.g. in a variable or struct.
+    /// 
+    /// It also works out the escape codes up front, so formatting thousands of log lines with the
+    /// result doesn't redo that every time. Changing it afterwards is fine, the codes just get
+    /// worked out as they're needed again.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// struct AppStyles {
+    ///     error: Formatting,
+    /// }
+    /// 
+    /// let styles = AppStyles { error: Formatting::new().foreground(Color::Red).build() };
+    /// println!("{}", styles.error.apply_to("HI MOM"));
+    /// ```
+    pub fn build(&self) -> Formatting {
+        let mut built = self.clone();
+        let mut escape = Escape::new();
+        // The longest possible prefix fits, so this can't fail.
+        if self.write_prefix(&mut escape).is_ok() {
+            built.cached_prefix = CachedPrefix(Some((self.prefix_key(), escape)));
+        }
+        built
+    }
+
+    /// Layer `other` on top of this `Formatting`, returning the combination.
+    /// 
+    /// Colors that `other` sets replace this one's, and colors it leaves as `Color::Default` are
+    /// kept. The styles from both are combined. The same goes for `downgrade_to`, `reset_mode`
+    /// (if `other` is `ResetMode::Targeted`), and `compat`. You can also use `+` to do this.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let header = Formatting::new().foreground(Color::Blue).background(Color::White).build();
+    /// let emphasis = Formatting::new().foreground(Color::Red).styles([Styles::Bold]).build();
+    /// 
+    /// let combined = header.merge(&emphasis);
+    /// assert_eq!(combined.apply_to("HI MOM"), "\x1B[31;47;1mHI MOM\x1B[0m");
+    /// assert_eq!((header + emphasis).apply_to("HI MOM"), combined.apply_to("HI MOM"));
+    /// ```
+    pub fn merge(&self, other: &Formatting) -> Formatting {
+        Formatting {
+            fg: if other.fg.is_set() { other.fg } else { self.fg },
+            bg: if other.bg.is_set() { other.bg } else { self.bg },
+            styles: other.styles.iter().fold(self.styles, StyleSet::with),
+            depth: other.depth.or(self.depth),
+            reset_mode: match other.reset_mode {
+                ResetMode::Targeted => ResetMode::Targeted,
+                ResetMode::Full => self.reset_mode,
+            },
+            compat: self.compat || other.compat,
+            per_line: self.per_line || other.per_line,
+            fill_line: self.fill_line || other.fill_line,
+            disabled: self.disabled || other.disabled,
+            cached_prefix: CachedPrefix::EMPTY,
+        }
+    }
+
+    /// Convert the colors to the closest ones a terminal with the given [`ColorDepth`] can show.
+    /// 
+    /// `Color::RGB` becomes the nearest `Color::Colors256` for `ColorDepth::Ansi256`, and both
+    /// become the nearest basic color for `ColorDepth::Basic`. `ColorDepth::NoColor` drops the
+    /// colors entirely, leaving only the styles. The conversion happens when the text is formatted,
+    /// so you can keep setting colors afterwards.
+    /// 
+    /// Pair it with [`TerminalCaps::detect`] to downgrade automatically.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// use coloring::detect::{ColorDepth, TerminalCaps};
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let formatted_text = Formatting::new()
+    ///     .foreground(Color::RGB { r: 255, g: 0, b: 0 })
+    ///     .downgrade_to(ColorDepth::Ansi256)
+    ///     .apply_to("HI MOM");
+    /// assert_eq!(formatted_text, "\x1B[38;5;196mHI MOM\x1B[0m");
+    /// 
+    /// let formatted_text = Formatting::new()
+    ///     .foreground(Color::RGB { r: 255, g: 135, b: 0 })
+    ///     .downgrade_to(TerminalCaps::detect().depth)
+    ///     .apply_to("HI MOM");
+    /// println!("{}", formatted_text);
+    /// ```
+    /// 
+    /// [`ColorDepth`]: detect/enum.ColorDepth.html
+    /// [`TerminalCaps::detect`]: detect/struct.TerminalCaps.html#method.detect
+    pub fn downgrade_to(&mut self, depth: ColorDepth) -> &mut Formatting {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Stick to codes that (nearly) every terminal understands.
+    /// 
+    /// For now this means the extended underlines (`Styles::CurlyUnderline`, etc.) become a plain
+    /// `Styles::Underline`, and `Styles::RapidBlink` becomes `Styles::Blink`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let mut formatting = Formatting::new();
+    /// formatting.styles([Styles::CurlyUnderline]);
+    /// assert_eq!(formatting.apply_to("HI MOM"), "\x1B[4:3mHI MOM\x1B[0m");
+    /// formatting.compat(true);
+    /// assert_eq!(formatting.apply_to("HI MOM"), "\x1B[4mHI MOM\x1B[0m");
+    /// ```
+    pub fn compat(&mut self, compat: bool) -> &mut Formatting {
+        self.compat = compat;
+        self
+    }
+
+    /// Reset before every newline and start the formatting again after it.
+    /// 
+    /// Some terminals and pagers (e.g. `less -R`) drop or smear background colors across line
+    /// breaks, so multi-line text with a background can look ragged. With this on, each line is
+    /// formatted on its own. Empty lines are left plain.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let formatted_text = Formatting::new().background(Color::Blue).per_line(true).apply_to("HI\nMOM");
+    /// assert_eq!(formatted_text, "\x1B[44mHI\x1B[0m\n\x1B[44mMOM\x1B[0m");
+    /// ```
+    pub fn per_line(&mut self, per_line: bool) -> &mut Formatting {
+        self.per_line = per_line;
+        self
+    }
+
+    /// Stretch the background color to the right edge of the terminal.
+    /// 
+    /// This writes an "erase to end of line" (`\x1B[K`) before the reset, which fills the rest
+    /// of the line with the current background. It's handy for full-width bars, like status lines
+    /// or diff headers. Combine it with [`per_line`] to fill every line of multi-line text.
+    /// Nothing extra is written without a background color.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let formatted_text = Formatting::new().background(Color::Blue).fill_line(true).apply_to("HI MOM");
+    /// assert_eq!(formatted_text, "\x1B[44mHI MOM\x1B[K\x1B[0m");
+    /// ```
+    /// 
+    /// [`per_line`]: struct.Formatting.html#method.per_line
+    pub fn fill_line(&mut self, fill_line: bool) -> &mut Formatting {
+        self.fill_line = fill_line;
+        self
+    }
+
+    /// Only write escape codes when `enabled` is true, e.g. to respect a `--no-color` flag.
+    /// 
+    /// With `false`, everything this `Formatting` writes is plain text, whatever
+    /// [`set_color_choice`] says. It saves wrapping every call site in an `if`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let no_color = true;
+    /// let formatting = Formatting::new().foreground(Color::Red).when(!no_color).build();
+    /// assert_eq!(formatting.apply_to("HI MOM"), "HI MOM");
+    /// ```
+    /// 
+    /// [`set_color_choice`]: fn.set_color_choice.html
+    pub fn when(&mut self, enabled: bool) -> &mut Formatting {
+        self.disabled = !enabled;
+        self
+    }
+
+    /// Choose how the end of the text is reset. See [`ResetMode`].
+    /// 
+    /// The default, `ResetMode::Full`, resets everything, including any formatting the text is
+    /// sitting inside of. `ResetMode::Targeted` only undoes what this `Formatting` set, so it is
+    /// the one to use for text that goes inside other formatted text.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let formatted_text = Formatting::new()
+    ///     .foreground(Color::Red)
+    ///     .styles([Styles::Bold])
+    ///     .reset_mode(ResetMode::Targeted)
+    ///     .apply_to("HI MOM");
+    /// assert_eq!(formatted_text, "\x1B[31;1mHI MOM\x1B[39;22m");
+    /// ```
+    /// 
+    /// [`ResetMode`]: enum.ResetMode.html
+    pub fn reset_mode(&mut self, reset_mode: ResetMode) -> &mut Formatting {
+        self.reset_mode = reset_mode;
         self
     }
     
     /// Apply your colors and styles to text.
     /// 
+    /// If `text` already contains styled text (e.g. from another `apply_to`), your colors and
+    /// styles are re-applied after each of its resets, so nesting works the way you'd expect.
+    /// 
     /// # Example
     /// 
     /// ```
     /// use coloring::*;
-    /// let formatted_text = Formatting::new().foreground(Color::Green).styles(vec![Styles::Bold, Styles::Blink]).apply_to("HI MOM!");
+    /// let formatted_text = Formatting::new().foreground(Color::Green).styles([Styles::Bold, Styles::Blink]).apply_to("HI MOM!");
     /// println!("{}", formatted_text);
     /// ```
     pub fn apply_to(&self, text: &str) -> String {
-        let mut colored = "\x1B[".to_string();  // Starting delimiter.
-        colored.push_str(&self.translate());
-        colored.push('m');
-        colored.push_str(text);
-        colored.push_str("\x1B[0m");  // Ending, resetting delimiter.
-        colored
-    }
-
-    fn translate(&self) -> String {
-        let fg = self.translate_foreground();
-        let bg = self.translate_background();
-        let styles = self.translate_styles();
-
-        let mut translation = String::new();
-        if fg.is_some() {
-            translation.push_str(&fg.unwrap());
-            if bg.is_some() || styles.is_some() {
-                translation.push(';');
+        self.paint(text).to_string()
+    }
+
+    /// Like [`apply_to`], but leaves `text` plain unless `condition` is true.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let formatting = Formatting::new().foreground(Color::Red).build();
+    /// let failed = false;
+    /// assert_eq!(formatting.apply_if(failed, "3 tests run"), "3 tests run");
+    /// ```
+    /// 
+    /// [`apply_to`]: struct.Formatting.html#method.apply_to
+    pub fn apply_if(&self, condition: bool, text: &str) -> String {
+        if condition {
+            self.apply_to(text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Like [`apply_to`], but for anything `Display`, e.g. numbers, paths, or your own types,
+    /// without calling `to_string()` on it first.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let count = Formatting::new().foreground(Color::Green).apply_to_display(&42);
+    /// assert_eq!(count, "\x1B[32m42\x1B[0m");
+    /// 
+    /// let path = std::path::Path::new("src/lib.rs");
+    /// println!("{}", Formatting::new().bold().apply_to_display(&path.display()));
+    /// ```
+    /// 
+    /// [`apply_to`]: struct.Formatting.html#method.apply_to
+    pub fn apply_to_display<T: fmt::Display + ?Sized>(&self, value: &T) -> String {
+        let mut formatted = String::new();
+        // Writing to a `String` can only fail if `value`'s `Display` does.
+        let _ = self.write_display(&mut formatted, value);
+        formatted
+    }
+
+    fn write_display<W: fmt::Write, T: fmt::Display + ?Sized>(&self, w: &mut W, value: &T) -> fmt::Result {
+        if !self.colorize(None) {
+            return write!(w, "{}", value);
+        }
+        self.write_prefix(w)?;
+        let mut nested = Nested::new(w, self);
+        write!(nested, "{}", value)?;
+        nested.finish()
+    }
+
+    /// Write your formatted text straight to an `io::Write`, e.g. a file, socket, or stdout lock.
+    /// 
+    /// No intermediate `String` is allocated.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use std::io::Write;
+    /// use coloring::*;
+    /// 
+    /// let mut stdout = std::io::stdout().lock();
+    /// Formatting::new().foreground(Color::Green).write_to(&mut stdout, "HI MOM").unwrap();
+    /// writeln!(stdout).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: io::Write + ?Sized>(&self, w: &mut W, text: &str) -> io::Result<()> {
+        write!(w, "{}", self.paint(text))
+    }
+
+    /// Write your formatted text to a `fmt::Write`, e.g. a `String` you're building up.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let mut buffer = String::from("> ");
+    /// Formatting::new().foreground(Color::Green).write_to_fmt(&mut buffer, "HI MOM").unwrap();
+    /// assert_eq!(buffer, "> \x1B[32mHI MOM\x1B[0m");
+    /// ```
+    pub fn write_to_fmt<W: fmt::Write + ?Sized>(&self, w: &mut W, text: &str) -> fmt::Result {
+        write!(w, "{}", self.paint(text))
+    }
+
+    /// Lazily apply your colors and styles to text.
+    /// 
+    /// Unlike [`apply_to`], nothing is allocated here. The escape codes are only written out
+    /// when the returned [`StyledText`] is formatted, so you can hand it straight to `println!`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// let formatting = Formatting::new().foreground(Color::Green).build();
+    /// println!("{}", formatting.paint("HI MOM!"));
+    /// ```
+    /// 
+    /// [`apply_to`]: struct.Formatting.html#method.apply_to
+    pub fn paint<'a>(&'a self, text: &'a str) -> StyledText<'a> {
+        StyledText { formatting: self, text, stream: None }
+    }
+
+    /// Like [`paint`], but for text headed to `stream`.
+    /// 
+    /// With `ColorChoice::Auto` this leaves out the escape codes if `stream` isn't a terminal, so
+    /// redirected output stays clean.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let formatting = Formatting::new().foreground(Color::Red).build();
+    /// eprintln!("{}: oh no", formatting.paint_for(Stream::Stderr, "error"));
+    /// ```
+    /// 
+    /// [`paint`]: struct.Formatting.html#method.paint
+    pub fn paint_for<'a>(&'a self, stream: Stream, text: &'a str) -> StyledText<'a> {
+        StyledText { formatting: self, text, stream: Some(stream) }
+    }
+
+    /// The escape sequence that turns your colors and styles on, without allocating.
+    /// 
+    /// Handy for wrapping output you're streaming yourself: write the prefix once, then as much
+    /// text as you like, then the [`suffix`]. Unlike [`apply_to`], nothing is re-applied after
+    /// resets inside the text, and `per_line` is ignored.
+    /// 
+    /// Empty when escape codes are turned off (see [`set_color_choice`]).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let formatting = Formatting::new().foreground(Color::Green).bold().build();
+    /// let mut output = String::new();
+    /// output.push_str(&formatting.prefix());
+    /// for line in ["one\n", "two\n"] {
+    ///     output.push_str(line);
+    /// }
+    /// output.push_str(&formatting.suffix());
+    /// assert_eq!(output, "\x1B[32;1mone\ntwo\n\x1B[0m");
+    /// ```
+    /// 
+    /// [`suffix`]: struct.Formatting.html#method.suffix
+    /// [`apply_to`]: struct.Formatting.html#method.apply_to
+    /// [`set_color_choice`]: fn.set_color_choice.html
+    pub fn prefix(&self) -> Escape {
+        let mut escape = Escape::new();
+        if self.colorize(None) {
+            // The longest possible prefix fits, so this can't fail.
+            let _ = self.write_prefix(&mut escape);
+        }
+        escape
+    }
+
+    /// The escape sequence that turns your colors and styles back off, to go with [`prefix`].
+    /// 
+    /// This respects [`reset_mode`] and [`fill_line`].
+    /// 
+    /// [`prefix`]: struct.Formatting.html#method.prefix
+    /// [`reset_mode`]: struct.Formatting.html#method.reset_mode
+    /// [`fill_line`]: struct.Formatting.html#method.fill_line
+    pub fn suffix(&self) -> Escape {
+        let mut escape = Escape::new();
+        if self.colorize(None) {
+            let _ = self.write_suffix(&mut escape);
+        }
+        escape
+    }
+
+    /// Whether to write escape codes for text headed to `stream`.
+    pub(crate) fn colorize(&self, stream: Option<Stream>) -> bool {
+        !self.disabled && control::should_colorize(stream)
+    }
+
+    fn write_prefix<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        if let CachedPrefix(Some((key, escape))) = &self.cached_prefix {
+            if *key == self.prefix_key() {
+                return w.write_str(escape);
+            }
+        }
+        // An empty `\x1B[m` is itself a full reset, which targeted mode is trying to avoid.
+        if self.reset_mode == ResetMode::Targeted && !self.has_codes() {
+            return Ok(());
+        }
+        w.write_str("\x1B[")?;  // Starting delimiter.
+        self.translate(w)?;
+        w.write_char('m')
+    }
+
+    fn write_suffix<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        if self.fill_line && self.effective_colors().1.is_set() {
+            w.write_str("\x1B[K")?;
+        }
+        match self.reset_mode {
+            ResetMode::Full => w.write_str("\x1B[0m"),  // Ending, resetting delimiter.
+            ResetMode::Targeted => self.write_targeted_reset(w),
+        }
+    }
+
+    fn write_targeted_reset<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let (fg, bg) = self.effective_colors();
+        // There are only a dozen distinct off codes, so no need to allocate.
+        let mut codes = [0u8; 16];
+        let mut len = 0;
+        let off_codes = [fg.is_set().then_some(39), bg.is_set().then_some(49)]
+            .into_iter()
+            .chain(self.effective_styles().iter().map(Styles::off_code));
+        for code in off_codes.flatten() {
+            if !codes[..len].contains(&code) {
+                codes[len] = code;
+                len += 1;
             }
         }
-        if bg.is_some() {
-            translation.push_str(&bg.unwrap());
-            if styles.is_some() {
-                translation.push(';');
+        let codes = &codes[..len];
+        if codes.is_empty() {
+            return Ok(());
+        }
+        w.write_str("\x1B[")?;
+        for (i, code) in codes.iter().enumerate() {
+            if i > 0 {
+                w.write_char(';')?;
             }
+            write!(w, "{}", code)?;
         }
-        translation.push_str(&styles.unwrap_or("".to_string()));
-        translation
+        w.write_char('m')
+    }
+
+    /// Everything the prefix depends on.
+    fn prefix_key(&self) -> PrefixKey {
+        (self.fg, self.bg, self.styles, self.depth, self.reset_mode, self.compat, accessibility())
+    }
+
+    fn has_codes(&self) -> bool {
+        let (fg, bg) = self.effective_colors();
+        fg.is_set() || bg.is_set() || !self.effective_styles().is_empty()
+    }
+
+    /// The colors after any accessibility adjustments and downgrading.
+    fn effective_colors(&self) -> (Color, Color) {
+        let (fg, bg) = accessibility::adjust_colors(self.fg, self.bg);
+        match self.depth {
+            Some(depth) => (fg.downgrade(depth), bg.downgrade(depth)),
+            None => (fg, bg),
+        }
+    }
+
+    /// The styles after any accessibility adjustments.
+    fn effective_styles(&self) -> StyleSet {
+        accessibility::adjust_styles(self.styles)
     }
 
-    fn translate_colors(color: Color, tens_digit: TensDigit) -> Option<String> {
+    fn translate<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let mut first = true;
+        let mut separate = |w: &mut W| {
+            if first {
+                first = false;
+                Ok(())
+            } else {
+                w.write_char(';')
+            }
+        };
+
+        let (fg, bg) = self.effective_colors();
+        if fg.is_set() {
+            separate(w)?;
+            Formatting::translate_color(w, fg, TensDigit::FG)?;
+        }
+        if bg.is_set() {
+            separate(w)?;
+            Formatting::translate_color(w, bg, TensDigit::BG)?;
+        }
+        let styles = self.effective_styles();
+        if !styles.is_empty() {
+            separate(w)?;
+            for (i, style) in styles.iter().enumerate() {
+                if i > 0 {
+                    w.write_char(';')?;
+                }
+                let style = if self.compat { style.compat() } else { style };
+                w.write_str(style.to_ansi_code())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn translate_color<W: fmt::Write>(w: &mut W, color: Color, tens_digit: TensDigit) -> fmt::Result {
         let td = tens_digit as u8;
         match color {
-            Color::Default => None,
-            Color::Colors256(val) => Some(format!("{}8;5;{}", td, val)),
-            Color::RGB { r, g, b } => Some(format!("{}8;2;{};{};{}", td, r, g, b)),
-            Color::Black => Some(format!("{}", (10 * td) + 0)),
-            Color::Red => Some(format!("{}", (10 * td) + 1)),
-            Color::Green => Some(format!("{}", (10 * td) + 2)),
-            Color::Yellow => Some(format!("{}", (10 * td) + 3)),
-            Color::Blue => Some(format!("{}", (10 * td) + 4)),
-            Color::Magenta => Some(format!("{}", (10 * td) + 5)),
-            Color::Cyan => Some(format!("{}", (10 * td) + 6)),
-            Color::White => Some(format!("{}", (10 * td) + 7)),
-            Color::BrightBlack => Some(format!("{}", (10 * td) + 60)),
-            Color::BrightRed => Some(format!("{}", (10 * td) + 61)),
-            Color::BrightGreen => Some(format!("{}", (10 * td) + 62)),
-            Color::BrightYellow => Some(format!("{}", (10 * td) + 63)),
-            Color::BrightBlue => Some(format!("{}", (10 * td) + 64)),
-            Color::BrightMagenta => Some(format!("{}", (10 * td) + 65)),
-            Color::BrightCyan => Some(format!("{}", (10 * td) + 66)),
-            Color::BrightWhite => Some(format!("{}", (10 * td) + 67)),
-        }
-    }
-
-    fn translate_foreground(&self) -> Option<String> {
-        Formatting::translate_colors(self.fg, TensDigit::FG)
-    }
-
-    fn translate_background(&self) -> Option<String> {
-        Formatting::translate_colors(self.bg, TensDigit::BG)
-    }
-
-    fn translate_styles(&self) -> Option<String> {
-        match &self.styles {
-            None => None,
-            Some(styles) => {
-                let styles: Vec<String> = styles.iter().map(|&x| (x as u8).to_string()).collect();
-                Some(styles.join(";"))
+            Color::Default => Ok(()),
+            Color::Colors256(val) => write!(w, "{}8;5;{}", td, val),
+            Color::Grayscale(level) => write!(w, "{}8;5;{}", td, quantize::grayscale_index(level)),
+            Color::RGB { r, g, b } => write!(w, "{}8;2;{};{};{}", td, r, g, b),
+            Color::Black => write!(w, "{}", 10 * td),
+            Color::Red => write!(w, "{}", (10 * td) + 1),
+            Color::Green => write!(w, "{}", (10 * td) + 2),
+            Color::Yellow => write!(w, "{}", (10 * td) + 3),
+            Color::Blue => write!(w, "{}", (10 * td) + 4),
+            Color::Magenta => write!(w, "{}", (10 * td) + 5),
+            Color::Cyan => write!(w, "{}", (10 * td) + 6),
+            Color::White => write!(w, "{}", (10 * td) + 7),
+            Color::BrightBlack => write!(w, "{}", (10 * td) + 60),
+            Color::BrightRed => write!(w, "{}", (10 * td) + 61),
+            Color::BrightGreen => write!(w, "{}", (10 * td) + 62),
+            Color::BrightYellow => write!(w, "{}", (10 * td) + 63),
+            Color::BrightBlue => write!(w, "{}", (10 * td) + 64),
+            Color::BrightMagenta => write!(w, "{}", (10 * td) + 65),
+            Color::BrightCyan => write!(w, "{}", (10 * td) + 66),
+            Color::BrightWhite => write!(w, "{}", (10 * td) + 67),
+        }
+    }
+}
+
+macro_rules! style_methods {
+    ($($name:ident => $style:ident),* $(,)?) => {
+        /// Shortcuts for adding one style at a time, instead of passing a `Vec` to [`styles`].
+        /// 
+        /// # Example
+        /// 
+        /// ```
+        /// use coloring::*;
+        /// 
+        /// # let _color = override_scope(ColorChoice::Always);
+        /// let formatted_text = Formatting::new().foreground(Color::Red).bold().underline().apply_to("HI MOM");
+        /// assert_eq!(formatted_text, "\x1B[31;1;4mHI MOM\x1B[0m");
+        /// ```
+        /// 
+        /// [`styles`]: struct.Formatting.html#method.styles
+        impl Formatting {
+            $(
+                #[doc = concat!("Add `Styles::", stringify!($style), "`.")]
+                pub fn $name(&mut self) -> &mut Formatting {
+                    self.add_style(Styles::$style)
+                }
+            )*
+        }
+    };
+}
+
+style_methods! {
+    bold => Bold,
+    faint => Faint,
+    dim => Faint,
+    italic => Italic,
+    underline => Underline,
+    blink => Blink,
+    invert => Invert,
+    invisible => Invisible,
+    strikethrough => Strikethrough,
+    double_underline => DoubleUnderline,
+    curly_underline => CurlyUnderline,
+    dotted_underline => DottedUnderline,
+    dashed_underline => DashedUnderline,
+    rapid_blink => RapidBlink,
+    overline => Overline,
+    frame => Frame,
+    encircle => Encircle,
+}
+
+/// Same as [`merge`], with the right-hand side layered on top.
+/// 
+/// [`merge`]: struct.Formatting.html#method.merge
+impl Add for Formatting {
+    type Output = Formatting;
+
+    fn add(self, other: Formatting) -> Formatting {
+        self.merge(&other)
+    }
+}
+
+/// Same as [`merge`], with the right-hand side layered on top.
+/// 
+/// [`merge`]: struct.Formatting.html#method.merge
+impl Add<&Formatting> for &Formatting {
+    type Output = Formatting;
+
+    fn add(self, other: &Formatting) -> Formatting {
+        self.merge(other)
+    }
+}
+
+impl AddAssign<&Formatting> for Formatting {
+    fn add_assign(&mut self, other: &Formatting) {
+        *self = self.merge(other);
+    }
+}
+
+/// Text with a `Formatting` applied to it, returned by [`paint`].
+/// 
+/// The escape codes are written when this is displayed, e.g. via `format!` or `println!`. Width,
+/// fill, alignment, and precision only count the text, not the escape codes, so
+/// `format!("{:>10}", formatting.paint("HI MOM"))` lines up with plain text. The padding goes
+/// inside the colors.
+/// 
+/// [`paint`]: struct.Formatting.html#method.paint
+#[derive(Clone, Copy, Debug)]
+pub struct StyledText<'a> {
+    formatting: &'a Formatting,
+    text: &'a str,
+    stream: Option<Stream>,
+}
+
+impl fmt::Display for StyledText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_some() || f.precision().is_some() {
+            return self.formatting.write_padded(f, self.text, self.formatting.colorize(self.stream));
+        }
+        if !self.formatting.colorize(self.stream) {
+            return f.write_str(self.text);
+        }
+        self.formatting.write_prefix(f)?;
+        let mut nested = Nested::new(f, self.formatting);
+        nested.write_str(self.text)?;
+        nested.finish()
+    }
+}
+
+impl Formatting {
+    /// Write `value` with `f`'s width, fill, alignment, and precision applied to the visible text
+    /// first, then the escape codes around all of it.
+    pub(crate) fn write_padded<T: fmt::Display + ?Sized>(&self, f: &mut fmt::Formatter<'_>, value: &T, colorize: bool) -> fmt::Result {
+        let content = match f.precision() {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        };
+        let gap = f.width().unwrap_or(0).saturating_sub(text::visible_width(&content));
+        let align = f.align().unwrap_or_else(|| natural_align(value, f.precision(), &content));
+        let (before, after) = match align {
+            fmt::Alignment::Left => (0, gap),
+            fmt::Alignment::Right => (gap, 0),
+            fmt::Alignment::Center => (gap / 2, gap - gap / 2),
+        };
+
+        let mut padded = String::with_capacity(content.len() + gap);
+        padded.extend(core::iter::repeat_n(f.fill(), before));
+        padded.push_str(&content);
+        padded.extend(core::iter::repeat_n(f.fill(), after));
+        if !colorize {
+            return f.write_str(&padded);
+        }
+        self.write_prefix(f)?;
+        let mut nested = Nested::new(f, self);
+        nested.write_str(&padded)?;
+        nested.finish()
+    }
+}
+
+/// The alignment `value` picks for itself when none is given, e.g. strings go on the left and
+/// numbers on the right. Values that ignore the width entirely go on the left.
+fn natural_align<T: fmt::Display + ?Sized>(value: &T, precision: Option<usize>, content: &str) -> fmt::Alignment {
+    let width = content.chars().count() + 1;
+    let probe = match precision {
+        Some(precision) => format!("{:width$.precision$}", value, width = width, precision = precision),
+        None => format!("{:width$}", value, width = width),
+    };
+    if probe.strip_prefix(' ') == Some(content) {
+        fmt::Alignment::Right
+    } else {
+        fmt::Alignment::Left
+    }
+}
+
+/// Passes text through, re-emitting a `Formatting`'s prefix after every full reset
+/// (`\x1B[0m` or `\x1B[m`) so that styled text nested inside other styled text
+/// doesn't cancel the outer colors and styles for the rest of the string.
+struct Nested<'a, W: fmt::Write> {
+    inner: &'a mut W,
+    formatting: &'a Formatting,
+    state: ResetState,
+    reapply: bool,
+    ended_line: bool,
+}
+
+/// How much of a reset sequence has been seen so far.
+#[derive(Clone, Copy)]
+enum ResetState {
+    Text,
+    Escape,
+    Bracket,
+    Zero,
+}
+
+impl<'a, W: fmt::Write> Nested<'a, W> {
+    fn new(inner: &'a mut W, formatting: &'a Formatting) -> Nested<'a, W> {
+        Nested { inner, formatting, state: ResetState::Text, reapply: false, ended_line: false }
+    }
+
+    /// Write the formatting's suffix, unless a per-line reset already took care of it.
+    fn finish(self) -> fmt::Result {
+        if self.ended_line {
+            return Ok(());
+        }
+        self.formatting.write_suffix(self.inner)
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for Nested<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            let new_line = c == '\n' && self.formatting.per_line;
+            // Wait for more text before re-applying, so a reset at the very end costs nothing.
+            if self.reapply && !new_line {
+                self.inner.write_str(&s[start..i])?;
+                self.formatting.write_prefix(self.inner)?;
+                start = i;
+                self.reapply = false;
+                self.ended_line = false;
+            }
+            if new_line && !self.reapply {
+                self.inner.write_str(&s[start..i])?;
+                self.formatting.write_suffix(self.inner)?;
+                start = i;
+                self.reapply = true;
+                self.ended_line = true;
             }
+            self.state = match (self.state, c) {
+                (_, '\x1B') => ResetState::Escape,
+                (ResetState::Escape, '[') => ResetState::Bracket,
+                (ResetState::Bracket, '0') => ResetState::Zero,
+                (ResetState::Bracket, 'm') | (ResetState::Zero, 'm') => {
+                    self.reapply = true;
+                    ResetState::Text
+                }
+                _ => ResetState::Text,
+            };
         }
+        self.inner.write_str(&s[start..])
     }
 }
@@ -0,0 +1,138 @@
+//! A colored logger for the [`log`](https://docs.rs/log) crate.
+//! 
+//! Levels are colored with a [`Theme`]: `"error"`, `"warning"`, and `"info"` for the first three,
+//! and `"debug"` and `"trace"` for the rest, falling back to `"hint"`. The target is colored with
+//! `"hint"`. Lines look like `ERROR my_app::db: connection refused`.
+//! 
+//! Use [`Logger`] on its own, or [`format_record`] to color the output of another logger, e.g. in
+//! an `env_logger` format function.
+//! 
+//! Needs the `logger` feature.
+//! 
+//! [`Theme`]: ../struct.Theme.html
+//! [`Logger`]: struct.Logger.html
+//! [`format_record`]: fn.format_record.html
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use std::eprintln;
+
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::{Formatting, Stream, StyledText, Theme};
+
+/// Logs to stderr, coloring each line with a [`Theme`].
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::logger::Logger;
+/// 
+/// let mut theme = Theme::default();
+/// theme.set("debug", Formatting::new().foreground(Color::Blue).build());
+/// Logger::new().theme(theme).max_level(log::LevelFilter::Debug).init().unwrap();
+/// 
+/// log::warn!("disk almost full");
+/// ```
+/// 
+/// [`Theme`]: ../struct.Theme.html
+#[derive(Clone, Debug)]
+pub struct Logger {
+    theme: Theme,
+    max_level: LevelFilter,
+}
+
+impl Logger {
+    /// A logger using `Theme::default()`, showing `Info` and up.
+    pub fn new() -> Logger {
+        Logger { theme: Theme::default(), max_level: LevelFilter::Info }
+    }
+
+    /// Set the theme to color levels and targets with.
+    pub fn theme(&mut self, theme: Theme) -> &mut Logger {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the most verbose level that gets logged.
+    pub fn max_level(&mut self, max_level: LevelFilter) -> &mut Logger {
+        self.max_level = max_level;
+        self
+    }
+
+    /// Install this as the global logger. Fails if there already is one.
+    pub fn init(&self) -> Result<(), SetLoggerError> {
+        log::set_boxed_logger(Box::new(self.clone()))?;
+        log::set_max_level(self.max_level);
+        Ok(())
+    }
+}
+
+impl Default for Logger {
+    fn default() -> Logger {
+        Logger::new()
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= self.max_level
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.enabled(record.metadata()) {
+            eprintln!("{}", format_with(record, &self.theme, Some(Stream::Stderr)));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Format `record` as one colored line (without a trailing newline), e.g.
+/// `ERROR my_app::db: connection refused`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let record = log::Record::builder()
+///     .level(log::Level::Error)
+///     .target("my_app")
+///     .args(format_args!("oh no"))
+///     .build();
+/// assert_eq!(
+///     coloring::logger::format_record(&record, &Theme::default()),
+///     "\x1B[31;1mERROR\x1B[0m \x1B[90mmy_app\x1B[0m: oh no"
+/// );
+/// ```
+pub fn format_record(record: &Record<'_>, theme: &Theme) -> String {
+    format_with(record, theme, None)
+}
+
+fn format_with(record: &Record<'_>, theme: &Theme, stream: Option<Stream>) -> String {
+    // Names missing from the theme leave the text plain, rather than wrapping it in empty codes.
+    let level = record.level().as_str();
+    let level = match level_formatting(record.level(), theme) {
+        Some(formatting) => format!("{:<5}", StyledText { formatting, text: level, stream }),
+        None => format!("{:<5}", level),
+    };
+    let target = match theme.get("hint") {
+        Some(formatting) => StyledText { formatting, text: record.target(), stream }.to_string(),
+        None => record.target().to_string(),
+    };
+    format!("{} {}: {}", level, target, record.args())
+}
+
+fn level_formatting(level: Level, theme: &Theme) -> Option<&Formatting> {
+    match level {
+        Level::Error => theme.get("error"),
+        Level::Warn => theme.get("warning"),
+        Level::Info => theme.get("info"),
+        Level::Debug => theme.get("debug").or_else(|| theme.get("hint")),
+        Level::Trace => theme.get("trace").or_else(|| theme.get("hint")),
+    }
+}
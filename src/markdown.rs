@@ -0,0 +1,284 @@
+//! Rendering a little Markdown for the terminal: headings, `**bold**`, `*italic*`, `` `code` ``,
+//! fenced code blocks, and lists. Everything else, like links and tables, is left as it's written.
+//! 
+//! The styles come from a [`Theme`], under these names:
+//! 
+//!   - `"heading"` for headings, or `"heading1"` to `"heading6"` for one level. Bold and
+//!     underlined if it's not in the theme.
+//!   - `"strong"` for `**bold**` text. Bold if it's not in the theme.
+//!   - `"emphasis"` for `*italic*` text. Italic if it's not in the theme.
+//!   - `"code"` for inline code and code blocks. Yellow if it's not in the theme.
+//!   - `"bullet"` for list markers. Cyan if it's not in the theme.
+//! 
+//! Needs the `markdown` feature.
+//! 
+//! [`Theme`]: ../struct.Theme.html
+
+use alloc::format;
+use alloc::string::String;
+
+use crate::{Color, Formatting, Styles, Theme};
+
+/// Turn Markdown into text with escape codes, using the styles in the registry (see
+/// [`theme::register`]) or the defaults listed in the [module docs].
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let text = markdown::render("Run `cargo test` **first**.");
+/// assert_eq!(text, "Run \x1B[33mcargo test\x1B[0m \x1B[1mfirst\x1B[0m.");
+/// 
+/// println!("{}", markdown::render("# Changes\n\n- Faster *startup*\n- Fewer bugs\n"));
+/// ```
+/// 
+/// [`theme::register`]: ../theme/fn.register.html
+/// [module docs]: index.html
+pub fn render(source: &str) -> String {
+    #[cfg(feature = "std")]
+    return crate::theme::with_registry(|registry| render_with(source, registry));
+    #[cfg(not(feature = "std"))]
+    render_with(source, &Theme::new())
+}
+
+/// Like [`render`], but with the styles in `theme`, e.g. to make headings match the rest of your
+/// program.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let mut theme = Theme::new();
+/// theme.set("heading1", Formatting::new().foreground(Color::Magenta).build());
+/// theme.set("bullet", Formatting::new());
+/// let text = markdown::render_with("# Todo\n* tests\n", &theme);
+/// assert_eq!(text, "\x1B[35mTodo\x1B[0m\n• tests\n");
+/// ```
+pub fn render_with(source: &str, theme: &Theme) -> String {
+    let mut rendered = String::with_capacity(source.len());
+    // The fence that opened the code block we're in, if any.
+    let mut fence: Option<&str> = None;
+    for line in source.split_inclusive('\n') {
+        let (line, newline) = match line.strip_suffix('\n') {
+            Some(line) => (line.strip_suffix('\r').unwrap_or(line), true),
+            None => (line, false),
+        };
+        let trimmed = line.trim_start();
+        match fence {
+            Some(open) if closes(trimmed, open) => {
+                fence = None;
+                continue;
+            }
+            Some(_) => push(&mut rendered, &style(theme, "code"), line),
+            None => match fence_at(trimmed) {
+                Some(open) => {
+                    fence = Some(open);
+                    continue;
+                }
+                None => block(&mut rendered, line, theme),
+            },
+        }
+        if newline {
+            rendered.push('\n');
+        }
+    }
+    rendered
+}
+
+/// Render one line that isn't in a code block.
+fn block(rendered: &mut String, line: &str, theme: &Theme) {
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let rest = &line[indent.len()..];
+
+    let hashes = rest.bytes().take_while(|&b| b == b'#').count();
+    if (1..=6).contains(&hashes) && (rest.len() == hashes || rest[hashes..].starts_with(' ')) {
+        let text = rest[hashes..].trim();
+        // A closing run of #s is just decoration.
+        let text = match text.trim_end_matches('#') {
+            without if without.is_empty() || without.ends_with(' ') => without.trim_end(),
+            _ => text,
+        };
+        let heading = theme
+            .get(&format!("heading{}", hashes))
+            .cloned()
+            .unwrap_or_else(|| style(theme, "heading"));
+        inline(rendered, text, &heading, theme);
+        return;
+    }
+
+    if let Some(item) = list_marker(rest) {
+        rendered.push_str(indent);
+        let marker = if item.ordered { &rest[..item.len] } else { "•" };
+        push(rendered, &style(theme, "bullet"), marker);
+        rendered.push(' ');
+        inline(rendered, &rest[item.len + 1..], &Formatting::new(), theme);
+        return;
+    }
+
+    inline(rendered, line, &Formatting::new(), theme);
+}
+
+struct ListMarker {
+    ordered: bool,
+    /// How long the marker is, not counting the space after it.
+    len: usize,
+}
+
+/// The `-`, `*`, `+`, or `1.` / `1)` marker at the start of `text`, if it's a list item.
+fn list_marker(text: &str) -> Option<ListMarker> {
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    let item = match text.as_bytes().get(digits)? {
+        b'-' | b'*' | b'+' if digits == 0 => ListMarker { ordered: false, len: 1 },
+        b'.' | b')' if (1..=9).contains(&digits) => ListMarker { ordered: true, len: digits + 1 },
+        _ => return None,
+    };
+    text[item.len..].starts_with(' ').then_some(item)
+}
+
+/// The ```` ``` ```` or `~~~` (or longer) fence at the start of `text`, if it opens a code block.
+fn fence_at(text: &str) -> Option<&str> {
+    let mark = match text.as_bytes().first()? {
+        b'`' => '`',
+        b'~' => '~',
+        _ => return None,
+    };
+    let len = text.len() - text.trim_start_matches(mark).len();
+    // An info string with a backtick in it means this is inline code, not a fence.
+    (len >= 3 && !(mark == '`' && text[len..].contains('`'))).then(|| &text[..len])
+}
+
+/// Whether `text` is a fence that closes the code block `open` started.
+fn closes(text: &str, open: &str) -> bool {
+    text.starts_with(open) && text.trim_end().trim_start_matches(&open[..1]).is_empty()
+}
+
+/// Render the inline markup in `text`, with everything layered on top of `outer`.
+fn inline(rendered: &mut String, text: &str, outer: &Formatting, theme: &Theme) {
+    let mut plain = String::new();
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let c = rest.chars().next().unwrap_or_default();
+
+        if c == '\\' {
+            if let Some(escaped) = rest[1..].chars().next().filter(char::is_ascii_punctuation) {
+                plain.push(escaped);
+                i += 2;
+                continue;
+            }
+        }
+
+        if c == '`' {
+            let ticks = rest.len() - rest.trim_start_matches('`').len();
+            if let Some(end) = closing_ticks(&rest[ticks..], ticks) {
+                let code = &rest[ticks..ticks + end];
+                // One space on each side is padding, so code can start or end with a backtick.
+                let code = match code.strip_prefix(' ').and_then(|code| code.strip_suffix(' ')) {
+                    Some(inner) if !inner.trim().is_empty() => inner,
+                    _ => code,
+                };
+                push(rendered, outer, &plain);
+                plain.clear();
+                push(rendered, &outer.merge(&style(theme, "code")), code);
+                i += end + 2 * ticks;
+                continue;
+            }
+            plain.push_str(&rest[..ticks]);
+            i += ticks;
+            continue;
+        }
+
+        if c == '*' || c == '_' {
+            let double = rest[1..].starts_with(c);
+            let delimiter = &rest[..if double { 2 } else { 1 }];
+            if let Some(end) = closing_delimiter(text, i, delimiter) {
+                let name = if double { "strong" } else { "emphasis" };
+                push(rendered, outer, &plain);
+                plain.clear();
+                let inner = &text[i + delimiter.len()..end];
+                inline(rendered, inner, &outer.merge(&style(theme, name)), theme);
+                i = end + delimiter.len();
+                continue;
+            }
+            plain.push_str(delimiter);
+            i += delimiter.len();
+            continue;
+        }
+
+        plain.push(c);
+        i += c.len_utf8();
+    }
+    push(rendered, outer, &plain);
+}
+
+/// Where the run of exactly `ticks` backticks closing a code span starts in `text`.
+fn closing_ticks(text: &str, ticks: usize) -> Option<usize> {
+    let mut i = 0;
+    while let Some(start) = text[i..].find('`') {
+        let start = i + start;
+        let len = text[start..].len() - text[start..].trim_start_matches('`').len();
+        if len == ticks {
+            return Some(start);
+        }
+        i = start + len;
+    }
+    None
+}
+
+/// Where the `delimiter` closing the one at `start` in `text` is.
+/// 
+/// Like in CommonMark, an opening delimiter has to be followed by something other than a space,
+/// and a closing one preceded by something other than a space. Underscores also can't be inside
+/// a word, so `snake_case_names` stay as they are.
+fn closing_delimiter(text: &str, start: usize, delimiter: &str) -> Option<usize> {
+    let underscore = delimiter.starts_with('_');
+    let before = text[..start].chars().next_back();
+    let after = text[start + delimiter.len()..].chars().next()?;
+    if after.is_whitespace() || (underscore && before.is_some_and(char::is_alphanumeric)) {
+        return None;
+    }
+
+    let mut i = start + delimiter.len() + after.len_utf8();
+    while let Some(found) = text[i..].find(delimiter) {
+        let end = i + found;
+        let before = text[..end].chars().next_back();
+        let after = text[end + delimiter.len()..].chars().next();
+        // Don't mistake the start of `**` for a closing `*`.
+        let longer = after == delimiter.chars().next() || before == delimiter.chars().next();
+        let word_after = underscore && after.is_some_and(char::is_alphanumeric);
+        if !before.is_some_and(char::is_whitespace) && !longer && !word_after {
+            return Some(end);
+        }
+        i = end + delimiter.len();
+    }
+    None
+}
+
+/// The formatting for `name` in `theme`, or its default.
+fn style(theme: &Theme, name: &str) -> Formatting {
+    if let Some(formatting) = theme.get(name) {
+        return formatting.clone();
+    }
+    match name {
+        "heading" => Formatting::new().styles([Styles::Bold, Styles::Underline]).build(),
+        "strong" => Formatting::new().styles([Styles::Bold]).build(),
+        "emphasis" => Formatting::new().styles([Styles::Italic]).build(),
+        "code" => Formatting::new().foreground(Color::Yellow).build(),
+        _ => Formatting::new().foreground(Color::Cyan).build(),
+    }
+}
+
+fn push(rendered: &mut String, formatting: &Formatting, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    if formatting.has_codes() {
+        rendered.push_str(&formatting.apply_to(text));
+    } else {
+        rendered.push_str(text);
+    }
+}
@@ -0,0 +1,180 @@
+//! Inline styling with tags, e.g. `"<red><bold>Error:</bold></red> file not found"`.
+//! 
+//! A tag holds anything [`Formatting::from_spec`] understands, so `<bold red on white>` works
+//! too, and tags can be written with either `<...>` or `{...}`. Colors given as just a number or
+//! hex digits don't count though, since `{1}` and `<add>` are much more likely to be placeholders
+//! or words. Write them as `256:1` and `#aadddd` instead. A closing tag (`</red>`,
+//! `{/red}`) closes the matching open tag along with anything opened inside it, and `</>` or `{/}`
+//! closes the most recent one. Tags nest, with inner ones layered on top of outer ones.
+//! 
+//! With the `std` feature, names added to the registry with [`theme::register`] work as tags
+//! too, and take priority over colors and styles with the same name.
+//! 
+//! Anything that isn't a recognizable tag, like `<div>` or `{}`, is left in the text as is. To
+//! write a tag without it being applied, put a backslash in front of it: `\<red>`.
+//! 
+//! [`Formatting::from_spec`]: ../struct.Formatting.html#method.from_spec
+//! [`theme::register`]: ../theme/fn.register.html
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Formatting, Theme};
+
+/// Turn tagged text into text with escape codes.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let text = markup::render("<red><bold>Error:</bold></red> file not found");
+/// assert_eq!(text, "\x1B[31;1mError:\x1B[0m file not found");
+/// 
+/// let text = markup::render("{green}ok{/green} {bold}2{/} files");
+/// assert_eq!(text, "\x1B[32mok\x1B[0m \x1B[1m2\x1B[0m files");
+/// ```
+pub fn render(template: &str) -> String {
+    #[cfg(feature = "std")]
+    return crate::theme::with_registry(|registry| render_with(template, registry));
+    #[cfg(not(feature = "std"))]
+    render_with(template, &Theme::new())
+}
+
+/// Like [`render`], but tags can also be the names of styles in `theme`, e.g. `<error>`.
+/// 
+/// Theme names take priority over colors and styles with the same name. `theme` is used instead
+/// of the registry, not on top of it.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let theme = Theme::default();
+/// println!("{}", markup::render_with("<error>error:</error> file not found", &theme));
+/// ```
+pub fn render_with(template: &str, theme: &Theme) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    walk(template, theme, |formatting, text| match formatting {
+        Some(formatting) => rendered.push_str(&formatting.apply_to(text)),
+        None => rendered.push_str(text),
+    });
+    rendered
+}
+
+/// Remove the tags, leaving just the text.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// assert_eq!(markup::strip("<red>Error:</red> <b> is not a tag"), "Error: <b> is not a tag");
+/// ```
+pub fn strip(template: &str) -> String {
+    let mut stripped = String::with_capacity(template.len());
+    #[cfg(feature = "std")]
+    crate::theme::with_registry(|registry| walk(template, registry, |_, text| stripped.push_str(text)));
+    #[cfg(not(feature = "std"))]
+    walk(template, &Theme::new(), |_, text| stripped.push_str(text));
+    stripped
+}
+
+/// Split `template` into runs of text, calling `emit` with each one and the formatting of the
+/// tags it's inside of (if any).
+fn walk<'a>(template: &'a str, theme: &Theme, mut emit: impl FnMut(Option<&Formatting>, &'a str)) {
+    let mut open: Vec<(String, Formatting)> = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    while i < template.len() {
+        let rest = &template[i..];
+        if rest.starts_with("\\<") || rest.starts_with("\\{") {
+            flush(&mut emit, &open, &template[start..i]);
+            start = i + 1;
+            i += 2;
+            continue;
+        }
+        let tag = match tag_at(rest) {
+            Some(tag) => tag,
+            None => {
+                i += rest.chars().next().map_or(1, char::len_utf8);
+                continue;
+            }
+        };
+        let applied = match tag.strip_prefix('/') {
+            Some(name) => match find_open(&open, &normalize(name)) {
+                Some(position) => {
+                    flush(&mut emit, &open, &template[start..i]);
+                    open.truncate(position);
+                    true
+                }
+                None => false,
+            },
+            None => {
+                let name = normalize(tag);
+                let formatting = theme.get(&name).cloned().or_else(|| spec(&name));
+                match formatting {
+                    Some(formatting) if !name.is_empty() => {
+                        flush(&mut emit, &open, &template[start..i]);
+                        let layered = match open.last() {
+                            Some((_, outer)) => outer.merge(&formatting),
+                            None => formatting,
+                        };
+                        open.push((name, layered));
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        };
+        if applied {
+            start = i + tag.len() + 2;
+            i = start;
+        } else {
+            i += 1;
+        }
+    }
+    flush(&mut emit, &open, &template[start..]);
+}
+
+/// The formatting for a tag that isn't in the theme, if it's a spec without any bare numbers or
+/// hex colors.
+fn spec(name: &str) -> Option<Formatting> {
+    if name.split_whitespace().any(|word| word.bytes().all(|b| b.is_ascii_hexdigit())) {
+        return None;
+    }
+    Formatting::from_spec(name).ok()
+}
+
+/// The inside of a `<...>` or `{...}` tag at the start of `text`.
+fn tag_at(text: &str) -> Option<&str> {
+    let close = match text.chars().next()? {
+        '<' => '>',
+        '{' => '}',
+        _ => return None,
+    };
+    let end = text[1..].find([close, '<', '{', '\n'])? + 1;
+    text[end..].starts_with(close).then(|| &text[1..end])
+}
+
+/// Where the most recent open tag named `name` is (or just the most recent tag, if `name` is
+/// empty).
+fn find_open(open: &[(String, Formatting)], name: &str) -> Option<usize> {
+    if name.is_empty() {
+        open.len().checked_sub(1)
+    } else {
+        open.iter().rposition(|(open_name, _)| open_name == name)
+    }
+}
+
+fn flush<'a>(emit: &mut impl FnMut(Option<&Formatting>, &'a str), open: &[(String, Formatting)], text: &'a str) {
+    if !text.is_empty() {
+        emit(open.last().map(|(_, formatting)| formatting), text);
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_ascii_lowercase()
+}
@@ -0,0 +1,102 @@
+//! Ready-made sets of colors: the CSS named colors, and a few popular terminal themes.
+//! 
+//! There's also [`simulate`], for checking how colors look to people with color blindness.
+//! 
+//! [`simulate`]: fn.simulate.html
+
+pub mod dracula;
+pub mod gruvbox;
+pub mod nord;
+pub mod okabe_ito;
+pub mod solarized;
+pub mod web;
+
+#[cfg(feature = "std")]
+use crate::quantize::round_u8;
+#[cfg(feature = "std")]
+use crate::Color;
+
+/// The common kinds of color blindness, for [`simulate`].
+/// 
+/// [`simulate`]: fn.simulate.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorBlindness {
+    /// No red cones, so reds look dark and get mixed up with greens.
+    Protanopia,
+    /// No green cones, the most common kind, so reds and greens get mixed up.
+    Deuteranopia,
+    /// No blue cones, so blues get mixed up with greens, and yellows with pinks.
+    Tritanopia,
+    /// No color vision at all, just shades of gray.
+    Achromatopsia,
+}
+
+/// How `color` looks to someone with `blindness`, roughly.
+/// 
+/// This uses the [Machado et al.] model. Palette colors use xterm's default values, and the
+/// result is always `Color::RGB`, except that `Color::Default` stays as it is.
+/// 
+/// Needs the `std` feature.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::palette::{self, ColorBlindness};
+/// 
+/// // Bright red and green look almost the same yellowish brown.
+/// let red = palette::simulate(ColorBlindness::Deuteranopia, Color::BrightRed);
+/// let green = palette::simulate(ColorBlindness::Deuteranopia, Color::Green);
+/// println!("{} {}", "error".color(red), "ok".color(green));
+/// assert_eq!(palette::simulate(ColorBlindness::Achromatopsia, Color::White), Color::RGB { r: 229, g: 229, b: 229 });
+/// ```
+/// 
+/// [Machado et al.]: https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+#[cfg(feature = "std")]
+pub fn simulate(blindness: ColorBlindness, color: Color) -> Color {
+    let Some((r, g, b)) = color.rgb() else {
+        return color;
+    };
+    let linear = [r, g, b].map(to_linear);
+    let [r, g, b] = match blindness {
+        ColorBlindness::Protanopia => transform(linear, [
+            [0.152286, 1.052583, -0.204868],
+            [0.114503, 0.786281, 0.099216],
+            [-0.003882, -0.048116, 1.051998],
+        ]),
+        ColorBlindness::Deuteranopia => transform(linear, [
+            [0.367322, 0.860646, -0.227968],
+            [0.280085, 0.672501, 0.047413],
+            [-0.011820, 0.042940, 0.968881],
+        ]),
+        ColorBlindness::Tritanopia => transform(linear, [
+            [1.255528, -0.076749, -0.178779],
+            [-0.078411, 0.930809, 0.147602],
+            [0.004733, 0.691367, 0.303900],
+        ]),
+        ColorBlindness::Achromatopsia => {
+            let gray = 0.2126 * linear[0] + 0.7152 * linear[1] + 0.0722 * linear[2];
+            [gray; 3]
+        }
+    }
+    .map(from_linear);
+    Color::RGB { r, g, b }
+}
+
+#[cfg(feature = "std")]
+fn transform(rgb: [f32; 3], matrix: [[f32; 3]; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2])
+}
+
+#[cfg(feature = "std")]
+fn to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+#[cfg(feature = "std")]
+fn from_linear(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let encoded = if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+    round_u8(encoded * 255.0)
+}
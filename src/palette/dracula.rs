@@ -0,0 +1,36 @@
+//! [Dracula](https://draculatheme.com/), a dark theme.
+//! 
+//! # Example
+//! 
+//! ```
+//! use coloring::*;
+//! use coloring::palette::dracula;
+//! 
+//! let formatted_text = Formatting::new().foreground(dracula::PURPLE).background(dracula::BACKGROUND).apply_to("HI MOM");
+//! println!("{}", formatted_text);
+//! ```
+
+use crate::Color;
+
+/// `#282a36`
+pub const BACKGROUND: Color = Color::RGB { r: 40, g: 42, b: 54 };
+/// `#44475a`, also used for selections
+pub const CURRENT_LINE: Color = Color::RGB { r: 68, g: 71, b: 90 };
+/// `#f8f8f2`
+pub const FOREGROUND: Color = Color::RGB { r: 248, g: 248, b: 242 };
+/// `#6272a4`
+pub const COMMENT: Color = Color::RGB { r: 98, g: 114, b: 164 };
+/// `#8be9fd`
+pub const CYAN: Color = Color::RGB { r: 139, g: 233, b: 253 };
+/// `#50fa7b`
+pub const GREEN: Color = Color::RGB { r: 80, g: 250, b: 123 };
+/// `#ffb86c`
+pub const ORANGE: Color = Color::RGB { r: 255, g: 184, b: 108 };
+/// `#ff79c6`
+pub const PINK: Color = Color::RGB { r: 255, g: 121, b: 198 };
+/// `#bd93f9`
+pub const PURPLE: Color = Color::RGB { r: 189, g: 147, b: 249 };
+/// `#ff5555`
+pub const RED: Color = Color::RGB { r: 255, g: 85, b: 85 };
+/// `#f1fa8c`
+pub const YELLOW: Color = Color::RGB { r: 241, g: 250, b: 140 };
@@ -0,0 +1,70 @@
+//! [Gruvbox](https://github.com/morhetz/gruvbox), a retro, warm theme, in its dark version.
+//! 
+//! Each accent comes in a normal and a `BRIGHT_` version, like a terminal's sixteen colors.
+//! 
+//! # Example
+//! 
+//! ```
+//! use coloring::*;
+//! use coloring::palette::gruvbox;
+//! 
+//! let formatted_text = Formatting::new().foreground(gruvbox::BRIGHT_ORANGE).background(gruvbox::BG).apply_to("HI MOM");
+//! println!("{}", formatted_text);
+//! ```
+
+use crate::Color;
+
+/// `#1d2021`, the hard-contrast background
+pub const BG0_H: Color = Color::RGB { r: 29, g: 32, b: 33 };
+/// `#282828`, the background
+pub const BG: Color = Color::RGB { r: 40, g: 40, b: 40 };
+/// `#32302f`, the soft-contrast background
+pub const BG0_S: Color = Color::RGB { r: 50, g: 48, b: 47 };
+/// `#3c3836`
+pub const BG1: Color = Color::RGB { r: 60, g: 56, b: 54 };
+/// `#504945`
+pub const BG2: Color = Color::RGB { r: 80, g: 73, b: 69 };
+/// `#665c54`
+pub const BG3: Color = Color::RGB { r: 102, g: 92, b: 84 };
+/// `#7c6f64`
+pub const BG4: Color = Color::RGB { r: 124, g: 111, b: 100 };
+/// `#928374`
+pub const GRAY: Color = Color::RGB { r: 146, g: 131, b: 116 };
+/// `#ebdbb2`, text
+pub const FG: Color = Color::RGB { r: 235, g: 219, b: 178 };
+/// `#fbf1c7`
+pub const FG0: Color = Color::RGB { r: 251, g: 241, b: 199 };
+/// `#d5c4a1`
+pub const FG2: Color = Color::RGB { r: 213, g: 196, b: 161 };
+/// `#bdae93`
+pub const FG3: Color = Color::RGB { r: 189, g: 174, b: 147 };
+/// `#a89984`
+pub const FG4: Color = Color::RGB { r: 168, g: 153, b: 132 };
+/// `#cc241d`
+pub const RED: Color = Color::RGB { r: 204, g: 36, b: 29 };
+/// `#98971a`
+pub const GREEN: Color = Color::RGB { r: 152, g: 151, b: 26 };
+/// `#d79921`
+pub const YELLOW: Color = Color::RGB { r: 215, g: 153, b: 33 };
+/// `#458588`
+pub const BLUE: Color = Color::RGB { r: 69, g: 133, b: 136 };
+/// `#b16286`
+pub const PURPLE: Color = Color::RGB { r: 177, g: 98, b: 134 };
+/// `#689d6a`
+pub const AQUA: Color = Color::RGB { r: 104, g: 157, b: 106 };
+/// `#d65d0e`
+pub const ORANGE: Color = Color::RGB { r: 214, g: 93, b: 14 };
+/// `#fb4934`
+pub const BRIGHT_RED: Color = Color::RGB { r: 251, g: 73, b: 52 };
+/// `#b8bb26`
+pub const BRIGHT_GREEN: Color = Color::RGB { r: 184, g: 187, b: 38 };
+/// `#fabd2f`
+pub const BRIGHT_YELLOW: Color = Color::RGB { r: 250, g: 189, b: 47 };
+/// `#83a598`
+pub const BRIGHT_BLUE: Color = Color::RGB { r: 131, g: 165, b: 152 };
+/// `#d3869b`
+pub const BRIGHT_PURPLE: Color = Color::RGB { r: 211, g: 134, b: 155 };
+/// `#8ec07c`
+pub const BRIGHT_AQUA: Color = Color::RGB { r: 142, g: 192, b: 124 };
+/// `#fe8019`
+pub const BRIGHT_ORANGE: Color = Color::RGB { r: 254, g: 128, b: 25 };
@@ -0,0 +1,50 @@
+//! [Nord](https://www.nordtheme.com/), an arctic, blue-ish theme.
+//! 
+//! The colors keep Nord's own numbering: `NORD0` to `NORD3` ("Polar Night") are backgrounds,
+//! `NORD4` to `NORD6` ("Snow Storm") are text, `NORD7` to `NORD10` ("Frost") are the blues, and
+//! `NORD11` to `NORD15` ("Aurora") are the accents.
+//! 
+//! # Example
+//! 
+//! ```
+//! use coloring::*;
+//! use coloring::palette::nord;
+//! 
+//! let formatted_text = Formatting::new().foreground(nord::NORD8).background(nord::NORD0).apply_to("HI MOM");
+//! println!("{}", formatted_text);
+//! ```
+
+use crate::Color;
+
+/// `#2e3440`, the background
+pub const NORD0: Color = Color::RGB { r: 46, g: 52, b: 64 };
+/// `#3b4252`
+pub const NORD1: Color = Color::RGB { r: 59, g: 66, b: 82 };
+/// `#434c5e`
+pub const NORD2: Color = Color::RGB { r: 67, g: 76, b: 94 };
+/// `#4c566a`, comments
+pub const NORD3: Color = Color::RGB { r: 76, g: 86, b: 106 };
+/// `#d8dee9`, text
+pub const NORD4: Color = Color::RGB { r: 216, g: 222, b: 233 };
+/// `#e5e9f0`
+pub const NORD5: Color = Color::RGB { r: 229, g: 233, b: 240 };
+/// `#eceff4`
+pub const NORD6: Color = Color::RGB { r: 236, g: 239, b: 244 };
+/// `#8fbcbb`, teal
+pub const NORD7: Color = Color::RGB { r: 143, g: 188, b: 187 };
+/// `#88c0d0`, light blue
+pub const NORD8: Color = Color::RGB { r: 136, g: 192, b: 208 };
+/// `#81a1c1`, blue
+pub const NORD9: Color = Color::RGB { r: 129, g: 161, b: 193 };
+/// `#5e81ac`, dark blue
+pub const NORD10: Color = Color::RGB { r: 94, g: 129, b: 172 };
+/// `#bf616a`, red
+pub const NORD11: Color = Color::RGB { r: 191, g: 97, b: 106 };
+/// `#d08770`, orange
+pub const NORD12: Color = Color::RGB { r: 208, g: 135, b: 112 };
+/// `#ebcb8b`, yellow
+pub const NORD13: Color = Color::RGB { r: 235, g: 203, b: 139 };
+/// `#a3be8c`, green
+pub const NORD14: Color = Color::RGB { r: 163, g: 190, b: 140 };
+/// `#b48ead`, purple
+pub const NORD15: Color = Color::RGB { r: 180, g: 142, b: 173 };
@@ -0,0 +1,35 @@
+//! The [Okabe–Ito](https://jfly.uni-koeln.de/color/) colors, picked to stay distinguishable for
+//! people with the common kinds of color blindness.
+//! 
+//! [`Theme::make_colorblind_safe`] switches a theme over to these.
+//! 
+//! # Example
+//! 
+//! ```
+//! use coloring::*;
+//! use coloring::palette::okabe_ito;
+//! 
+//! println!("{} {}", "passed".color(okabe_ito::BLUISH_GREEN), "failed".color(okabe_ito::VERMILLION));
+//! ```
+//! 
+//! [`Theme::make_colorblind_safe`]: ../../struct.Theme.html#method.make_colorblind_safe
+
+use crate::Color;
+
+/// `#e69f00`
+pub const ORANGE: Color = Color::RGB { r: 230, g: 159, b: 0 };
+/// `#56b4e9`
+pub const SKY_BLUE: Color = Color::RGB { r: 86, g: 180, b: 233 };
+/// `#009e73`
+pub const BLUISH_GREEN: Color = Color::RGB { r: 0, g: 158, b: 115 };
+/// `#f0e442`
+pub const YELLOW: Color = Color::RGB { r: 240, g: 228, b: 66 };
+/// `#0072b2`
+pub const BLUE: Color = Color::RGB { r: 0, g: 114, b: 178 };
+/// `#d55e00`
+pub const VERMILLION: Color = Color::RGB { r: 213, g: 94, b: 0 };
+/// `#cc79a7`
+pub const REDDISH_PURPLE: Color = Color::RGB { r: 204, g: 121, b: 167 };
+
+/// All the colors above, for picking from.
+pub const ALL: [Color; 7] = [ORANGE, SKY_BLUE, BLUISH_GREEN, YELLOW, BLUE, VERMILLION, REDDISH_PURPLE];
@@ -0,0 +1,50 @@
+//! [Solarized](https://ethanschoonover.com/solarized/), by Ethan Schoonover.
+//! 
+//! The same sixteen colors make up both the dark and light versions: dark uses `BASE03` and
+//! `BASE02` for backgrounds and `BASE0` and `BASE1` for text, and light flips them to `BASE3` and
+//! `BASE2` with `BASE00` and `BASE01`.
+//! 
+//! # Example
+//! 
+//! ```
+//! use coloring::*;
+//! use coloring::palette::solarized;
+//! 
+//! let formatted_text = Formatting::new().foreground(solarized::YELLOW).background(solarized::BASE03).apply_to("HI MOM");
+//! println!("{}", formatted_text);
+//! ```
+
+use crate::Color;
+
+/// `#002b36`, the dark background
+pub const BASE03: Color = Color::RGB { r: 0, g: 43, b: 54 };
+/// `#073642`, dark highlighted background
+pub const BASE02: Color = Color::RGB { r: 7, g: 54, b: 66 };
+/// `#586e75`, dark comments, or light emphasized text
+pub const BASE01: Color = Color::RGB { r: 88, g: 110, b: 117 };
+/// `#657b83`, light body text
+pub const BASE00: Color = Color::RGB { r: 101, g: 123, b: 131 };
+/// `#839496`, dark body text
+pub const BASE0: Color = Color::RGB { r: 131, g: 148, b: 150 };
+/// `#93a1a1`, dark emphasized text, or light comments
+pub const BASE1: Color = Color::RGB { r: 147, g: 161, b: 161 };
+/// `#eee8d5`, light highlighted background
+pub const BASE2: Color = Color::RGB { r: 238, g: 232, b: 213 };
+/// `#fdf6e3`, the light background
+pub const BASE3: Color = Color::RGB { r: 253, g: 246, b: 227 };
+/// `#b58900`
+pub const YELLOW: Color = Color::RGB { r: 181, g: 137, b: 0 };
+/// `#cb4b16`
+pub const ORANGE: Color = Color::RGB { r: 203, g: 75, b: 22 };
+/// `#dc322f`
+pub const RED: Color = Color::RGB { r: 220, g: 50, b: 47 };
+/// `#d33682`
+pub const MAGENTA: Color = Color::RGB { r: 211, g: 54, b: 130 };
+/// `#6c71c4`
+pub const VIOLET: Color = Color::RGB { r: 108, g: 113, b: 196 };
+/// `#268bd2`
+pub const BLUE: Color = Color::RGB { r: 38, g: 139, b: 210 };
+/// `#2aa198`
+pub const CYAN: Color = Color::RGB { r: 42, g: 161, b: 152 };
+/// `#859900`
+pub const GREEN: Color = Color::RGB { r: 133, g: 153, b: 0 };
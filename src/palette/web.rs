@@ -0,0 +1,512 @@
+//! The named CSS colors, like `cornflowerblue` and `tomato`.
+//! 
+//! See [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/named-color) for the full list.
+//! Note that these are the CSS values: `web::RED` is `#ff0000`, which isn't necessarily what your
+//! terminal shows for `Color::Red`.
+//! 
+//! # Example
+//! 
+//! ```
+//! use coloring::*;
+//! use coloring::palette::web;
+//! 
+//! let formatted_text = Formatting::new().foreground(web::TOMATO).background(web::MIDNIGHTBLUE).apply_to("HI MOM");
+//! println!("{}", formatted_text);
+//! ```
+
+use crate::Color;
+
+/// `#f0f8ff`
+pub const ALICEBLUE: Color = Color::RGB { r: 240, g: 248, b: 255 };
+/// `#faebd7`
+pub const ANTIQUEWHITE: Color = Color::RGB { r: 250, g: 235, b: 215 };
+/// `#00ffff`
+pub const AQUA: Color = Color::RGB { r: 0, g: 255, b: 255 };
+/// `#7fffd4`
+pub const AQUAMARINE: Color = Color::RGB { r: 127, g: 255, b: 212 };
+/// `#f0ffff`
+pub const AZURE: Color = Color::RGB { r: 240, g: 255, b: 255 };
+/// `#f5f5dc`
+pub const BEIGE: Color = Color::RGB { r: 245, g: 245, b: 220 };
+/// `#ffe4c4`
+pub const BISQUE: Color = Color::RGB { r: 255, g: 228, b: 196 };
+/// `#000000`
+pub const BLACK: Color = Color::RGB { r: 0, g: 0, b: 0 };
+/// `#ffebcd`
+pub const BLANCHEDALMOND: Color = Color::RGB { r: 255, g: 235, b: 205 };
+/// `#0000ff`
+pub const BLUE: Color = Color::RGB { r: 0, g: 0, b: 255 };
+/// `#8a2be2`
+pub const BLUEVIOLET: Color = Color::RGB { r: 138, g: 43, b: 226 };
+/// `#a52a2a`
+pub const BROWN: Color = Color::RGB { r: 165, g: 42, b: 42 };
+/// `#deb887`
+pub const BURLYWOOD: Color = Color::RGB { r: 222, g: 184, b: 135 };
+/// `#5f9ea0`
+pub const CADETBLUE: Color = Color::RGB { r: 95, g: 158, b: 160 };
+/// `#7fff00`
+pub const CHARTREUSE: Color = Color::RGB { r: 127, g: 255, b: 0 };
+/// `#d2691e`
+pub const CHOCOLATE: Color = Color::RGB { r: 210, g: 105, b: 30 };
+/// `#ff7f50`
+pub const CORAL: Color = Color::RGB { r: 255, g: 127, b: 80 };
+/// `#6495ed`
+pub const CORNFLOWERBLUE: Color = Color::RGB { r: 100, g: 149, b: 237 };
+/// `#fff8dc`
+pub const CORNSILK: Color = Color::RGB { r: 255, g: 248, b: 220 };
+/// `#dc143c`
+pub const CRIMSON: Color = Color::RGB { r: 220, g: 20, b: 60 };
+/// `#00ffff`
+pub const CYAN: Color = Color::RGB { r: 0, g: 255, b: 255 };
+/// `#00008b`
+pub const DARKBLUE: Color = Color::RGB { r: 0, g: 0, b: 139 };
+/// `#008b8b`
+pub const DARKCYAN: Color = Color::RGB { r: 0, g: 139, b: 139 };
+/// `#b8860b`
+pub const DARKGOLDENROD: Color = Color::RGB { r: 184, g: 134, b: 11 };
+/// `#a9a9a9`
+pub const DARKGRAY: Color = Color::RGB { r: 169, g: 169, b: 169 };
+/// `#006400`
+pub const DARKGREEN: Color = Color::RGB { r: 0, g: 100, b: 0 };
+/// `#a9a9a9`
+pub const DARKGREY: Color = Color::RGB { r: 169, g: 169, b: 169 };
+/// `#bdb76b`
+pub const DARKKHAKI: Color = Color::RGB { r: 189, g: 183, b: 107 };
+/// `#8b008b`
+pub const DARKMAGENTA: Color = Color::RGB { r: 139, g: 0, b: 139 };
+/// `#556b2f`
+pub const DARKOLIVEGREEN: Color = Color::RGB { r: 85, g: 107, b: 47 };
+/// `#ff8c00`
+pub const DARKORANGE: Color = Color::RGB { r: 255, g: 140, b: 0 };
+/// `#9932cc`
+pub const DARKORCHID: Color = Color::RGB { r: 153, g: 50, b: 204 };
+/// `#8b0000`
+pub const DARKRED: Color = Color::RGB { r: 139, g: 0, b: 0 };
+/// `#e9967a`
+pub const DARKSALMON: Color = Color::RGB { r: 233, g: 150, b: 122 };
+/// `#8fbc8f`
+pub const DARKSEAGREEN: Color = Color::RGB { r: 143, g: 188, b: 143 };
+/// `#483d8b`
+pub const DARKSLATEBLUE: Color = Color::RGB { r: 72, g: 61, b: 139 };
+/// `#2f4f4f`
+pub const DARKSLATEGRAY: Color = Color::RGB { r: 47, g: 79, b: 79 };
+/// `#2f4f4f`
+pub const DARKSLATEGREY: Color = Color::RGB { r: 47, g: 79, b: 79 };
+/// `#00ced1`
+pub const DARKTURQUOISE: Color = Color::RGB { r: 0, g: 206, b: 209 };
+/// `#9400d3`
+pub const DARKVIOLET: Color = Color::RGB { r: 148, g: 0, b: 211 };
+/// `#ff1493`
+pub const DEEPPINK: Color = Color::RGB { r: 255, g: 20, b: 147 };
+/// `#00bfff`
+pub const DEEPSKYBLUE: Color = Color::RGB { r: 0, g: 191, b: 255 };
+/// `#696969`
+pub const DIMGRAY: Color = Color::RGB { r: 105, g: 105, b: 105 };
+/// `#696969`
+pub const DIMGREY: Color = Color::RGB { r: 105, g: 105, b: 105 };
+/// `#1e90ff`
+pub const DODGERBLUE: Color = Color::RGB { r: 30, g: 144, b: 255 };
+/// `#b22222`
+pub const FIREBRICK: Color = Color::RGB { r: 178, g: 34, b: 34 };
+/// `#fffaf0`
+pub const FLORALWHITE: Color = Color::RGB { r: 255, g: 250, b: 240 };
+/// `#228b22`
+pub const FORESTGREEN: Color = Color::RGB { r: 34, g: 139, b: 34 };
+/// `#ff00ff`
+pub const FUCHSIA: Color = Color::RGB { r: 255, g: 0, b: 255 };
+/// `#dcdcdc`
+pub const GAINSBORO: Color = Color::RGB { r: 220, g: 220, b: 220 };
+/// `#f8f8ff`
+pub const GHOSTWHITE: Color = Color::RGB { r: 248, g: 248, b: 255 };
+/// `#ffd700`
+pub const GOLD: Color = Color::RGB { r: 255, g: 215, b: 0 };
+/// `#daa520`
+pub const GOLDENROD: Color = Color::RGB { r: 218, g: 165, b: 32 };
+/// `#808080`
+pub const GRAY: Color = Color::RGB { r: 128, g: 128, b: 128 };
+/// `#008000`
+pub const GREEN: Color = Color::RGB { r: 0, g: 128, b: 0 };
+/// `#adff2f`
+pub const GREENYELLOW: Color = Color::RGB { r: 173, g: 255, b: 47 };
+/// `#808080`
+pub const GREY: Color = Color::RGB { r: 128, g: 128, b: 128 };
+/// `#f0fff0`
+pub const HONEYDEW: Color = Color::RGB { r: 240, g: 255, b: 240 };
+/// `#ff69b4`
+pub const HOTPINK: Color = Color::RGB { r: 255, g: 105, b: 180 };
+/// `#cd5c5c`
+pub const INDIANRED: Color = Color::RGB { r: 205, g: 92, b: 92 };
+/// `#4b0082`
+pub const INDIGO: Color = Color::RGB { r: 75, g: 0, b: 130 };
+/// `#fffff0`
+pub const IVORY: Color = Color::RGB { r: 255, g: 255, b: 240 };
+/// `#f0e68c`
+pub const KHAKI: Color = Color::RGB { r: 240, g: 230, b: 140 };
+/// `#e6e6fa`
+pub const LAVENDER: Color = Color::RGB { r: 230, g: 230, b: 250 };
+/// `#fff0f5`
+pub const LAVENDERBLUSH: Color = Color::RGB { r: 255, g: 240, b: 245 };
+/// `#7cfc00`
+pub const LAWNGREEN: Color = Color::RGB { r: 124, g: 252, b: 0 };
+/// `#fffacd`
+pub const LEMONCHIFFON: Color = Color::RGB { r: 255, g: 250, b: 205 };
+/// `#add8e6`
+pub const LIGHTBLUE: Color = Color::RGB { r: 173, g: 216, b: 230 };
+/// `#f08080`
+pub const LIGHTCORAL: Color = Color::RGB { r: 240, g: 128, b: 128 };
+/// `#e0ffff`
+pub const LIGHTCYAN: Color = Color::RGB { r: 224, g: 255, b: 255 };
+/// `#fafad2`
+pub const LIGHTGOLDENRODYELLOW: Color = Color::RGB { r: 250, g: 250, b: 210 };
+/// `#d3d3d3`
+pub const LIGHTGRAY: Color = Color::RGB { r: 211, g: 211, b: 211 };
+/// `#90ee90`
+pub const LIGHTGREEN: Color = Color::RGB { r: 144, g: 238, b: 144 };
+/// `#d3d3d3`
+pub const LIGHTGREY: Color = Color::RGB { r: 211, g: 211, b: 211 };
+/// `#ffb6c1`
+pub const LIGHTPINK: Color = Color::RGB { r: 255, g: 182, b: 193 };
+/// `#ffa07a`
+pub const LIGHTSALMON: Color = Color::RGB { r: 255, g: 160, b: 122 };
+/// `#20b2aa`
+pub const LIGHTSEAGREEN: Color = Color::RGB { r: 32, g: 178, b: 170 };
+/// `#87cefa`
+pub const LIGHTSKYBLUE: Color = Color::RGB { r: 135, g: 206, b: 250 };
+/// `#778899`
+pub const LIGHTSLATEGRAY: Color = Color::RGB { r: 119, g: 136, b: 153 };
+/// `#778899`
+pub const LIGHTSLATEGREY: Color = Color::RGB { r: 119, g: 136, b: 153 };
+/// `#b0c4de`
+pub const LIGHTSTEELBLUE: Color = Color::RGB { r: 176, g: 196, b: 222 };
+/// `#ffffe0`
+pub const LIGHTYELLOW: Color = Color::RGB { r: 255, g: 255, b: 224 };
+/// `#00ff00`
+pub const LIME: Color = Color::RGB { r: 0, g: 255, b: 0 };
+/// `#32cd32`
+pub const LIMEGREEN: Color = Color::RGB { r: 50, g: 205, b: 50 };
+/// `#faf0e6`
+pub const LINEN: Color = Color::RGB { r: 250, g: 240, b: 230 };
+/// `#ff00ff`
+pub const MAGENTA: Color = Color::RGB { r: 255, g: 0, b: 255 };
+/// `#800000`
+pub const MAROON: Color = Color::RGB { r: 128, g: 0, b: 0 };
+/// `#66cdaa`
+pub const MEDIUMAQUAMARINE: Color = Color::RGB { r: 102, g: 205, b: 170 };
+/// `#0000cd`
+pub const MEDIUMBLUE: Color = Color::RGB { r: 0, g: 0, b: 205 };
+/// `#ba55d3`
+pub const MEDIUMORCHID: Color = Color::RGB { r: 186, g: 85, b: 211 };
+/// `#9370db`
+pub const MEDIUMPURPLE: Color = Color::RGB { r: 147, g: 112, b: 219 };
+/// `#3cb371`
+pub const MEDIUMSEAGREEN: Color = Color::RGB { r: 60, g: 179, b: 113 };
+/// `#7b68ee`
+pub const MEDIUMSLATEBLUE: Color = Color::RGB { r: 123, g: 104, b: 238 };
+/// `#00fa9a`
+pub const MEDIUMSPRINGGREEN: Color = Color::RGB { r: 0, g: 250, b: 154 };
+/// `#48d1cc`
+pub const MEDIUMTURQUOISE: Color = Color::RGB { r: 72, g: 209, b: 204 };
+/// `#c71585`
+pub const MEDIUMVIOLETRED: Color = Color::RGB { r: 199, g: 21, b: 133 };
+/// `#191970`
+pub const MIDNIGHTBLUE: Color = Color::RGB { r: 25, g: 25, b: 112 };
+/// `#f5fffa`
+pub const MINTCREAM: Color = Color::RGB { r: 245, g: 255, b: 250 };
+/// `#ffe4e1`
+pub const MISTYROSE: Color = Color::RGB { r: 255, g: 228, b: 225 };
+/// `#ffe4b5`
+pub const MOCCASIN: Color = Color::RGB { r: 255, g: 228, b: 181 };
+/// `#ffdead`
+pub const NAVAJOWHITE: Color = Color::RGB { r: 255, g: 222, b: 173 };
+/// `#000080`
+pub const NAVY: Color = Color::RGB { r: 0, g: 0, b: 128 };
+/// `#fdf5e6`
+pub const OLDLACE: Color = Color::RGB { r: 253, g: 245, b: 230 };
+/// `#808000`
+pub const OLIVE: Color = Color::RGB { r: 128, g: 128, b: 0 };
+/// `#6b8e23`
+pub const OLIVEDRAB: Color = Color::RGB { r: 107, g: 142, b: 35 };
+/// `#ffa500`
+pub const ORANGE: Color = Color::RGB { r: 255, g: 165, b: 0 };
+/// `#ff4500`
+pub const ORANGERED: Color = Color::RGB { r: 255, g: 69, b: 0 };
+/// `#da70d6`
+pub const ORCHID: Color = Color::RGB { r: 218, g: 112, b: 214 };
+/// `#eee8aa`
+pub const PALEGOLDENROD: Color = Color::RGB { r: 238, g: 232, b: 170 };
+/// `#98fb98`
+pub const PALEGREEN: Color = Color::RGB { r: 152, g: 251, b: 152 };
+/// `#afeeee`
+pub const PALETURQUOISE: Color = Color::RGB { r: 175, g: 238, b: 238 };
+/// `#db7093`
+pub const PALEVIOLETRED: Color = Color::RGB { r: 219, g: 112, b: 147 };
+/// `#ffefd5`
+pub const PAPAYAWHIP: Color = Color::RGB { r: 255, g: 239, b: 213 };
+/// `#ffdab9`
+pub const PEACHPUFF: Color = Color::RGB { r: 255, g: 218, b: 185 };
+/// `#cd853f`
+pub const PERU: Color = Color::RGB { r: 205, g: 133, b: 63 };
+/// `#ffc0cb`
+pub const PINK: Color = Color::RGB { r: 255, g: 192, b: 203 };
+/// `#dda0dd`
+pub const PLUM: Color = Color::RGB { r: 221, g: 160, b: 221 };
+/// `#b0e0e6`
+pub const POWDERBLUE: Color = Color::RGB { r: 176, g: 224, b: 230 };
+/// `#800080`
+pub const PURPLE: Color = Color::RGB { r: 128, g: 0, b: 128 };
+/// `#663399`
+pub const REBECCAPURPLE: Color = Color::RGB { r: 102, g: 51, b: 153 };
+/// `#ff0000`
+pub const RED: Color = Color::RGB { r: 255, g: 0, b: 0 };
+/// `#bc8f8f`
+pub const ROSYBROWN: Color = Color::RGB { r: 188, g: 143, b: 143 };
+/// `#4169e1`
+pub const ROYALBLUE: Color = Color::RGB { r: 65, g: 105, b: 225 };
+/// `#8b4513`
+pub const SADDLEBROWN: Color = Color::RGB { r: 139, g: 69, b: 19 };
+/// `#fa8072`
+pub const SALMON: Color = Color::RGB { r: 250, g: 128, b: 114 };
+/// `#f4a460`
+pub const SANDYBROWN: Color = Color::RGB { r: 244, g: 164, b: 96 };
+/// `#2e8b57`
+pub const SEAGREEN: Color = Color::RGB { r: 46, g: 139, b: 87 };
+/// `#fff5ee`
+pub const SEASHELL: Color = Color::RGB { r: 255, g: 245, b: 238 };
+/// `#a0522d`
+pub const SIENNA: Color = Color::RGB { r: 160, g: 82, b: 45 };
+/// `#c0c0c0`
+pub const SILVER: Color = Color::RGB { r: 192, g: 192, b: 192 };
+/// `#87ceeb`
+pub const SKYBLUE: Color = Color::RGB { r: 135, g: 206, b: 235 };
+/// `#6a5acd`
+pub const SLATEBLUE: Color = Color::RGB { r: 106, g: 90, b: 205 };
+/// `#708090`
+pub const SLATEGRAY: Color = Color::RGB { r: 112, g: 128, b: 144 };
+/// `#708090`
+pub const SLATEGREY: Color = Color::RGB { r: 112, g: 128, b: 144 };
+/// `#fffafa`
+pub const SNOW: Color = Color::RGB { r: 255, g: 250, b: 250 };
+/// `#00ff7f`
+pub const SPRINGGREEN: Color = Color::RGB { r: 0, g: 255, b: 127 };
+/// `#4682b4`
+pub const STEELBLUE: Color = Color::RGB { r: 70, g: 130, b: 180 };
+/// `#d2b48c`
+pub const TAN: Color = Color::RGB { r: 210, g: 180, b: 140 };
+/// `#008080`
+pub const TEAL: Color = Color::RGB { r: 0, g: 128, b: 128 };
+/// `#d8bfd8`
+pub const THISTLE: Color = Color::RGB { r: 216, g: 191, b: 216 };
+/// `#ff6347`
+pub const TOMATO: Color = Color::RGB { r: 255, g: 99, b: 71 };
+/// `#40e0d0`
+pub const TURQUOISE: Color = Color::RGB { r: 64, g: 224, b: 208 };
+/// `#ee82ee`
+pub const VIOLET: Color = Color::RGB { r: 238, g: 130, b: 238 };
+/// `#f5deb3`
+pub const WHEAT: Color = Color::RGB { r: 245, g: 222, b: 179 };
+/// `#ffffff`
+pub const WHITE: Color = Color::RGB { r: 255, g: 255, b: 255 };
+/// `#f5f5f5`
+pub const WHITESMOKE: Color = Color::RGB { r: 245, g: 245, b: 245 };
+/// `#ffff00`
+pub const YELLOW: Color = Color::RGB { r: 255, g: 255, b: 0 };
+/// `#9acd32`
+pub const YELLOWGREEN: Color = Color::RGB { r: 154, g: 205, b: 50 };
+
+const NAMES: [(&str, Color); 148] = [
+    ("aliceblue", ALICEBLUE),
+    ("antiquewhite", ANTIQUEWHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHEDALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUEVIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADETBLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWERBLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARKBLUE),
+    ("darkcyan", DARKCYAN),
+    ("darkgoldenrod", DARKGOLDENROD),
+    ("darkgray", DARKGRAY),
+    ("darkgreen", DARKGREEN),
+    ("darkgrey", DARKGREY),
+    ("darkkhaki", DARKKHAKI),
+    ("darkmagenta", DARKMAGENTA),
+    ("darkolivegreen", DARKOLIVEGREEN),
+    ("darkorange", DARKORANGE),
+    ("darkorchid", DARKORCHID),
+    ("darkred", DARKRED),
+    ("darksalmon", DARKSALMON),
+    ("darkseagreen", DARKSEAGREEN),
+    ("darkslateblue", DARKSLATEBLUE),
+    ("darkslategray", DARKSLATEGRAY),
+    ("darkslategrey", DARKSLATEGREY),
+    ("darkturquoise", DARKTURQUOISE),
+    ("darkviolet", DARKVIOLET),
+    ("deeppink", DEEPPINK),
+    ("deepskyblue", DEEPSKYBLUE),
+    ("dimgray", DIMGRAY),
+    ("dimgrey", DIMGREY),
+    ("dodgerblue", DODGERBLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORALWHITE),
+    ("forestgreen", FORESTGREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOSTWHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREENYELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOTPINK),
+    ("indianred", INDIANRED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDERBLUSH),
+    ("lawngreen", LAWNGREEN),
+    ("lemonchiffon", LEMONCHIFFON),
+    ("lightblue", LIGHTBLUE),
+    ("lightcoral", LIGHTCORAL),
+    ("lightcyan", LIGHTCYAN),
+    ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+    ("lightgray", LIGHTGRAY),
+    ("lightgreen", LIGHTGREEN),
+    ("lightgrey", LIGHTGREY),
+    ("lightpink", LIGHTPINK),
+    ("lightsalmon", LIGHTSALMON),
+    ("lightseagreen", LIGHTSEAGREEN),
+    ("lightskyblue", LIGHTSKYBLUE),
+    ("lightslategray", LIGHTSLATEGRAY),
+    ("lightslategrey", LIGHTSLATEGREY),
+    ("lightsteelblue", LIGHTSTEELBLUE),
+    ("lightyellow", LIGHTYELLOW),
+    ("lime", LIME),
+    ("limegreen", LIMEGREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUMAQUAMARINE),
+    ("mediumblue", MEDIUMBLUE),
+    ("mediumorchid", MEDIUMORCHID),
+    ("mediumpurple", MEDIUMPURPLE),
+    ("mediumseagreen", MEDIUMSEAGREEN),
+    ("mediumslateblue", MEDIUMSLATEBLUE),
+    ("mediumspringgreen", MEDIUMSPRINGGREEN),
+    ("mediumturquoise", MEDIUMTURQUOISE),
+    ("mediumvioletred", MEDIUMVIOLETRED),
+    ("midnightblue", MIDNIGHTBLUE),
+    ("mintcream", MINTCREAM),
+    ("mistyrose", MISTYROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJOWHITE),
+    ("navy", NAVY),
+    ("oldlace", OLDLACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVEDRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGERED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALEGOLDENROD),
+    ("palegreen", PALEGREEN),
+    ("paleturquoise", PALETURQUOISE),
+    ("palevioletred", PALEVIOLETRED),
+    ("papayawhip", PAPAYAWHIP),
+    ("peachpuff", PEACHPUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDERBLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCAPURPLE),
+    ("red", RED),
+    ("rosybrown", ROSYBROWN),
+    ("royalblue", ROYALBLUE),
+    ("saddlebrown", SADDLEBROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDYBROWN),
+    ("seagreen", SEAGREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKYBLUE),
+    ("slateblue", SLATEBLUE),
+    ("slategray", SLATEGRAY),
+    ("slategrey", SLATEGREY),
+    ("snow", SNOW),
+    ("springgreen", SPRINGGREEN),
+    ("steelblue", STEELBLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITESMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOWGREEN),
+];
+
+/// Look up a CSS color by name, ignoring case.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::palette::web;
+/// 
+/// assert!(matches!(web::from_name("CornflowerBlue"), Some(Color::RGB { r: 100, g: 149, b: 237 })));
+/// assert!(web::from_name("octarine").is_none());
+/// ```
+pub fn from_name(name: &str) -> Option<Color> {
+    NAMES.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, color)| *color)
+}
+
+/// Like [`from_name`], but also ignoring `_`, `-`, and spaces, e.g. for `"dark orange"`. It's
+/// `const` so `Color`'s parsing can be.
+/// 
+/// [`from_name`]: fn.from_name.html
+pub(crate) const fn from_loose_name(name: &[u8]) -> Option<Color> {
+    let mut i = 0;
+    while i < NAMES.len() {
+        if is_loose_name(name, NAMES[i].0.as_bytes()) {
+            return Some(NAMES[i].1);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn is_loose_name(s: &[u8], name: &[u8]) -> bool {
+    let mut i = 0;
+    let mut j = 0;
+    while i < s.len() {
+        if matches!(s[i], b'_' | b'-' | b' ') {
+            i += 1;
+            continue;
+        }
+        if j == name.len() || s[i].to_ascii_lowercase() != name[j] {
+            return false;
+        }
+        i += 1;
+        j += 1;
+    }
+    j == name.len()
+}
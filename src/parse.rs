@@ -0,0 +1,170 @@
+//! Turning text with escape codes back into `Formatting`s.
+//! 
+//! This is the reverse of `apply_to`: it reads the SGR escape codes (colors and styles) in a string,
+//! e.g. captured from another program, and tells you which `Formatting` each piece of text had.
+//! Other escape sequences (cursor movement, etc.) are skipped.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use crate::ansi::{self, Token};
+use crate::quantize::BASIC_COLORS;
+use crate::{Color, Formatting, Styles};
+
+/// Split `text` into pieces of plain text along with the `Formatting` each one was shown with.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let text = format!("{} {}", "HI".red(), "MOM".bold());
+/// let spans = coloring::parse::spans(&text);
+/// assert_eq!(spans.len(), 3);
+/// assert_eq!(spans[2].1, "MOM");
+/// assert_eq!(spans[2].0.apply_to("MOM"), "MOM".bold().to_string());
+/// ```
+pub fn spans(text: &str) -> Vec<(Formatting, &str)> {
+    let mut spans = Vec::new();
+    let mut current = Formatting::new();
+    for token in ansi::tokens(text) {
+        match token {
+            Token::Text(text) => spans.push((current.clone(), text)),
+            Token::Escape(escape) => {
+                if let Some(params) = escape.strip_prefix("\x1B[").and_then(|e| e.strip_suffix('m')) {
+                    apply_sgr(&mut current, params);
+                }
+            }
+        }
+    }
+    spans
+}
+
+/// The `Formatting` that one SGR sequence's parameters (e.g. `"1;31"`) set up, starting from nothing.
+pub(crate) fn sgr_formatting(params: &str) -> Formatting {
+    let mut formatting = Formatting::new();
+    apply_sgr(&mut formatting, params);
+    formatting
+}
+
+/// Update `formatting` with the parameters of one SGR sequence, e.g. `"1;38;5;120"`.
+pub(crate) fn apply_sgr(formatting: &mut Formatting, params: &str) {
+    if params.is_empty() {
+        *formatting = Formatting::new();
+        return;
+    }
+    let mut params = params.split(';');
+    while let Some(param) = params.next() {
+        let mut parts = param.split(':');
+        let code: u8 = match parts.next().and_then(|code| code.parse().ok()) {
+            Some(code) => code,
+            None => continue,
+        };
+        match code {
+            0 => *formatting = Formatting::new(),
+            4 => match parts.next() {
+                Some("0") => turn_off(formatting, 24),
+                Some(kind) => {
+                    let style = style_for(&format!("4:{}", kind)).unwrap_or(Styles::Underline);
+                    formatting.add_style(style);
+                }
+                None => {
+                    formatting.add_style(Styles::Underline);
+                }
+            },
+            1..=9 | 51..=53 => {
+                if let Some(style) = style_for(&code.to_string()) {
+                    formatting.add_style(style);
+                }
+            }
+            10 | 22..=29 | 54 | 55 => turn_off(formatting, code),
+            11..=19 => {
+                turn_off(formatting, 10);
+                if let Some(style) = style_for(&code.to_string()) {
+                    formatting.add_style(style);
+                }
+            }
+            30..=37 => {
+                formatting.foreground(BASIC_COLORS[(code - 30) as usize]);
+            }
+            39 => {
+                formatting.foreground(Color::Default);
+            }
+            40..=47 => {
+                formatting.background(BASIC_COLORS[(code - 40) as usize]);
+            }
+            49 => {
+                formatting.background(Color::Default);
+            }
+            90..=97 => {
+                formatting.foreground(BASIC_COLORS[(code - 90 + 8) as usize]);
+            }
+            100..=107 => {
+                formatting.background(BASIC_COLORS[(code - 100 + 8) as usize]);
+            }
+            38 | 48 | 58 => {
+                // Either the `38;5;n` form or the `38:5:n` form.
+                let extended: Vec<&str> = if param.contains(':') {
+                    parts.collect()
+                } else {
+                    extended_params(&mut params)
+                };
+                // Underline colors (58) aren't kept, but their arguments still have to be skipped
+                // so they aren't read as styles.
+                if let Some(color) = extended_color(&extended) {
+                    match code {
+                        38 => {
+                            formatting.foreground(color);
+                        }
+                        48 => {
+                            formatting.background(color);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            // Resetting the underline color, which isn't kept either.
+            59 => {}
+            _ => {}
+        }
+    }
+}
+
+/// Take the parameters following a `38`, `48`, or `58`.
+fn extended_params<'a>(params: &mut impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    match params.next() {
+        Some("5") => ["5"].into_iter().chain(params.next()).collect(),
+        Some("2") => ["2"].into_iter().chain(params.take(3)).collect(),
+        Some(other) => vec![other],
+        None => Vec::new(),
+    }
+}
+
+fn extended_color(params: &[&str]) -> Option<Color> {
+    match params {
+        ["5", val] => val.parse().ok().map(Color::Colors256),
+        // The colon form may have an empty color space id before the channels.
+        ["2", r, g, b] | ["2", _, r, g, b] => Some(Color::RGB {
+            r: r.parse().ok()?,
+            g: g.parse().ok()?,
+            b: b.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+/// The style that `code` turns on, e.g. `"1"` or `"4:3"`.
+pub(crate) fn style_for(code: &str) -> Option<Styles> {
+    Styles::ALL.into_iter().find(|style| style.to_ansi_code() == code)
+}
+
+/// Remove every style that `code` turns off, e.g. both bold and faint for 22.
+fn turn_off(formatting: &mut Formatting, code: u8) {
+    for style in Styles::ALL {
+        if style.off_code() == Some(code) {
+            formatting.remove_style(style);
+        }
+    }
+}
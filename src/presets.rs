@@ -0,0 +1,68 @@
+//! Ready-made formatting for the messages most command-line tools print.
+//! 
+//! These match the `"error"`, `"warning"`, `"success"`, and `"info"` entries in
+//! `Theme::default()`, so you can start with these and move to a [`Theme`] once you need to let
+//! users change them.
+//! 
+//! # Example
+//! 
+//! ```
+//! use coloring::presets;
+//! 
+//! println!("{} could not open config", presets::error("error:"));
+//! println!("{} wrote 3 files", presets::success("done:"));
+//! ```
+//! 
+//! [`Theme`]: ../struct.Theme.html
+
+use crate::{Color, Formatting, StyledText, Styles};
+
+/// Bold red.
+pub const ERROR: Formatting = Formatting::new().with_foreground(Color::Red).with_style(Styles::Bold);
+/// Bold yellow.
+pub const WARNING: Formatting = Formatting::new().with_foreground(Color::Yellow).with_style(Styles::Bold);
+/// Green.
+pub const SUCCESS: Formatting = Formatting::new().with_foreground(Color::Green);
+/// Cyan.
+pub const INFO: Formatting = Formatting::new().with_foreground(Color::Cyan);
+
+/// `text` in [`ERROR`] formatting.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::presets;
+/// 
+/// # let _color = coloring::override_scope(coloring::ColorChoice::Always);
+/// assert_eq!(presets::error("oh no").to_string(), "\x1B[31;1moh no\x1B[0m");
+/// ```
+/// 
+/// [`ERROR`]: constant.ERROR.html
+pub fn error(text: &str) -> StyledText<'_> {
+    styled(&ERROR, text)
+}
+
+/// `text` in [`WARNING`] formatting.
+/// 
+/// [`WARNING`]: constant.WARNING.html
+pub fn warn(text: &str) -> StyledText<'_> {
+    styled(&WARNING, text)
+}
+
+/// `text` in [`SUCCESS`] formatting.
+/// 
+/// [`SUCCESS`]: constant.SUCCESS.html
+pub fn success(text: &str) -> StyledText<'_> {
+    styled(&SUCCESS, text)
+}
+
+/// `text` in [`INFO`] formatting.
+/// 
+/// [`INFO`]: constant.INFO.html
+pub fn info(text: &str) -> StyledText<'_> {
+    styled(&INFO, text)
+}
+
+fn styled<'a>(formatting: &'static Formatting, text: &'a str) -> StyledText<'a> {
+    StyledText { formatting, text, stream: None }
+}
@@ -0,0 +1,311 @@
+//! Asking the terminal what it supports, rather than guessing from environment variables.
+//! 
+//! [`query`] sends a few questions to the terminal and reads back the answers:
+//! 
+//!   - DECRQSS: after setting an RGB foreground, ask which colors are set. Terminals that really
+//!     support truecolor answer with the RGB color rather than a palette color.
+//!   - XTGETTCAP: ask for the `RGB` and `Smulx` (colored and curly underlines) terminfo
+//!     capabilities, which some terminals (e.g. kitty, foot, WezTerm) can answer.
+//!   - DA1 (primary device attributes), which practically every terminal answers. Its reply comes
+//!     last, so once it's in, so are the others.
+//! 
+//! [`background_color`] asks a different question, OSC 11, for the terminal's background color.
+//! 
+//! This takes over the terminal for a moment, so don't call it while other threads are reading
+//! from or writing to it. Needs the `probe` feature. Asking only works on Unix; elsewhere there's
+//! never an answer, so [`query`] and [`background_color`] return `Ok(None)`.
+//! 
+//! [`query`]: fn.query.html
+//! [`background_color`]: fn.background_color.html
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::time::Duration;
+use std::io;
+
+use crate::detect::ColorDepth;
+use crate::Color;
+
+/// The questions [`query`] sends, for when you want to do the I/O yourself, e.g. with an async
+/// runtime. Send this with the terminal in raw mode, read until the DA1 reply (`ESC [ ? ... c`)
+/// comes in, and hand everything to [`Report::parse`].
+/// 
+/// [`query`]: fn.query.html
+/// [`Report::parse`]: struct.Report.html#method.parse
+pub const QUERY: &str = concat!(
+    // DECRQSS for SGR, with an RGB color set (and reset again afterwards).
+    "\x1B[38;2;1;2;3m\x1BP$qm\x1B\\\x1B[0m",
+    // XTGETTCAP for `RGB` and `Smulx`, hex encoded.
+    "\x1BP+q524742;536d756c78\x1B\\",
+    // DA1.
+    "\x1B[c",
+);
+
+/// What the terminal said about itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Report {
+    /// The terminal kept the exact RGB color it was given, or has the `RGB` capability.
+    pub truecolor: bool,
+    /// The terminal has the `Smulx` capability, for curly, dotted, and dashed underlines.
+    pub styled_underlines: bool,
+    /// The parameters of the DA1 reply, e.g. `[62, 22]`. Terminals use these to say which VT
+    /// model they are, plus extensions like `4` for sixel graphics or `22` for color.
+    pub device_attributes: Vec<u16>,
+}
+
+impl Report {
+    /// Make sense of the terminal's replies to [`QUERY`], or `None` if there's no DA1 reply among
+    /// them.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::probe::Report;
+    /// 
+    /// let report = Report::parse(b"\x1BP1$r0;38:2::1:2:3m\x1B\\\x1B[?62;22c").unwrap();
+    /// assert!(report.truecolor);
+    /// assert_eq!(report.device_attributes, [62, 22]);
+    /// ```
+    /// 
+    /// [`QUERY`]: constant.QUERY.html
+    pub fn parse(reply: &[u8]) -> Option<Report> {
+        let text = String::from_utf8_lossy(reply);
+        let mut report = Report::default();
+        let mut answered = false;
+        let mut rest = &*text;
+        while let Some(start) = rest.find('\x1B') {
+            rest = &rest[start..];
+            if let Some(body) = rest.strip_prefix("\x1BP") {
+                // DCS replies run until ST.
+                let end = body.find("\x1B\\").unwrap_or(body.len());
+                report.read_dcs(&body[..end]);
+                rest = &body[end..];
+            } else if let Some(body) = rest.strip_prefix("\x1B[?") {
+                let end = body.find(|c: char| !(c.is_ascii_digit() || c == ';')).unwrap_or(body.len());
+                if body[end..].starts_with('c') {
+                    report.device_attributes = body[..end].split(';').filter_map(|p| p.parse().ok()).collect();
+                    answered = true;
+                }
+                rest = &body[end..];
+            } else {
+                rest = &rest[1..];
+            }
+        }
+        answered.then_some(report)
+    }
+
+    fn read_dcs(&mut self, body: &str) {
+        if let Some(sgr) = body.strip_prefix("1$r") {
+            // Either separator may be used, and some terminals add an empty color space ID.
+            let sgr = sgr.replace(':', ";");
+            if sgr.contains("38;2;1;2;3") || sgr.contains("38;2;;1;2;3") {
+                self.truecolor = true;
+            }
+        } else if let Some(capabilities) = body.strip_prefix("1+r") {
+            for capability in capabilities.split(';') {
+                let name = capability.split('=').next().unwrap_or("");
+                if name.eq_ignore_ascii_case("524742") {
+                    self.truecolor = true;
+                } else if name.eq_ignore_ascii_case("536d756c78") {
+                    self.styled_underlines = true;
+                }
+            }
+        }
+    }
+
+    /// `Some(ColorDepth::TrueColor)` if the terminal showed it supports truecolor, otherwise
+    /// `None`, since not answering doesn't prove anything. Fall back to [`TerminalCaps::detect`]
+    /// then.
+    /// 
+    /// [`TerminalCaps::detect`]: ../detect/struct.TerminalCaps.html#method.detect
+    pub fn depth(&self) -> Option<ColorDepth> {
+        self.truecolor.then_some(ColorDepth::TrueColor)
+    }
+}
+
+/// Ask the terminal (via `/dev/tty`) what it supports, waiting at most `timeout` for the answers.
+/// 
+/// Returns `Ok(None)` if there's no terminal, it doesn't answer in time, or this isn't Unix.
+/// 
+/// # Example
+/// 
+/// ```no_run
+/// use std::time::Duration;
+/// use coloring::detect::TerminalCaps;
+/// use coloring::probe;
+/// 
+/// let probed = probe::query(Duration::from_millis(100)).ok().flatten();
+/// let depth = probed.and_then(|report| report.depth()).unwrap_or(TerminalCaps::detect().depth);
+/// ```
+pub fn query(timeout: Duration) -> io::Result<Option<Report>> {
+    let reply = exchange(QUERY, timeout, |reply| Report::parse(reply).is_some())?;
+    Ok(reply.as_deref().and_then(Report::parse))
+}
+
+/// The question [`background_color`] sends: OSC 11 to ask for the background color, then DA1 so
+/// that terminals that don't understand OSC 11 still answer something. Hand the replies to
+/// [`parse_background`].
+/// 
+/// [`background_color`]: fn.background_color.html
+/// [`parse_background`]: fn.parse_background.html
+pub const BACKGROUND_QUERY: &str = "\x1B]11;?\x1B\\\x1B[c";
+
+/// Find the background color in the terminal's replies to [`BACKGROUND_QUERY`], e.g.
+/// `ESC ] 11 ; rgb:2828/2a2a/3636 ESC \`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::probe::parse_background;
+/// 
+/// let color = parse_background(b"\x1B]11;rgb:2828/2a2a/3636\x07\x1B[?62;22c");
+/// assert_eq!(color, Some(Color::RGB { r: 40, g: 42, b: 54 }));
+/// assert_eq!(parse_background(b"\x1B[?62;22c"), None);
+/// ```
+/// 
+/// [`BACKGROUND_QUERY`]: constant.BACKGROUND_QUERY.html
+pub fn parse_background(reply: &[u8]) -> Option<Color> {
+    let text = String::from_utf8_lossy(reply);
+    let body = &text[text.find("\x1B]11;")? + 5..];
+    let end = body.find(['\x07', '\x1B']).unwrap_or(body.len());
+    let mut channels = body[..end].strip_prefix("rgb:")?.split('/').map(|channel| {
+        // Each channel is 1 to 4 hex digits, scaled to however many there are.
+        let value = u32::from_str_radix(channel, 16).ok().filter(|_| (1..=4).contains(&channel.len()))?;
+        let max = (1u32 << (4 * channel.len())) - 1;
+        Some(((value * 255 + max / 2) / max) as u8)
+    });
+    let (r, g, b) = (channels.next()??, channels.next()??, channels.next()??);
+    Some(Color::RGB { r, g, b })
+}
+
+/// Ask the terminal (via `/dev/tty`) for its background color, waiting at most `timeout`.
+/// 
+/// Returns `Ok(None)` if there's no terminal, it doesn't say, or this isn't Unix. Most of the
+/// time you want [`detect::background_color`] instead, which falls back to `COLORFGBG`.
+/// 
+/// [`detect::background_color`]: ../detect/fn.background_color.html
+pub fn background_color(timeout: Duration) -> io::Result<Option<Color>> {
+    let reply = exchange(BACKGROUND_QUERY, timeout, |reply| Report::parse(reply).is_some())?;
+    Ok(reply.as_deref().and_then(parse_background))
+}
+
+/// Send `question` to the terminal in raw mode and read until `done` says the reply is complete.
+/// 
+/// `Ok(None)` if there's no terminal or no complete reply within `timeout`.
+#[cfg(unix)]
+pub(crate) fn exchange<F: Fn(&[u8]) -> bool>(question: &str, timeout: Duration, done: F) -> io::Result<Option<Vec<u8>>> {
+    use std::fs::OpenOptions;
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsRawFd;
+    use std::time::Instant;
+
+    let mut tty = match OpenOptions::new().read(true).write(true).open("/dev/tty") {
+        Ok(tty) => tty,
+        Err(_) => return Ok(None),
+    };
+    let _raw = RawMode::enable(tty.as_raw_fd())?;
+    tty.write_all(question.as_bytes())?;
+    tty.flush()?;
+
+    let deadline = Instant::now() + timeout;
+    let mut reply = Vec::new();
+    let mut buffer = [0; 256];
+    while !done(&reply) {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            discard_late_reply(&mut tty);
+            return Ok(None);
+        }
+        let mut poll = libc::pollfd { fd: tty.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+        // SAFETY: `poll` is a single valid `pollfd` that outlives the call.
+        let ready = unsafe { libc::poll(&mut poll, 1, left.as_millis().min(i32::MAX as u128) as i32) };
+        match ready {
+            -1 if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted => continue,
+            -1 => return Err(io::Error::last_os_error()),
+            0 => {
+                discard_late_reply(&mut tty);
+                return Ok(None);
+            }
+            _ => {}
+        }
+        let read = tty.read(&mut buffer)?;
+        reply.extend_from_slice(&buffer[..read]);
+    }
+    Ok(Some(reply))
+}
+
+/// How long to keep listening after giving up on a reply, in case it's on its way.
+#[cfg(unix)]
+const LATE_REPLY_GRACE: Duration = Duration::from_millis(100);
+
+/// Read and throw away whatever the terminal sends for a moment, then flush anything left
+/// unread, so a reply that shows up after the timeout doesn't end up in the program's stdin or
+/// at the shell prompt. Call this while still in raw mode.
+#[cfg(unix)]
+fn discard_late_reply(tty: &mut std::fs::File) {
+    use std::io::Read;
+    use std::os::unix::io::AsRawFd;
+    use std::time::Instant;
+
+    let deadline = Instant::now() + LATE_REPLY_GRACE;
+    let mut buffer = [0; 256];
+    loop {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            break;
+        }
+        let mut poll = libc::pollfd { fd: tty.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+        // SAFETY: `poll` is a single valid `pollfd` that outlives the call.
+        let ready = unsafe { libc::poll(&mut poll, 1, left.as_millis() as i32) };
+        if ready <= 0 || !matches!(tty.read(&mut buffer), Ok(read) if read > 0) {
+            break;
+        }
+    }
+    // SAFETY: Only drops input queued on the file descriptor, which is still open.
+    unsafe {
+        libc::tcflush(tty.as_raw_fd(), libc::TCIFLUSH);
+    }
+}
+
+#[cfg(not(unix))]
+pub(crate) fn exchange<F: Fn(&[u8]) -> bool>(_question: &str, _timeout: Duration, _done: F) -> io::Result<Option<Vec<u8>>> {
+    Ok(None)
+}
+
+/// Raw mode (no echo, no line buffering) until dropped, when the old settings come back.
+#[cfg(unix)]
+struct RawMode {
+    fd: i32,
+    original: libc::termios,
+}
+
+#[cfg(unix)]
+impl RawMode {
+    fn enable(fd: i32) -> io::Result<RawMode> {
+        // SAFETY: `termios` is plain old data, so zeroed is a valid value to be overwritten, and
+        // both calls only touch the struct passed in.
+        unsafe {
+            let mut original: libc::termios = core::mem::zeroed();
+            if libc::tcgetattr(fd, &mut original) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let mut raw = original;
+            raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+            if libc::tcsetattr(fd, libc::TCSANOW, &raw) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(RawMode { fd, original })
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        // SAFETY: Restores the settings read in `enable`, on the same file descriptor.
+        unsafe {
+            libc::tcsetattr(self.fd, libc::TCSANOW, &self.original);
+        }
+    }
+}
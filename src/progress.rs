@@ -0,0 +1,252 @@
+//! Single-line progress bars and spinners.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Color, Formatting};
+
+/// A progress bar that renders to a string, e.g. `█████░░░░░  50%`.
+/// 
+/// The filled part changes color as it fills up, red to yellow to green by default. In
+/// [`carriage_return`] mode each bar starts with `\r`, so printing them one after another (without
+/// newlines) keeps redrawing the same line.
+/// 
+/// # Example
+/// 
+/// ```
+/// use std::io::Write;
+/// use coloring::progress::ProgressBar;
+/// 
+/// let mut bar = ProgressBar::new(20);
+/// bar.carriage_return(true);
+/// for done in 0..=10 {
+///     print!("{}", bar.render_ratio(done, 10));
+///     std::io::stdout().flush().unwrap();
+/// }
+/// println!();
+/// ```
+/// 
+/// [`carriage_return`]: struct.ProgressBar.html#method.carriage_return
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgressBar {
+    width: usize,
+    fill: char,
+    empty: char,
+    stages: Vec<(u16, Formatting)>,
+    percentage: bool,
+    carriage_return: bool,
+}
+
+impl ProgressBar {
+    /// A bar `width` columns wide, not counting the percentage after it.
+    pub fn new(width: usize) -> ProgressBar {
+        ProgressBar {
+            width,
+            fill: '█',
+            empty: '░',
+            stages: Vec::from([
+                (0, Formatting::new().foreground(Color::Red).build()),
+                (3333, Formatting::new().foreground(Color::Yellow).build()),
+                (6667, Formatting::new().foreground(Color::Green).build()),
+            ]),
+            percentage: true,
+            carriage_return: false,
+        }
+    }
+
+    /// Set the character for the part that's done, `█` by default.
+    pub fn fill(&mut self, fill: char) -> &mut ProgressBar {
+        self.fill = fill;
+        self
+    }
+
+    /// Set the character for the part that isn't done yet, `░` by default.
+    pub fn empty(&mut self, empty: char) -> &mut ProgressBar {
+        self.empty = empty;
+        self
+    }
+
+    /// Set the formatting of the part that's done, each starting at a fraction from `0.0` to
+    /// `1.0`.
+    /// 
+    /// The default is red from `0.0`, yellow from a third, and green from two thirds. Pass a
+    /// single stage for one color throughout.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// use coloring::progress::ProgressBar;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let mut bar = ProgressBar::new(4);
+    /// bar.stages([(0.0, Formatting::new().foreground(Color::Blue).build())]).percentage(false);
+    /// assert_eq!(bar.render(0.5), "\x1B[34m██\x1B[0m░░");
+    /// ```
+    pub fn stages<I: IntoIterator<Item = (f32, Formatting)>>(&mut self, stages: I) -> &mut ProgressBar {
+        // Stored in hundredths of a percent so the bar can be `Eq`.
+        self.stages = stages.into_iter().map(|(from, formatting)| (basis_points(from), formatting)).collect();
+        self.stages.sort_by_key(|(from, _)| *from);
+        self
+    }
+
+    /// Show the percentage after the bar. On by default.
+    pub fn percentage(&mut self, percentage: bool) -> &mut ProgressBar {
+        self.percentage = percentage;
+        self
+    }
+
+    /// Start each bar with `\r`, so printing it again overwrites the last one.
+    pub fn carriage_return(&mut self, carriage_return: bool) -> &mut ProgressBar {
+        self.carriage_return = carriage_return;
+        self
+    }
+
+    /// The bar at `fraction` done, from `0.0` to `1.0`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::progress::ProgressBar;
+    /// 
+    /// # let _color = coloring::override_scope(coloring::ColorChoice::Always);
+    /// let bar = ProgressBar::new(10);
+    /// assert_eq!(bar.render(0.5), "\x1B[33m█████\x1B[0m░░░░░  50%");
+    /// ```
+    pub fn render(&self, fraction: f32) -> String {
+        let fraction = if fraction.is_nan() { 0.0 } else { fraction.clamp(0.0, 1.0) };
+        // Round down, so the bar is only full once everything's done.
+        let filled = ((fraction * self.width as f32) as usize).min(self.width);
+        let done: String = core::iter::repeat_n(self.fill, filled).collect();
+        let left: String = core::iter::repeat_n(self.empty, self.width - filled).collect();
+
+        let mut bar = String::new();
+        if self.carriage_return {
+            bar.push('\r');
+        }
+        let stage = self.stages.iter().rev().find(|(from, _)| *from <= basis_points(fraction));
+        match stage {
+            Some((_, formatting)) if filled > 0 => bar.push_str(&formatting.apply_to(&done)),
+            _ => bar.push_str(&done),
+        }
+        bar.push_str(&left);
+        if self.percentage {
+            bar.push_str(&format!(" {:>3}%", (fraction * 100.0) as u8));
+        }
+        bar
+    }
+
+    /// The bar at `done` out of `total`. A `total` of 0 counts as finished.
+    pub fn render_ratio(&self, done: u64, total: u64) -> String {
+        if total == 0 {
+            return self.render(1.0);
+        }
+        self.render(done.min(total) as f32 / total as f32)
+    }
+}
+
+/// Animation frames for a [`Spinner`]: `⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏`.
+/// 
+/// [`Spinner`]: struct.Spinner.html
+pub const DOTS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+/// Animation frames for a [`Spinner`]: `-\|/`, for terminals without Unicode.
+/// 
+/// [`Spinner`]: struct.Spinner.html
+pub const LINE: &[&str] = &["-", "\\", "|", "/"];
+/// Animation frames for a [`Spinner`]: `◜◠◝◞◡◟`.
+/// 
+/// [`Spinner`]: struct.Spinner.html
+pub const ARC: &[&str] = &["◜", "◠", "◝", "◞", "◡", "◟"];
+
+/// A styled, animated "still working" indicator.
+/// 
+/// It doesn't keep time itself: call [`frame`] with a counter that goes up on every tick of your
+/// own loop, and print the result over the last frame (e.g. after a `\r`).
+/// 
+/// # Example
+/// 
+/// ```
+/// use std::io::Write;
+/// use coloring::progress::Spinner;
+/// 
+/// let spinner = Spinner::dots();
+/// for tick in 0..20 {
+///     print!("\r{} loading", spinner.frame(tick));
+///     std::io::stdout().flush().unwrap();
+/// }
+/// println!();
+/// ```
+/// 
+/// [`frame`]: struct.Spinner.html#method.frame
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Spinner {
+    frames: &'static [&'static str],
+    formatting: Formatting,
+}
+
+impl Spinner {
+    /// A spinner going through `frames`, in cyan.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::progress::Spinner;
+    /// 
+    /// # let _color = coloring::override_scope(coloring::ColorChoice::Always);
+    /// let spinner = Spinner::new(&["◐", "◓", "◑", "◒"]);
+    /// assert_eq!(spinner.frame(5), "\x1B[36m◓\x1B[0m");
+    /// ```
+    pub fn new(frames: &'static [&'static str]) -> Spinner {
+        Spinner { frames, formatting: Formatting::new().foreground(Color::Cyan).build() }
+    }
+
+    /// A spinner using [`DOTS`].
+    /// 
+    /// [`DOTS`]: constant.DOTS.html
+    pub fn dots() -> Spinner {
+        Spinner::new(DOTS)
+    }
+
+    /// A spinner using [`LINE`].
+    /// 
+    /// [`LINE`]: constant.LINE.html
+    pub fn line() -> Spinner {
+        Spinner::new(LINE)
+    }
+
+    /// A spinner using [`ARC`].
+    /// 
+    /// [`ARC`]: constant.ARC.html
+    pub fn arc() -> Spinner {
+        Spinner::new(ARC)
+    }
+
+    /// Set the formatting of the frames.
+    pub fn formatting(&mut self, formatting: Formatting) -> &mut Spinner {
+        self.formatting = formatting;
+        self
+    }
+
+    /// How many frames there are before the animation repeats.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether there are no frames at all.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Frame number `i`, wrapping around after the last one. Empty if there are no frames.
+    pub fn frame(&self, i: usize) -> String {
+        if self.frames.is_empty() {
+            return String::new();
+        }
+        self.formatting.apply_to(self.frames[i % self.frames.len()])
+    }
+}
+
+fn basis_points(fraction: f32) -> u16 {
+    (fraction.clamp(0.0, 1.0) * 10000.0 + 0.5) as u16
+}
@@ -0,0 +1,446 @@
+//! Nearest-color math for squeezing colors into smaller palettes.
+
+use crate::detect::ColorDepth;
+use crate::{Color, Error};
+
+/// xterm's default values for the basic 16 colors.
+const BASIC_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+pub(crate) const BASIC_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+/// The channel values used by the 6x6x6 color cube (indices 16 - 231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+    /// Create an RGB color from hue, saturation, and lightness.
+    /// 
+    /// `hue` is in degrees and wraps around, so `-30.0`, `330.0`, and `690.0` are all the same.
+    /// `saturation` and `lightness` go from `0.0` to `1.0`. A lightness of `0.5` gives the most
+    /// vivid colors, with `0.0` being black and `1.0` white.
+    /// 
+    /// HSL makes it easy to design a palette: pick a few hues, then keep the saturation and
+    /// lightness the same across them so they look balanced.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let orange = Color::hsl(30.0, 1.0, 0.5);
+    /// assert!(matches!(orange, Color::RGB { r: 255, g: 128, b: 0 }));
+    /// println!("{}", Formatting::new().foreground(orange).apply_to("HI MOM"));
+    /// ```
+    pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+        let (r, g, b) = hsl_to_rgb(hue, saturation, lightness);
+        Color::RGB { r, g, b }
+    }
+
+    /// Create an RGB color from hue, saturation, and value (a.k.a. brightness).
+    /// 
+    /// `hue` is in degrees and wraps around. `saturation` and `value` go from `0.0` to `1.0`.
+    /// Unlike [`hsl`], a value of `1.0` with full saturation gives the most vivid colors.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let teal = Color::hsv(180.0, 1.0, 0.5);
+    /// assert!(matches!(teal, Color::RGB { r: 0, g: 128, b: 128 }));
+    /// ```
+    /// 
+    /// [`hsl`]: enum.Color.html#method.hsl
+    pub fn hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        let (r, g, b) = hsv_to_rgb(hue, saturation, value);
+        Color::RGB { r, g, b }
+    }
+
+    /// Same as [`hsl`].
+    /// 
+    /// [`hsl`]: enum.Color.html#method.hsl
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+        Color::hsl(hue, saturation, lightness)
+    }
+
+    /// A color from the 256 color palette's 6x6x6 color cube (indices 16 - 231), with each of
+    /// `r`, `g`, and `b` from 0 to 5.
+    /// 
+    /// Returns `None` if any of them is above 5.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert_eq!(Color::cube(5, 0, 0), Some(Color::Colors256(196)));
+    /// assert_eq!(Color::cube(0, 2, 5), Some(Color::Colors256(33)));
+    /// assert_eq!(Color::cube(6, 0, 0), None);
+    /// ```
+    pub const fn cube(r: u8, g: u8, b: u8) -> Option<Color> {
+        if r > 5 || g > 5 || b > 5 {
+            return None;
+        }
+        Some(Color::Colors256(16 + 36 * r + 6 * g + b))
+    }
+
+    /// Like [`cube`], but saying which channel was out of range.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert_eq!(Color::try_cube(5, 0, 0), Ok(Color::Colors256(196)));
+    /// assert_eq!(Color::try_cube(0, 6, 0).unwrap_err().to_string(), "color cube green 6 is out of range (0 to 5)");
+    /// ```
+    /// 
+    /// [`cube`]: enum.Color.html#method.cube
+    pub fn try_cube(r: u8, g: u8, b: u8) -> Result<Color, Error> {
+        for (what, value) in [("color cube red", r), ("color cube green", g), ("color cube blue", b)] {
+            if value > 5 {
+                return Err(Error::OutOfRange { what, value, max: 5 });
+            }
+        }
+        Ok(Color::Colors256(16 + 36 * r + 6 * g + b))
+    }
+
+    /// A shade of gray from the 256 color palette's grayscale ramp, from 0% (nearly black) to
+    /// 100% (nearly white).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert_eq!(Color::gray_percent(50.0), Color::Grayscale(12));
+    /// assert_eq!(Color::gray_percent(100.0).to_256(), Color::Colors256(255));
+    /// ```
+    pub fn gray_percent(percent: f32) -> Color {
+        Color::Grayscale(round_u8(percent.clamp(0.0, 100.0) / 100.0 * 23.0))
+    }
+
+    /// Convert to `Color::RGB`, using xterm's default values for the palette colors.
+    /// 
+    /// `Color::Default` stays as it is, since it's up to the terminal.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert!(matches!(Color::Colors256(196).to_rgb(), Color::RGB { r: 255, g: 0, b: 0 }));
+    /// ```
+    pub fn to_rgb(&self) -> Color {
+        match self.rgb() {
+            Some((r, g, b)) => Color::RGB { r, g, b },
+            None => Color::Default,
+        }
+    }
+
+    /// Convert to the nearest `Color::Colors256`.
+    /// 
+    /// RGB colors are matched against the 6x6x6 color cube (indices 16 - 231) and the grayscale
+    /// ramp (232 - 255), whichever is closer. The basic colors become their indices 0 - 15.
+    /// `Color::Default` stays as it is.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert!(matches!(Color::RGB { r: 0, g: 135, b: 255 }.to_256(), Color::Colors256(33)));
+    /// assert!(matches!(Color::BrightRed.to_256(), Color::Colors256(9)));
+    /// ```
+    pub fn to_256(&self) -> Color {
+        match *self {
+            Color::Default => Color::Default,
+            Color::Colors256(val) => Color::Colors256(val),
+            Color::Grayscale(level) => Color::Colors256(grayscale_index(level)),
+            Color::RGB { r, g, b } => Color::Colors256(nearest_256(r, g, b)),
+            basic => Color::Colors256(BASIC_COLORS.iter().position(|c| *c == basic).unwrap_or(0) as u8),
+        }
+    }
+
+    /// Convert to the nearest of the basic 16 colors (`Color::Red`, `Color::BrightRed`, etc.).
+    /// 
+    /// `Color::Default` stays as it is.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert!(matches!(Color::RGB { r: 250, g: 10, b: 10 }.to_basic(), Color::BrightRed));
+    /// ```
+    pub fn to_basic(&self) -> Color {
+        self.downgrade(ColorDepth::Basic)
+    }
+
+    /// The [relative luminance] of the color, from 0 (black) to 1 (white).
+    /// 
+    /// Palette colors use xterm's default values. `Color::Default` gives `None`, since it's up to
+    /// the terminal.
+    /// 
+    /// Needs the `std` feature.
+    /// 
+    /// [relative luminance]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    #[cfg(feature = "std")]
+    pub fn luminance(&self) -> Option<f32> {
+        let (r, g, b) = self.rgb()?;
+        let linear = |channel: u8| {
+            let c = channel as f32 / 255.0;
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+
+    /// The [WCAG contrast ratio] between two colors, from 1 (the same) to 21 (black and white).
+    /// 
+    /// `None` if either is `Color::Default`.
+    /// 
+    /// [WCAG contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    #[cfg(feature = "std")]
+    pub fn contrast_ratio(&self, other: &Color) -> Option<f32> {
+        let (a, b) = (self.luminance()?, other.luminance()?);
+        Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+    }
+
+    /// Black or white, whichever is easier to read on top of this color as a background.
+    /// 
+    /// Use this to keep text readable when the background is picked at runtime, e.g. badges
+    /// colored by a user's settings. `Color::Default` gives `Color::Default`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert_eq!(Color::Yellow.contrast_fg(), Color::Black);
+    /// assert_eq!(Color::RGB { r: 0, g: 0, b: 128 }.contrast_fg(), Color::BrightWhite);
+    /// 
+    /// let background = Color::Colors256(202);
+    /// let badge = Formatting::new().background(background).foreground(background.contrast_fg()).build();
+    /// println!("{}", badge.apply_to(" NEW "));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn contrast_fg(&self) -> Color {
+        let (Some(on_black), Some(on_white)) =
+            (self.contrast_ratio(&Color::Black), self.contrast_ratio(&Color::RGB { r: 255, g: 255, b: 255 }))
+        else {
+            return Color::Default;
+        };
+        if on_black >= on_white { Color::Black } else { Color::BrightWhite }
+    }
+
+    /// Blend toward white by `amount`, from 0 (unchanged) to 1 (white).
+    /// 
+    /// Palette colors are converted to `Color::RGB` first, using xterm's default values.
+    /// `Color::Default` stays as it is, since its actual color is up to the terminal.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let brand = Color::RGB { r: 0, g: 100, b: 200 };
+    /// assert_eq!(brand.lighten(0.5), Color::RGB { r: 128, g: 178, b: 228 });
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Color {
+        self.mix(&Color::RGB { r: 255, g: 255, b: 255 }, amount)
+    }
+
+    /// Blend toward black by `amount`, from 0 (unchanged) to 1 (black). See [`lighten`].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let brand = Color::RGB { r: 0, g: 100, b: 200 };
+    /// assert_eq!(brand.darken(0.25), Color::RGB { r: 0, g: 75, b: 150 });
+    /// ```
+    /// 
+    /// [`lighten`]: enum.Color.html#method.lighten
+    pub fn darken(&self, amount: f32) -> Color {
+        self.mix(&Color::RGB { r: 0, g: 0, b: 0 }, amount)
+    }
+
+    /// Blend with `other`, where `weight` is how much of `other` to use, from 0 (just this color)
+    /// to 1 (just `other`).
+    /// 
+    /// Palette colors are converted to `Color::RGB` first, using xterm's default values. If either
+    /// color is `Color::Default` this one is returned as it is.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let purple = Color::RGB { r: 255, g: 0, b: 0 }.mix(&Color::RGB { r: 0, g: 0, b: 255 }, 0.5);
+    /// assert_eq!(purple, Color::RGB { r: 128, g: 0, b: 128 });
+    /// ```
+    pub fn mix(&self, other: &Color, weight: f32) -> Color {
+        let (Some(from), Some(to)) = (self.rgb(), other.rgb()) else {
+            return *self;
+        };
+        let weight = weight.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| round_u8(from as f32 + (to as f32 - from as f32) * weight);
+        Color::RGB { r: channel(from.0, to.0), g: channel(from.1, to.1), b: channel(from.2, to.2) }
+    }
+
+    /// Convert to the closest color that a terminal with `depth` can show.
+    pub(crate) fn downgrade(self, depth: ColorDepth) -> Color {
+        match (depth, self) {
+            (_, Color::Default) => Color::Default,
+            (ColorDepth::NoColor, _) => Color::Default,
+            (ColorDepth::TrueColor, color) => color,
+            (ColorDepth::Ansi256, Color::RGB { r, g, b }) => Color::Colors256(nearest_256(r, g, b)),
+            (ColorDepth::Ansi256, color) => color,
+            (ColorDepth::Basic, Color::Colors256(val)) if val < 16 => BASIC_COLORS[val as usize],
+            (ColorDepth::Basic, color) => match color.rgb() {
+                Some((r, g, b)) => BASIC_COLORS[nearest_basic(r, g, b)],
+                None => color,
+            },
+        }
+    }
+
+    /// The RGB values for this color, using xterm's defaults for the palette colors.
+    pub(crate) fn rgb(self) -> Option<(u8, u8, u8)> {
+        match self {
+            Color::Default => None,
+            Color::RGB { r, g, b } => Some((r, g, b)),
+            Color::Colors256(val) => Some(rgb_256(val)),
+            Color::Grayscale(level) => Some(rgb_256(grayscale_index(level))),
+            basic => BASIC_COLORS.iter().position(|&c| c == basic).map(|i| BASIC_RGB[i]),
+        }
+    }
+}
+
+/// The 256 color palette index of a `Color::Grayscale` level.
+pub(crate) fn grayscale_index(level: u8) -> u8 {
+    232 + level.min(23)
+}
+
+fn rgb_256(val: u8) -> (u8, u8, u8) {
+    match val {
+        0..=15 => BASIC_RGB[val as usize],
+        16..=231 => {
+            let i = val - 16;
+            (CUBE_LEVELS[(i / 36) as usize], CUBE_LEVELS[((i / 6) % 6) as usize], CUBE_LEVELS[(i % 6) as usize])
+        }
+        232..=255 => {
+            let level = 8 + 10 * (val - 232);
+            (level, level, level)
+        }
+    }
+}
+
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |v: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, &level)| (level as i32 - v as i32).abs())
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    };
+    let cube = 16 + 36 * cube_index(r) + 6 * cube_index(g) + cube_index(b);
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = if average < 8 {
+        232
+    } else {
+        232 + ((average - 8) / 10).min(23) as u8
+    };
+
+    if distance(rgb_256(gray), (r, g, b)) < distance(rgb_256(cube), (r, g, b)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn nearest_basic(r: u8, g: u8, b: u8) -> usize {
+    BASIC_RGB
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, &rgb)| distance(rgb, (r, g, b)))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// `hue` is in degrees and wraps around, `saturation` and `lightness` go from `0.0` to `1.0`.
+pub(crate) fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> (u8, u8, u8) {
+    let saturation = saturation.clamp(0.0, 1.0);
+    let lightness = lightness.clamp(0.0, 1.0);
+
+    // Every HSL color is also an HSV color.
+    let value = lightness + saturation * lightness.min(1.0 - lightness);
+    let saturation = if value == 0.0 { 0.0 } else { 2.0 * (1.0 - lightness / value) };
+    hsv_to_rgb(hue, saturation, value)
+}
+
+/// `hue` is in degrees and wraps around, `saturation` and `value` go from `0.0` to `1.0`.
+pub(crate) fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> (u8, u8, u8) {
+    let hue = if hue % 360.0 < 0.0 { hue % 360.0 + 360.0 } else { hue % 360.0 };
+    let saturation = saturation.clamp(0.0, 1.0);
+    let value = value.clamp(0.0, 1.0);
+
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+    let channel = |v: f32| round_u8((v + m) * 255.0);
+    (channel(r), channel(g), channel(b))
+}
+
+/// Round a non-negative `value` to the nearest `u8`, saturating. `f32::round` needs `std`.
+pub(crate) fn round_u8(value: f32) -> u8 {
+    (value + 0.5) as u8
+}
@@ -0,0 +1,289 @@
+//! Rendering styled text to formats other than escape codes.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::text::char_width;
+use crate::{parse, Color, Formatting, Styles};
+
+/// How wide one column is in [`svg`], in pixels.
+const CELL_WIDTH: usize = 9;
+/// How tall one line is in [`svg`], in pixels.
+const LINE_HEIGHT: usize = 18;
+/// The space around the text in [`svg`], in pixels.
+const PADDING: usize = 9;
+/// The colors [`svg`] uses for `Color::Default`: xterm's black and white.
+const SVG_BACKGROUND: &str = "#000000";
+const SVG_FOREGROUND: &str = "#e5e5e5";
+
+/// Convert text with escape codes into HTML, with each styled piece in a
+/// `<span style="...">` with inline CSS.
+/// 
+/// The text itself is HTML-escaped. Newlines are kept as is, so put the result in a `<pre>`
+/// (or something with `white-space: pre`) to keep the layout. Colors from the 16 color and 256
+/// color palettes use xterm's default values. For input that doesn't fit in memory, or to use CSS
+/// classes instead, see [`convert::to_html`].
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let text = format!("{} <b>", "HI".red().bold());
+/// assert_eq!(
+///     coloring::render::html(&text),
+///     "<span style=\"color:#cd0000;font-weight:bold\">HI</span> &lt;b&gt;"
+/// );
+/// ```
+/// 
+/// [`convert::to_html`]: ../convert/fn.to_html.html
+pub fn html(text: &str) -> String {
+    let mut html = String::with_capacity(text.len());
+    for (formatting, text) in parse::spans(text) {
+        write_html(&mut html, &formatting, text);
+    }
+    html
+}
+
+/// Draw text with escape codes as an SVG image of a terminal, e.g. for a screenshot in a README
+/// that stays sharp and up to date.
+/// 
+/// Every character takes up one column (two for wide ones) of a monospaced grid, with a
+/// rectangle behind each piece of text that has a background color. Bold, faint, italic,
+/// underlines, strikethrough, overline, invert, and invisible are shown. The default colors
+/// are white on black, and palette colors use xterm's default values.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let text = format!("{} 3 tests\n{}", "PASS".black().on_green(), "done".bold());
+/// let svg = coloring::render::svg(&text);
+/// assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"126\" height=\"54\""));
+/// assert!(svg.contains("<rect x=\"9\" y=\"9\" width=\"36\" height=\"18\" fill=\"#00cd00\"/>"));
+/// ```
+pub fn svg(text: &str) -> String {
+    let mut body = String::new();
+    let (mut row, mut column, mut columns) = (0, 0, 0);
+    for (formatting, text) in parse::spans(text) {
+        let mut lines = text.split('\n');
+        if let Some(first) = lines.next() {
+            column += write_svg_run(&mut body, &formatting, first, row, column);
+        }
+        for line in lines {
+            columns = columns.max(column);
+            row += 1;
+            column = write_svg_run(&mut body, &formatting, line, row, 0);
+        }
+    }
+    columns = columns.max(column);
+    // A trailing newline doesn't start another line.
+    let rows = if column == 0 && row > 0 { row } else { row + 1 };
+
+    let (width, height) = (columns * CELL_WIDTH + 2 * PADDING, rows * LINE_HEIGHT + 2 * PADDING);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" \
+         font-family=\"monospace\" font-size=\"15\">\n<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+        SVG_BACKGROUND,
+        w = width,
+        h = height,
+    );
+    svg.push_str(&body);
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Draw one piece of a line, returning how many columns it took up.
+fn write_svg_run(svg: &mut String, formatting: &Formatting, text: &str, row: usize, column: usize) -> usize {
+    let width: usize = text.chars().map(char_width).sum();
+    if width == 0 {
+        return 0;
+    }
+    let (x, y) = (PADDING + column * CELL_WIDTH, PADDING + row * LINE_HEIGHT);
+    let styles = formatting.effective_styles();
+    let (fg, bg) = formatting.effective_colors();
+    let (mut fg, mut bg) = (hex(fg), hex(bg));
+    if styles.contains(Styles::Invert) {
+        let swapped = (bg.unwrap_or_else(|| SVG_BACKGROUND.to_string()), fg.unwrap_or_else(|| SVG_FOREGROUND.to_string()));
+        fg = Some(swapped.0);
+        bg = Some(swapped.1);
+    }
+
+    if let Some(bg) = bg {
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            x, y, width * CELL_WIDTH, LINE_HEIGHT, bg
+        );
+    }
+    if styles.contains(Styles::Invisible) || text.chars().all(|c| c == ' ') {
+        return width;
+    }
+
+    // `textLength` keeps the text on the grid even if the font's widths are a little off.
+    let _ = write!(
+        svg,
+        "<text x=\"{}\" y=\"{}\" textLength=\"{}\" fill=\"{}\" xml:space=\"preserve\"",
+        x,
+        y + LINE_HEIGHT - 5,
+        width * CELL_WIDTH,
+        fg.as_deref().unwrap_or(SVG_FOREGROUND)
+    );
+    if styles.contains(Styles::Bold) {
+        svg.push_str(" font-weight=\"bold\"");
+    }
+    if styles.contains(Styles::Italic) {
+        svg.push_str(" font-style=\"italic\"");
+    }
+    if styles.contains(Styles::Faint) {
+        svg.push_str(" opacity=\"0.5\"");
+    }
+    let decorations: Vec<&str> = styles
+        .iter()
+        .filter_map(|style| match style {
+            Styles::Underline
+            | Styles::DoubleUnderline
+            | Styles::CurlyUnderline
+            | Styles::DottedUnderline
+            | Styles::DashedUnderline => Some("underline"),
+            Styles::Strikethrough => Some("line-through"),
+            Styles::Overline => Some("overline"),
+            _ => None,
+        })
+        .fold(Vec::new(), |mut decorations, decoration| {
+            if !decorations.contains(&decoration) {
+                decorations.push(decoration);
+            }
+            decorations
+        });
+    if !decorations.is_empty() {
+        let _ = write!(svg, " text-decoration=\"{}\"", decorations.join(" "));
+    }
+    svg.push('>');
+    escape_html(svg, text);
+    svg.push_str("</text>\n");
+    width
+}
+
+impl Formatting {
+    /// Like [`apply_to`], but produces HTML with inline CSS instead of escape codes.
+    /// 
+    /// See [`render::html`] for the details.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let html = Formatting::new().foreground(Color::RGB { r: 255, g: 0, b: 170 }).apply_to_html("HI MOM");
+    /// assert_eq!(html, "<span style=\"color:#ff00aa\">HI MOM</span>");
+    /// ```
+    /// 
+    /// [`apply_to`]: struct.Formatting.html#method.apply_to
+    /// [`render::html`]: render/fn.html.html
+    pub fn apply_to_html(&self, text: &str) -> String {
+        let mut html = String::with_capacity(text.len());
+        write_html(&mut html, self, text);
+        html
+    }
+}
+
+fn write_html(html: &mut String, formatting: &Formatting, text: &str) {
+    let css = css(formatting);
+    if css.is_empty() {
+        escape_html(html, text);
+    } else {
+        let _ = write!(html, "<span style=\"{}\">", css);
+        escape_html(html, text);
+        html.push_str("</span>");
+    }
+}
+
+/// The inline CSS equivalent to a `Formatting`.
+pub(crate) fn css(formatting: &Formatting) -> String {
+    let (mut fg, mut bg) = formatting.effective_colors();
+    let styles = formatting.effective_styles();
+    let has = |style: Styles| styles.contains(style);
+
+    let mut properties: Vec<String> = Vec::new();
+    if has(Styles::Invert) {
+        if fg.is_set() && bg.is_set() {
+            core::mem::swap(&mut fg, &mut bg);
+        } else {
+            properties.push("filter:invert(100%)".to_string());
+        }
+    }
+    if let Some(hex) = hex(fg) {
+        properties.push(format!("color:{}", hex));
+    }
+    if let Some(hex) = hex(bg) {
+        properties.push(format!("background-color:{}", hex));
+    }
+    if has(Styles::Bold) {
+        properties.push("font-weight:bold".to_string());
+    }
+    if has(Styles::Faint) {
+        properties.push("opacity:0.5".to_string());
+    }
+    if has(Styles::Italic) {
+        properties.push("font-style:italic".to_string());
+    }
+
+    let mut decorations = Vec::new();
+    let mut decoration_style = None;
+    for style in styles.iter() {
+        let (decoration, line_style) = match style {
+            Styles::Underline => ("underline", None),
+            Styles::DoubleUnderline => ("underline", Some("double")),
+            Styles::CurlyUnderline => ("underline", Some("wavy")),
+            Styles::DottedUnderline => ("underline", Some("dotted")),
+            Styles::DashedUnderline => ("underline", Some("dashed")),
+            Styles::Strikethrough => ("line-through", None),
+            Styles::Overline => ("overline", None),
+            Styles::Blink | Styles::RapidBlink => ("blink", None),
+            _ => continue,
+        };
+        if !decorations.contains(&decoration) {
+            decorations.push(decoration);
+        }
+        decoration_style = decoration_style.or(line_style);
+    }
+    if !decorations.is_empty() {
+        properties.push(format!("text-decoration:{}", decorations.join(" ")));
+    }
+    if let Some(line_style) = decoration_style {
+        properties.push(format!("text-decoration-style:{}", line_style));
+    }
+    if has(Styles::Frame) || has(Styles::Encircle) {
+        properties.push("border:1px solid".to_string());
+    }
+    if has(Styles::Encircle) {
+        properties.push("border-radius:50%".to_string());
+    }
+    if has(Styles::Invisible) {
+        properties.push("visibility:hidden".to_string());
+    }
+    properties.join(";")
+}
+
+fn hex(color: Color) -> Option<String> {
+    color.rgb().map(|(r, g, b)| format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+pub(crate) fn escape_html(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+}
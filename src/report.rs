@@ -0,0 +1,216 @@
+//! Error reports for the end of `main`: the error, what caused it, hints on fixing it, and
+//! optionally a backtrace, colored and indented.
+//! 
+//! The styles come from a [`Theme`], under these names:
+//! 
+//!   - `"error"` for the `error:` headline. Bold red if it's not in the theme.
+//!   - `"cause"` for the `caused by:` labels. Yellow if it's not in the theme.
+//!   - `"hint"` for hints. Cyan if it's not in the theme.
+//!   - `"backtrace"` for the backtrace. Faint if it's not in the theme.
+//! 
+//! Needs the `std` feature.
+//! 
+//! [`Theme`]: ../struct.Theme.html
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+
+use crate::{Color, Formatting, Styles, Theme};
+
+/// A report for `error` and its causes, using the styles in the registry (see
+/// [`theme::register`]) or the defaults listed in the [module docs].
+/// 
+/// For hints or a backtrace, use a [`Report`].
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let error = std::fs::read("/no/such/file").unwrap_err();
+/// eprint!("{}", report::render(&error));
+/// ```
+/// 
+/// [`theme::register`]: ../theme/fn.register.html
+/// [module docs]: index.html
+/// [`Report`]: struct.Report.html
+pub fn render(error: &dyn Error) -> String {
+    Report::new(error).render()
+}
+
+/// A report for an error, with hints and a backtrace if you add them.
+/// 
+/// It's shown like this, with the causes taken from [`Error::source`]:
+/// 
+/// ```text
+/// error: couldn't load the config
+///   caused by: couldn't read settings.toml
+///     caused by: No such file or directory (os error 2)
+/// hint: run `app init` to create one
+/// backtrace:
+///   ...
+/// ```
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let error = "12x".parse::<u8>().unwrap_err();
+/// let text = report::Report::new(&error)
+///     .hint("the port has to be a number")
+///     .theme(Theme::new())
+///     .render();
+/// assert_eq!(
+///     text,
+///     "\x1B[31;1merror:\x1B[0m invalid digit found in string\n\x1B[36mhint: the port has to be a number\x1B[0m\n"
+/// );
+/// ```
+/// 
+/// [`Error::source`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.source
+pub struct Report<'a> {
+    error: &'a dyn Error,
+    hints: Vec<String>,
+    backtrace: Option<String>,
+    theme: Option<Theme>,
+}
+
+impl<'a> Report<'a> {
+    /// A report for `error`, using the registry's styles.
+    pub fn new(error: &'a dyn Error) -> Report<'a> {
+        Report { error, hints: Vec::new(), backtrace: None, theme: None }
+    }
+
+    /// Add a hint, shown after the causes.
+    pub fn hint(&mut self, hint: impl Into<String>) -> &mut Report<'a> {
+        self.hints.push(hint.into());
+        self
+    }
+
+    /// Show `backtrace` at the end, if it was captured (see [`Backtrace::capture`]).
+    /// 
+    /// [`Backtrace::capture`]: https://doc.rust-lang.org/std/backtrace/struct.Backtrace.html#method.capture
+    pub fn backtrace(&mut self, backtrace: &Backtrace) -> &mut Report<'a> {
+        self.backtrace = match backtrace.status() {
+            BacktraceStatus::Captured => Some(backtrace.to_string()),
+            _ => None,
+        };
+        self
+    }
+
+    /// Use the styles in `theme` instead of the registry's.
+    pub fn theme(&mut self, theme: Theme) -> &mut Report<'a> {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// The report as text with escape codes, with a newline after every line.
+    pub fn render(&self) -> String {
+        match &self.theme {
+            Some(theme) => self.render_with(theme),
+            None => crate::theme::with_registry(|registry| self.render_with(registry)),
+        }
+    }
+
+    fn render_with(&self, theme: &Theme) -> String {
+        let mut report = String::new();
+        push_message(&mut report, &style(theme, "error"), "error:", &self.error.to_string(), 0);
+
+        let cause = style(theme, "cause");
+        let mut depth = 1;
+        let mut source = self.error.source();
+        while let Some(error) = source {
+            push_message(&mut report, &cause, "caused by:", &error.to_string(), 2 * depth);
+            source = error.source();
+            depth += 1;
+        }
+
+        let hint = style(theme, "hint");
+        for text in &self.hints {
+            push_lines(&mut report, &hint, &["hint: ", text].concat(), 0, "hint: ".len());
+        }
+
+        if let Some(backtrace) = &self.backtrace {
+            let backtrace_style = style(theme, "backtrace");
+            push_lines(&mut report, &backtrace_style, "backtrace:", 0, 0);
+            push_lines(&mut report, &backtrace_style, backtrace.trim_end(), 2, 0);
+        }
+        report
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+impl fmt::Debug for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Report")
+            .field("error", &self.error)
+            .field("hints", &self.hints)
+            .field("backtrace", &self.backtrace.is_some())
+            .finish()
+    }
+}
+
+/// Push `label` styled with `formatting`, then `message`, indented by `indent`. Lines after the
+/// first line up with the start of the message.
+fn push_message(report: &mut String, formatting: &Formatting, label: &str, message: &str, indent: usize) {
+    let mut lines = message.lines();
+    report.extend(core::iter::repeat_n(' ', indent));
+    push_styled(report, formatting, label);
+    if let Some(first) = lines.next() {
+        report.push(' ');
+        report.push_str(first);
+    }
+    report.push('\n');
+    for line in lines {
+        push_line(report, line, indent + label.len() + 1);
+    }
+}
+
+/// Push each line of `text` styled with `formatting`, indented by `indent`, or by `indent` plus
+/// `hanging` after the first line.
+fn push_lines(report: &mut String, formatting: &Formatting, text: &str, indent: usize, hanging: usize) {
+    for (i, line) in text.lines().enumerate() {
+        let indent = if i == 0 { indent } else { indent + hanging };
+        report.extend(core::iter::repeat_n(' ', indent));
+        push_styled(report, formatting, line);
+        report.push('\n');
+    }
+}
+
+fn push_line(report: &mut String, line: &str, indent: usize) {
+    if !line.is_empty() {
+        report.extend(core::iter::repeat_n(' ', indent));
+        report.push_str(line);
+    }
+    report.push('\n');
+}
+
+fn push_styled(report: &mut String, formatting: &Formatting, text: &str) {
+    if formatting.has_codes() && !text.is_empty() {
+        report.push_str(&formatting.apply_to(text));
+    } else {
+        report.push_str(text);
+    }
+}
+
+/// The formatting for `name` in `theme`, or its default.
+fn style(theme: &Theme, name: &str) -> Formatting {
+    if let Some(formatting) = theme.get(name) {
+        return formatting.clone();
+    }
+    match name {
+        "error" => Formatting::new().foreground(Color::Red).styles([Styles::Bold]).build(),
+        "cause" => Formatting::new().foreground(Color::Yellow).build(),
+        "hint" => Formatting::new().foreground(Color::Cyan).build(),
+        _ => Formatting::new().styles([Styles::Faint]).build(),
+    }
+}
@@ -0,0 +1,85 @@
+//! Coloring program output line by line with regular expressions, like `grc`.
+//! 
+//! Needs the `regex` feature.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::io::{self, BufRead, Write};
+
+use regex::Regex;
+
+use crate::{stream, text, Formatting};
+
+/// An ordered list of patterns and the formatting for their matches.
+/// 
+/// Where matches of different rules overlap, the earlier rule wins (its colors are on top).
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::rules::Rules;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let mut rules = Rules::new();
+/// rules
+///     .add(r"\bERROR\b", Formatting::new().foreground(Color::Red).bold().build())?
+///     .add(r"\d+ms", Formatting::new().foreground(Color::Cyan).build())?;
+/// 
+/// assert_eq!(rules.colorize("ERROR after 35ms"), "\x1B[31;1mERROR\x1B[0m after \x1B[36m35ms\x1B[0m");
+/// # Ok::<(), regex::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Rules {
+    rules: Vec<(Regex, Formatting)>,
+}
+
+impl Rules {
+    /// No rules yet.
+    pub fn new() -> Rules {
+        Rules::default()
+    }
+
+    /// Add a rule coloring matches of `pattern` with `formatting`. Fails if `pattern` isn't a
+    /// valid regular expression.
+    pub fn add(&mut self, pattern: &str, formatting: Formatting) -> Result<&mut Rules, regex::Error> {
+        Ok(self.add_regex(Regex::new(pattern)?, formatting))
+    }
+
+    /// Add a rule with an already compiled `Regex`.
+    pub fn add_regex(&mut self, regex: Regex, formatting: Formatting) -> &mut Rules {
+        self.rules.push((regex, formatting));
+        self
+    }
+
+    /// Apply every rule to `line`.
+    pub fn colorize(&self, line: &str) -> String {
+        // Going backwards puts the earlier rules' colors on top of the later ones'.
+        let mut colored = String::from(line);
+        for (regex, formatting) in self.rules.iter().rev() {
+            colored = text::highlight_regex(&colored, regex, formatting);
+        }
+        colored
+    }
+
+    /// Color everything from `reader` line by line, writing it to `writer`, e.g. to color the
+    /// output of another program piped into yours.
+    /// 
+    /// This is the same as [`stream::process`].
+    /// 
+    /// # Example
+    /// 
+    /// ```no_run
+    /// use coloring::*;
+    /// use coloring::rules::Rules;
+    /// 
+    /// let mut rules = Rules::new();
+    /// rules.add("warning", Formatting::new().foreground(Color::Yellow).build()).unwrap();
+    /// rules.colorize_reader(std::io::stdin().lock(), std::io::stdout().lock()).unwrap();
+    /// ```
+    /// 
+    /// [`stream::process`]: ../stream/fn.process.html
+    pub fn colorize_reader<R: BufRead, W: Write>(&self, reader: R, writer: W) -> io::Result<()> {
+        stream::process(reader, writer, self)
+    }
+}
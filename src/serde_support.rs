@@ -0,0 +1,80 @@
+//! `Serialize` and `Deserialize` for `Color` and `Styles`, using their human-readable names.
+//! 
+//! `Formatting` derives its implementations, so it looks like
+//! `{ "fg": "red", "bg": "#ff00aa", "styles": ["bold"] }`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::style_set::StyleSet;
+use crate::{spec, Color, Styles};
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut name = String::new();
+        spec::write_color(&mut name, *self).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&name)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+struct ColorVisitor;
+
+impl Visitor<'_> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(r##"a color such as "red", "bright_cyan", "256:120", or "#ff00aa""##)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Color, E> {
+        v.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+impl Serialize for Styles {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(spec::style_name(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Styles {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Styles, D::Error> {
+        deserializer.deserialize_str(StylesVisitor)
+    }
+}
+
+struct StylesVisitor;
+
+impl Visitor<'_> for StylesVisitor {
+    type Value = Styles;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(r#"a style such as "bold" or "underline""#)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Styles, E> {
+        spec::parse_style(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+impl Serialize for StyleSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for StyleSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<StyleSet, D::Error> {
+        Vec::<Styles>::deserialize(deserializer).map(|styles| styles.into_iter().collect())
+    }
+}
@@ -0,0 +1,562 @@
+//! Converting colors and styles to and from human-readable strings.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::{self, Vec};
+use core::error;
+use core::fmt;
+use core::iter::Peekable;
+use core::str::FromStr;
+
+use crate::{palette, Color, Error, Formatting, Styles};
+
+const COLOR_NAMES: [(Color, &str); 17] = [
+    (Color::Default, "default"),
+    (Color::Black, "black"),
+    (Color::Red, "red"),
+    (Color::Green, "green"),
+    (Color::Yellow, "yellow"),
+    (Color::Blue, "blue"),
+    (Color::Magenta, "magenta"),
+    (Color::Cyan, "cyan"),
+    (Color::White, "white"),
+    (Color::BrightBlack, "bright_black"),
+    (Color::BrightRed, "bright_red"),
+    (Color::BrightGreen, "bright_green"),
+    (Color::BrightYellow, "bright_yellow"),
+    (Color::BrightBlue, "bright_blue"),
+    (Color::BrightMagenta, "bright_magenta"),
+    (Color::BrightCyan, "bright_cyan"),
+    (Color::BrightWhite, "bright_white"),
+];
+
+const STYLE_NAMES: [(Styles, &str); 27] = [
+    (Styles::Reset, "reset"),
+    (Styles::Bold, "bold"),
+    (Styles::Faint, "faint"),
+    (Styles::Italic, "italic"),
+    (Styles::Underline, "underline"),
+    (Styles::Blink, "blink"),
+    (Styles::Invert, "invert"),
+    (Styles::Invisible, "invisible"),
+    (Styles::Strikethrough, "strikethrough"),
+    (Styles::DoubleUnderline, "double_underline"),
+    (Styles::CurlyUnderline, "curly_underline"),
+    (Styles::DottedUnderline, "dotted_underline"),
+    (Styles::DashedUnderline, "dashed_underline"),
+    (Styles::RapidBlink, "rapid_blink"),
+    (Styles::Overline, "overline"),
+    (Styles::Frame, "frame"),
+    (Styles::Encircle, "encircle"),
+    (Styles::DefaultFont, "default_font"),
+    (Styles::AltFont1, "alt_font_1"),
+    (Styles::AltFont2, "alt_font_2"),
+    (Styles::AltFont3, "alt_font_3"),
+    (Styles::AltFont4, "alt_font_4"),
+    (Styles::AltFont5, "alt_font_5"),
+    (Styles::AltFont6, "alt_font_6"),
+    (Styles::AltFont7, "alt_font_7"),
+    (Styles::AltFont8, "alt_font_8"),
+    (Styles::AltFont9, "alt_font_9"),
+];
+
+/// Write a color in the canonical form that `FromStr` reads back:
+/// `"red"`, `"bright_cyan"`, `"256:120"`, `"gray:12"`, or `"#ff00aa"`.
+pub(crate) fn write_color<W: fmt::Write>(w: &mut W, color: Color) -> fmt::Result {
+    match color {
+        Color::Colors256(val) => write!(w, "256:{}", val),
+        Color::Grayscale(level) => write!(w, "gray:{}", level.min(23)),
+        Color::RGB { r, g, b } => write!(w, "#{:02x}{:02x}{:02x}", r, g, b),
+        named => {
+            let name = COLOR_NAMES
+                .iter()
+                .find(|(c, _)| *c == named)
+                .map(|(_, name)| *name)
+                .unwrap_or_default();
+            w.write_str(name)
+        }
+    }
+}
+
+/// The error returned when a string isn't a recognizable [`Color`].
+/// 
+/// [`Color`]: enum.Color.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color: {:?}", self.input)
+    }
+}
+
+impl error::Error for ParseColorError {}
+
+/// Parse a color from a name (`"red"`, `"bright_cyan"`), hex (`"#ffcc00"`, `"ffcc00"`, `"#fc0"`),
+/// `"rgb(255, 204, 0)"`, a 256-color index (`"120"`, `"256:120"`), or a grayscale level from 0 to
+/// 23 (`"gray:12"` or `"grey:12"`). Three-digit hex needs the `#`, so words like `"bad"` aren't
+/// colors.
+/// 
+/// Names ignore case, and `-` or spaces work in place of `_`. Any of the CSS color names that
+/// aren't also one of the basic colors (e.g. `"tomato"`) work too, see [`palette::web`].
+/// 
+/// [`palette::web`]: palette/web/index.html
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let color: Color = "bright-cyan".parse().unwrap();
+/// let formatted_text = Formatting::new().foreground(color).apply_to("HI MOM");
+/// println!("{}", formatted_text);
+/// 
+/// assert!("octarine".parse::<Color>().is_err());
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Color, ParseColorError> {
+        parse_color(s.trim()).ok_or_else(|| ParseColorError { input: s.to_string() })
+    }
+}
+
+/// Shows the color the way a person would write it: `"red"`, `"bright cyan"`, `"256:120"`,
+/// `"gray:12"`, or `"#ff00aa"`. These all parse back into the same color.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// assert_eq!(Color::BrightCyan.to_string(), "bright cyan");
+/// assert_eq!(format!("{}", Color::RGB { r: 255, g: 0, b: 170 }), "#ff00aa");
+/// assert_eq!("bright cyan".parse::<Color>().unwrap(), Color::BrightCyan);
+/// ```
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut name = String::new();
+        write_color(&mut name, *self)?;
+        f.pad(&name.replace('_', " "))
+    }
+}
+
+/// The color literal in a `#[derive(Styled)]` attribute. It's `const`, so the derive can build its
+/// `Formatting`s when they're compiled, and a literal that isn't a color fails the build.
+#[cfg(feature = "derive")]
+pub const fn color_literal(s: &str) -> Color {
+    match parse_color(s.trim_ascii()) {
+        Some(color) => color,
+        None => panic!("invalid color in #[styled]"),
+    }
+}
+
+// Parsing is `const` for `color_literal`, so it works on bytes without allocating or using
+// iterators.
+const fn parse_color(s: &str) -> Option<Color> {
+    let s = s.as_bytes();
+    if let Some(val) = strip_prefix(s, b"256:") {
+        return match parse_u8(val) {
+            Some(val) => Some(Color::Colors256(val)),
+            None => None,
+        };
+    }
+    if let Some(level) = match strip_prefix(s, b"gray:") {
+        Some(level) => Some(level),
+        None => strip_prefix(s, b"grey:"),
+    } {
+        return match parse_u8(level) {
+            Some(level) if level <= 23 => Some(Color::Grayscale(level)),
+            _ => None,
+        };
+    }
+    if let Some(hex) = strip_prefix(s, b"#") {
+        return parse_hex(hex);
+    }
+    if let Some(rgb) = strip_prefix(s, b"rgb(") {
+        if let Some((b')', rgb)) = rgb.split_last() {
+            return parse_rgb(rgb);
+        }
+    }
+    if !s.is_empty() && s.len() <= 3 && all_digits(s) {
+        return match parse_u8(s) {
+            Some(val) => Some(Color::Colors256(val)),
+            None => None,
+        };
+    }
+    let mut i = 0;
+    while i < COLOR_NAMES.len() {
+        if is_name(s, COLOR_NAMES[i].1.as_bytes()) {
+            return Some(COLOR_NAMES[i].0);
+        }
+        i += 1;
+    }
+    if let Some(color) = palette::web::from_loose_name(s) {
+        return Some(color);
+    }
+    // Short hex needs the `#`, or words like "bad" would be colors.
+    if s.len() == 6 {
+        return parse_hex(s);
+    }
+    None
+}
+
+/// Whether `s` is `name`, ignoring case and with `-` or spaces in place of `_`.
+const fn is_name(s: &[u8], name: &[u8]) -> bool {
+    if s.len() != name.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < s.len() {
+        let c = match s[i] {
+            b'-' | b' ' => b'_',
+            c => c.to_ascii_lowercase(),
+        };
+        if c != name[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn strip_prefix<'a>(s: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if s.len() < prefix.len() {
+        return None;
+    }
+    let (start, rest) = s.split_at(prefix.len());
+    let mut i = 0;
+    while i < prefix.len() {
+        if start[i] != prefix[i] {
+            return None;
+        }
+        i += 1;
+    }
+    Some(rest)
+}
+
+const fn all_digits(s: &[u8]) -> bool {
+    let mut i = 0;
+    while i < s.len() {
+        if !s[i].is_ascii_digit() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Like `u8::from_str`, which isn't `const`.
+const fn parse_u8(s: &[u8]) -> Option<u8> {
+    let s = match s.split_first() {
+        Some((b'+', rest)) => rest,
+        _ => s,
+    };
+    if s.is_empty() || !all_digits(s) {
+        return None;
+    }
+    let mut val: u32 = 0;
+    let mut i = 0;
+    while i < s.len() {
+        val = val * 10 + (s[i] - b'0') as u32;
+        if val > u8::MAX as u32 {
+            return None;
+        }
+        i += 1;
+    }
+    Some(val as u8)
+}
+
+const fn parse_hex(hex: &[u8]) -> Option<Color> {
+    match hex.len() {
+        6 => match (hex_byte(hex[0], hex[1]), hex_byte(hex[2], hex[3]), hex_byte(hex[4], hex[5])) {
+            (Some(r), Some(g), Some(b)) => Some(Color::RGB { r, g, b }),
+            _ => None,
+        },
+        3 => match (hex_byte(hex[0], hex[0]), hex_byte(hex[1], hex[1]), hex_byte(hex[2], hex[2])) {
+            (Some(r), Some(g), Some(b)) => Some(Color::RGB { r, g, b }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+const fn hex_byte(high: u8, low: u8) -> Option<u8> {
+    match (hex_digit(high), hex_digit(low)) {
+        (Some(high), Some(low)) => Some(high * 16 + low),
+        _ => None,
+    }
+}
+
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// The `r, g, b` inside `rgb(...)`.
+const fn parse_rgb(rgb: &[u8]) -> Option<Color> {
+    let mut channels = [0; 3];
+    let mut count = 0;
+    let mut rest = rgb;
+    loop {
+        let mut end = 0;
+        while end < rest.len() && rest[end] != b',' {
+            end += 1;
+        }
+        let (channel, after) = rest.split_at(end);
+        if count == channels.len() {
+            return None;
+        }
+        channels[count] = match parse_u8(channel.trim_ascii()) {
+            Some(channel) => channel,
+            None => return None,
+        };
+        count += 1;
+        match after.split_first() {
+            Some((_, after)) => rest = after,
+            None => break,
+        }
+    }
+    if count != channels.len() {
+        return None;
+    }
+    Some(Color::RGB { r: channels[0], g: channels[1], b: channels[2] })
+}
+
+pub(crate) fn style_name(style: Styles) -> &'static str {
+    STYLE_NAMES
+        .iter()
+        .find(|(s, _)| *s == style)
+        .map(|(_, name)| *name)
+        .unwrap_or_default()
+}
+
+/// Shows the style's name, e.g. `"bold"` or `"curly underline"`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// assert_eq!(Styles::Bold.to_string(), "bold");
+/// assert_eq!(format!("{}", Styles::CurlyUnderline), "curly underline");
+/// ```
+impl fmt::Display for Styles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&style_name(*self).replace('_', " "))
+    }
+}
+
+pub(crate) fn parse_style(s: &str) -> Option<Styles> {
+    let name = s.to_ascii_lowercase().replace(['-', ' '], "_");
+    let name = match name.as_str() {
+        "dim" => "faint",
+        "reverse" | "inverse" => "invert",
+        "hidden" => "invisible",
+        "strike" => "strikethrough",
+        name => name,
+    };
+    STYLE_NAMES.iter().find(|(_, n)| *n == name).map(|(style, _)| *style)
+}
+
+/// The error returned when [`Formatting::from_spec`] can't make sense of a spec.
+/// 
+/// [`Formatting::from_spec`]: struct.Formatting.html#method.from_spec
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseSpecError {
+    spec: String,
+    problem: String,
+}
+
+impl ParseSpecError {
+    fn new(spec: &str, problem: String) -> ParseSpecError {
+        ParseSpecError { spec: spec.to_string(), problem }
+    }
+}
+
+impl fmt::Display for ParseSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid style spec {:?}: {}", self.spec, self.problem)
+    }
+}
+
+impl error::Error for ParseSpecError {}
+
+impl Formatting {
+    /// Parse a compact, human-friendly description like `"bold red on blue"`.
+    /// 
+    /// The spec is a list of words in any order:
+    ///   - Style names (`bold`, `italic`, `underline`, ...) add that style.
+    ///   - A color (anything `Color` can be parsed from) sets the foreground.
+    ///   - `on` followed by a color sets the background.
+    /// 
+    /// `bright` can also go before a color name as its own word, e.g. `"bright red on black"`.
+    /// This makes it easy to keep styles in config files or environment variables.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let formatting = Formatting::from_spec("bold red on blue").unwrap();
+    /// assert_eq!(formatting.apply_to("HI MOM"), "\x1B[31;44;1mHI MOM\x1B[0m");
+    /// 
+    /// let formatting = Formatting::from_spec("italic #ff8700 on rgb(0, 0, 95)").unwrap();
+    /// println!("{}", formatting.apply_to("HI MOM"));
+    /// 
+    /// assert!(Formatting::from_spec("bold on").is_err());
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<Formatting, ParseSpecError> {
+        let mut formatting = Formatting::new();
+        let mut fg = None;
+        let mut bg = None;
+        let mut words = spec_words(spec).into_iter().peekable();
+
+        while let Some(word) = words.next() {
+            if word.eq_ignore_ascii_case("on") {
+                let color = match words.next() {
+                    Some(word) => take_color(&mut words, word),
+                    None => return Err(ParseSpecError::new(spec, "expected a color after `on`".to_string())),
+                };
+                let color = color.map_err(|word| ParseSpecError::new(spec, format!("unknown color {:?}", word)))?;
+                if bg.replace(color).is_some() {
+                    return Err(ParseSpecError::new(spec, "more than one background color".to_string()));
+                }
+            } else if let Some(style) = parse_style(&word) {
+                formatting.add_style(style);
+            } else {
+                let color = take_color(&mut words, word)
+                    .map_err(|word| ParseSpecError::new(spec, format!("unknown word {:?}", word)))?;
+                if fg.replace(color).is_some() {
+                    return Err(ParseSpecError::new(spec, "more than one foreground color".to_string()));
+                }
+            }
+        }
+
+        if let Some(fg) = fg {
+            formatting.foreground(fg);
+        }
+        if let Some(bg) = bg {
+            formatting.background(bg);
+        }
+        Ok(formatting)
+    }
+
+    /// Like [`from_spec`], but also checking the result with [`validate`], so e.g.
+    /// `"underline curly_underline"` is an error rather than whichever the terminal picks.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// assert!(Formatting::try_from_spec("bold red").is_ok());
+    /// assert!(matches!(Formatting::try_from_spec("bold on"), Err(Error::ParseSpec(_))));
+    /// assert_eq!(
+    ///     Formatting::try_from_spec("blink rapid_blink").unwrap_err(),
+    ///     Error::ConflictingStyles(Styles::Blink, Styles::RapidBlink)
+    /// );
+    /// ```
+    /// 
+    /// [`from_spec`]: struct.Formatting.html#method.from_spec
+    /// [`validate`]: struct.Formatting.html#method.validate
+    pub fn try_from_spec(spec: &str) -> Result<Formatting, Error> {
+        let formatting = Formatting::from_spec(spec)?;
+        formatting.validate()?;
+        Ok(formatting)
+    }
+
+    /// Check that the styles don't contradict each other.
+    /// 
+    /// Styles that are turned off by the same code (see [`Styles::off_code`]) are alternatives,
+    /// like bold and faint or the different underlines, so only one of each group can be set.
+    /// The fonts count as one group, and `Styles::Reset` conflicts with any other style, since it
+    /// undoes them. Terminals differ in what they show for these, so it's usually a mistake.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let formatting = Formatting::new().styles([Styles::Bold, Styles::Underline]).build();
+    /// assert!(formatting.validate().is_ok());
+    /// 
+    /// let formatting = Formatting::new().styles([Styles::Bold, Styles::Faint]).build();
+    /// assert_eq!(formatting.validate(), Err(Error::ConflictingStyles(Styles::Bold, Styles::Faint)));
+    /// ```
+    /// 
+    /// [`Styles::off_code`]: enum.Styles.html#method.off_code
+    pub fn validate(&self) -> Result<(), Error> {
+        let group = |style: Styles| match style {
+            Styles::Reset => None,
+            Styles::DefaultFont => Some(10),
+            style => style.off_code(),
+        };
+        for (i, a) in self.styles.iter().enumerate() {
+            for b in self.styles.iter().skip(i + 1) {
+                if a == Styles::Reset || group(a) == group(b) {
+                    return Err(Error::ConflictingStyles(a, b));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Same as [`Formatting::from_spec`].
+/// 
+/// [`Formatting::from_spec`]: struct.Formatting.html#method.from_spec
+impl FromStr for Formatting {
+    type Err = ParseSpecError;
+
+    fn from_str(s: &str) -> Result<Formatting, ParseSpecError> {
+        Formatting::from_spec(s)
+    }
+}
+
+/// Parse `word` as a color, also taking the next word if `word` is just `bright`.
+/// 
+/// On failure, returns the word(s) that didn't parse.
+fn take_color(words: &mut Peekable<vec::IntoIter<String>>, word: String) -> Result<Color, String> {
+    let word = match words.peek() {
+        Some(next) if word.eq_ignore_ascii_case("bright") => {
+            let joined = format!("bright_{}", next);
+            words.next();
+            joined
+        }
+        _ => word,
+    };
+    word.parse().map_err(|_| word)
+}
+
+/// Split on whitespace, except inside parentheses so `rgb(1, 2, 3)` stays one word.
+fn spec_words(spec: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut depth = 0;
+    for c in spec.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if c.is_whitespace() && depth <= 0 {
+            if !word.is_empty() {
+                words.push(core::mem::take(&mut word));
+            }
+        } else {
+            word.push(c);
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
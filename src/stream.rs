@@ -0,0 +1,56 @@
+//! Coloring whole streams of program output, e.g. for a `colorize` filter in a pipeline.
+//! 
+//! Needs the `regex` feature.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::io::{self, BufRead, Read, Write};
+
+use crate::rules::Rules;
+
+/// Lines longer than this (in bytes) are colored in pieces, so memory use stays bounded.
+const MAX_CHUNK: usize = 64 * 1024;
+
+/// Color everything from `reader` with `rules` line by line, writing it to `writer`.
+/// 
+/// Each line is written (and `writer` flushed) as soon as it's read, so this works for
+/// long-running commands, like `tail -f`. Only one line is held in memory at a time, and very long
+/// lines are split into pieces of 64 KiB, although a match that straddles two pieces won't be
+/// found. Line endings are kept as they are, and anything that isn't valid UTF-8 is replaced with
+/// `�`.
+/// 
+/// # Example
+/// 
+/// ```no_run
+/// use coloring::*;
+/// use coloring::rules::Rules;
+/// 
+/// let mut rules = Rules::new();
+/// rules.add(r"\bERROR\b", Formatting::new().foreground(Color::Red).build()).unwrap();
+/// coloring::stream::process(std::io::stdin().lock(), std::io::stdout().lock(), &rules).unwrap();
+/// ```
+pub fn process<R: BufRead, W: Write>(mut reader: R, mut writer: W, rules: &Rules) -> io::Result<()> {
+    let mut buffer = Vec::new();
+    loop {
+        let limit = MAX_CHUNK.saturating_sub(buffer.len()) as u64;
+        let read = reader.by_ref().take(limit).read_until(b'\n', &mut buffer)?;
+        if buffer.is_empty() {
+            return writer.flush();
+        }
+        // Don't cut a character in half when a long line is split up.
+        let complete = read == 0 || buffer.ends_with(b"\n");
+        let partial = match core::str::from_utf8(&buffer) {
+            Err(error) if !complete && error.error_len().is_none() => buffer.len() - error.valid_up_to(),
+            _ => 0,
+        };
+        let rest = buffer.split_off(buffer.len() - partial);
+
+        let chunk = String::from_utf8_lossy(&buffer);
+        let content = chunk.strip_suffix('\n').unwrap_or(&chunk);
+        let content = content.strip_suffix('\r').unwrap_or(content);
+        writer.write_all(rules.colorize(content).as_bytes())?;
+        writer.write_all(chunk[content.len()..].as_bytes())?;
+        writer.flush()?;
+        buffer = rest;
+    }
+}
@@ -0,0 +1,126 @@
+//! A fixed-size set of `Styles`, so `Formatting` never needs to allocate.
+
+use crate::Styles;
+
+/// A set of `Styles`, stored as bit flags.
+/// 
+/// Each style is in the set at most once, and they always come out in the same order
+/// (the order they're declared in `Styles`), which is also the order their codes are emitted in.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let mut set: StyleSet = [Styles::Underline, Styles::Bold, Styles::Underline].into_iter().collect();
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(Styles::Bold));
+/// 
+/// set.remove(Styles::Bold);
+/// assert_eq!(set.iter().count(), 1);
+/// 
+/// let formatted_text = Formatting::new().styles(set).apply_to("HI MOM");
+/// assert_eq!(formatted_text, "\x1B[4mHI MOM\x1B[0m");
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StyleSet(u32);
+
+impl StyleSet {
+    /// A set with no styles in it.
+    pub const fn new() -> StyleSet {
+        StyleSet(0)
+    }
+
+    /// The set with `style` added.
+    pub const fn with(self, style: Styles) -> StyleSet {
+        StyleSet(self.0 | StyleSet::bit(style))
+    }
+
+    /// The set with `style` removed.
+    pub const fn without(self, style: Styles) -> StyleSet {
+        StyleSet(self.0 & !StyleSet::bit(style))
+    }
+
+    /// Add `style`, returning whether it wasn't already there.
+    pub fn insert(&mut self, style: Styles) -> bool {
+        let added = !self.contains(style);
+        *self = self.with(style);
+        added
+    }
+
+    /// Remove `style`, returning whether it was there.
+    pub fn remove(&mut self, style: Styles) -> bool {
+        let removed = self.contains(style);
+        *self = self.without(style);
+        removed
+    }
+
+    /// Whether `style` is in the set.
+    pub const fn contains(self, style: Styles) -> bool {
+        self.0 & StyleSet::bit(style) != 0
+    }
+
+    /// Whether the set has no styles in it.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// How many styles are in the set.
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// The styles in the set, in declaration order.
+    pub fn iter(self) -> Iter {
+        Iter { set: self, index: 0 }
+    }
+
+    const fn bit(style: Styles) -> u32 {
+        1 << style.index()
+    }
+}
+
+impl FromIterator<Styles> for StyleSet {
+    fn from_iter<I: IntoIterator<Item = Styles>>(iter: I) -> StyleSet {
+        iter.into_iter().fold(StyleSet::new(), StyleSet::with)
+    }
+}
+
+impl Extend<Styles> for StyleSet {
+    fn extend<I: IntoIterator<Item = Styles>>(&mut self, iter: I) {
+        *self = iter.into_iter().fold(*self, StyleSet::with);
+    }
+}
+
+impl IntoIterator for StyleSet {
+    type Item = Styles;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+/// An iterator over the styles in a [`StyleSet`], in declaration order.
+/// 
+/// [`StyleSet`]: struct.StyleSet.html
+#[derive(Clone, Debug)]
+pub struct Iter {
+    set: StyleSet,
+    index: usize,
+}
+
+impl Iterator for Iter {
+    type Item = Styles;
+
+    fn next(&mut self) -> Option<Styles> {
+        while let Some(&style) = Styles::ALL.get(self.index) {
+            self.index += 1;
+            if self.set.contains(style) {
+                return Some(style);
+            }
+        }
+        None
+    }
+}
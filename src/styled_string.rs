@@ -0,0 +1,217 @@
+//! `StyledString`, text made of differently styled pieces.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, AddAssign, Bound, RangeBounds};
+
+use crate::{parse, Formatting};
+
+/// Text made up of pieces that each have their own `Formatting`.
+/// 
+/// Unlike gluing together the output of `apply_to`, this keeps track of which text has which
+/// style, so it can be appended to, sliced, and measured without tripping over escape codes.
+/// The escape codes are only written when it's displayed.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let red = Formatting::new().foreground(Color::Red).build();
+/// let mut line = StyledString::new();
+/// line.push_styled("error", &red);
+/// line.push_str(": file not found");
+/// 
+/// assert_eq!(line.len(), 21);
+/// assert_eq!(line.to_string(), "\x1B[31merror\x1B[0m: file not found");
+/// assert_eq!(line.slice(3..7).to_string(), "\x1B[31mor\x1B[0m: ");
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct StyledString {
+    spans: Vec<(Formatting, String)>,
+}
+
+impl StyledString {
+    /// An empty `StyledString`.
+    pub fn new() -> StyledString {
+        StyledString { spans: Vec::new() }
+    }
+
+    /// Read text that already has escape codes in it, e.g. from `apply_to` or another program.
+    /// 
+    /// See [`parse::spans`] for which codes are understood.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let styled = StyledString::from_ansi(&format!("{} MOM", "HI".bold()));
+    /// assert_eq!(styled.plain(), "HI MOM");
+    /// ```
+    /// 
+    /// [`parse::spans`]: parse/fn.spans.html
+    pub fn from_ansi(text: &str) -> StyledString {
+        let mut styled = StyledString::new();
+        for (formatting, text) in parse::spans(text) {
+            styled.push_styled(text, &formatting);
+        }
+        styled
+    }
+
+    /// Add unstyled text to the end.
+    pub fn push_str(&mut self, text: &str) {
+        self.push_styled(text, &Formatting::new());
+    }
+
+    /// Add text with `formatting` to the end.
+    pub fn push_styled(&mut self, text: &str, formatting: &Formatting) {
+        if text.is_empty() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some((last, last_text)) if last == formatting => last_text.push_str(text),
+            _ => self.spans.push((formatting.clone(), text.to_string())),
+        }
+    }
+
+    /// The number of characters, not counting escape codes.
+    pub fn len(&self) -> usize {
+        self.spans.iter().map(|(_, text)| text.chars().count()).sum()
+    }
+
+    /// Whether there's no text.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The text without any escape codes.
+    pub fn plain(&self) -> String {
+        self.spans.iter().map(|(_, text)| text.as_str()).collect()
+    }
+
+    /// The pieces of text and their formatting, in order.
+    pub fn spans(&self) -> impl Iterator<Item = (&Formatting, &str)> {
+        self.spans.iter().map(|(formatting, text)| (formatting, text.as_str()))
+    }
+
+    /// The characters in `range`, keeping their styles.
+    /// 
+    /// `range` counts characters, not bytes, and is clamped to the length.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> StyledString {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => usize::MAX,
+        };
+        let mut sliced = StyledString::new();
+        let mut offset = 0;
+        for (formatting, text) in &self.spans {
+            let len = text.chars().count();
+            let from = start.clamp(offset, offset + len) - offset;
+            let to = end.clamp(offset, offset + len) - offset;
+            if from < to {
+                let piece: String = text.chars().skip(from).take(to - from).collect();
+                sliced.push_styled(&piece, formatting);
+            }
+            offset += len;
+        }
+        sliced
+    }
+}
+
+impl fmt::Display for StyledString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (formatting, text) in &self.spans {
+            if *formatting == Formatting::new() {
+                f.write_str(text)?;
+            } else {
+                write!(f, "{}", formatting.paint(text))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for StyledString {
+    fn from(text: &str) -> StyledString {
+        let mut styled = StyledString::new();
+        styled.push_str(text);
+        styled
+    }
+}
+
+impl From<String> for StyledString {
+    fn from(text: String) -> StyledString {
+        StyledString::from(text.as_str())
+    }
+}
+
+impl AddAssign<&StyledString> for StyledString {
+    fn add_assign(&mut self, rhs: &StyledString) {
+        for (formatting, text) in &rhs.spans {
+            self.push_styled(text, formatting);
+        }
+    }
+}
+
+impl AddAssign<StyledString> for StyledString {
+    fn add_assign(&mut self, rhs: StyledString) {
+        *self += &rhs;
+    }
+}
+
+impl AddAssign<&str> for StyledString {
+    fn add_assign(&mut self, rhs: &str) {
+        self.push_str(rhs);
+    }
+}
+
+/// Join two `StyledString`s, keeping the styles of both.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let bold = Formatting::new().bold().build();
+/// let mut name = StyledString::new();
+/// name.push_styled("MOM", &bold);
+/// 
+/// let greeting = StyledString::from("HI ") + name + "!";
+/// assert_eq!(greeting.to_string(), "HI \x1B[1mMOM\x1B[0m!");
+/// ```
+impl Add<StyledString> for StyledString {
+    type Output = StyledString;
+
+    fn add(mut self, rhs: StyledString) -> StyledString {
+        self += &rhs;
+        self
+    }
+}
+
+impl Add<&StyledString> for StyledString {
+    type Output = StyledString;
+
+    fn add(mut self, rhs: &StyledString) -> StyledString {
+        self += rhs;
+        self
+    }
+}
+
+impl Add<&str> for StyledString {
+    type Output = StyledString;
+
+    fn add(mut self, rhs: &str) -> StyledString {
+        self += rhs;
+        self
+    }
+}
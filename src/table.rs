@@ -0,0 +1,130 @@
+//! Lining up styled text in columns.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::text::str_width;
+use crate::StyledString;
+
+/// How to line up the text in a column.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub enum Align {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+/// Rows of styled cells, rendered with each column as wide as its widest cell.
+/// 
+/// Widths only count the visible text, not the escape codes, so colored cells line up.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::table::{Align, Table};
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let ok = Formatting::new().foreground(Color::Green).build();
+/// let mut status = StyledString::new();
+/// status.push_styled("ok", &ok);
+/// 
+/// let mut table = Table::new();
+/// table.add_row(["name", "status", "time"]);
+/// table.add_row([StyledString::from("build"), status, StyledString::from("1.2s")]);
+/// table.align(2, Align::Right);
+/// 
+/// assert_eq!(
+///     table.to_string(),
+///     "name   status  time\nbuild  \x1B[32mok\x1B[0m      1.2s\n"
+/// );
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct Table {
+    rows: Vec<Vec<StyledString>>,
+    alignments: Vec<Align>,
+    separator: Option<String>,
+}
+
+impl Table {
+    /// An empty table.
+    pub fn new() -> Table {
+        Table::default()
+    }
+
+    /// Add a row of cells. Rows can have different numbers of cells, with the missing ones left
+    /// blank.
+    pub fn add_row<I, S>(&mut self, row: I) -> &mut Table
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<StyledString>,
+    {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set how `column` (counting from 0) is aligned. Columns are left-aligned by default.
+    pub fn align(&mut self, column: usize, align: Align) -> &mut Table {
+        if self.alignments.len() <= column {
+            self.alignments.resize(column + 1, Align::Left);
+        }
+        self.alignments[column] = align;
+        self
+    }
+
+    /// Set what goes between columns, two spaces by default.
+    pub fn separator(&mut self, separator: &str) -> &mut Table {
+        self.separator = Some(separator.to_string());
+        self
+    }
+
+    /// The table as a string, with a newline after every row. Same as `to_string()`.
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths = Vec::new();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                let width = str_width(&cell.plain());
+                match widths.get_mut(i) {
+                    Some(max) => *max = width.max(*max),
+                    None => widths.push(width),
+                }
+            }
+        }
+        widths
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        let separator = self.separator.as_deref().unwrap_or("  ");
+        for row in &self.rows {
+            for (i, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+                let align = self.alignments.get(i).copied().unwrap_or_default();
+                let last = i + 1 == row.len();
+                let gap = width - str_width(&cell.plain());
+                let (before, after) = match align {
+                    Align::Left => (0, gap),
+                    Align::Right => (gap, 0),
+                    Align::Center => (gap / 2, gap - gap / 2),
+                };
+                if i > 0 {
+                    f.write_str(separator)?;
+                }
+                write!(f, "{:before$}{}", "", cell, before = before)?;
+                // Don't leave trailing spaces at the end of the line.
+                if !last {
+                    write!(f, "{:after$}", "", after = after)?;
+                }
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
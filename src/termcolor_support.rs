@@ -0,0 +1,92 @@
+//! Output through [`termcolor`](https://docs.rs/termcolor), for old Windows consoles that only
+//! understand WinAPI calls rather than escape codes.
+//! 
+//! termcolor only knows about bold, faint, italic, underline, and strikethrough, so the other
+//! styles are dropped. Bright colors become `Ansi256` 8 to 15, which termcolor turns into its
+//! "intense" colors on Windows.
+
+use std::io;
+
+use termcolor::{ColorSpec, WriteColor};
+
+use crate::{quantize, Color, Formatting, Styles};
+
+fn termcolor_color(color: Color) -> Option<termcolor::Color> {
+    use termcolor::Color as Tc;
+
+    Some(match color {
+        Color::Default => return None,
+        Color::Black => Tc::Black,
+        Color::Red => Tc::Red,
+        Color::Green => Tc::Green,
+        Color::Yellow => Tc::Yellow,
+        Color::Blue => Tc::Blue,
+        Color::Magenta => Tc::Magenta,
+        Color::Cyan => Tc::Cyan,
+        Color::White => Tc::White,
+        Color::BrightBlack => Tc::Ansi256(8),
+        Color::BrightRed => Tc::Ansi256(9),
+        Color::BrightGreen => Tc::Ansi256(10),
+        Color::BrightYellow => Tc::Ansi256(11),
+        Color::BrightBlue => Tc::Ansi256(12),
+        Color::BrightMagenta => Tc::Ansi256(13),
+        Color::BrightCyan => Tc::Ansi256(14),
+        Color::BrightWhite => Tc::Ansi256(15),
+        Color::Colors256(index) => Tc::Ansi256(index),
+        Color::Grayscale(level) => Tc::Ansi256(quantize::grayscale_index(level)),
+        Color::RGB { r, g, b } => Tc::Rgb(r, g, b),
+    })
+}
+
+impl From<&Formatting> for ColorSpec {
+    fn from(formatting: &Formatting) -> ColorSpec {
+        let (fg, bg) = formatting.effective_colors();
+        let styles = formatting.effective_styles();
+        let mut spec = ColorSpec::new();
+        spec.set_fg(termcolor_color(fg))
+            .set_bg(termcolor_color(bg))
+            .set_bold(styles.contains(Styles::Bold))
+            .set_dimmed(styles.contains(Styles::Faint))
+            .set_italic(styles.contains(Styles::Italic))
+            .set_underline(styles.contains(Styles::Underline))
+            .set_strikethrough(styles.contains(Styles::Strikethrough));
+        spec
+    }
+}
+
+impl From<Formatting> for ColorSpec {
+    fn from(formatting: Formatting) -> ColorSpec {
+        ColorSpec::from(&formatting)
+    }
+}
+
+impl Formatting {
+    /// Write your formatted text through a `termcolor::WriteColor`, e.g. a `StandardStream`.
+    /// 
+    /// This lets the same `Formatting` drive both escape codes and the WinAPI calls that old
+    /// Windows consoles need. termcolor makes its own decision about whether to use colors, so
+    /// [`set_color_choice`] and `NO_COLOR` aren't checked here, though [`when`] is.
+    /// 
+    /// Needs the `termcolor` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// use termcolor::{ColorChoice, StandardStream};
+    /// 
+    /// let mut stdout = StandardStream::stdout(ColorChoice::Auto);
+    /// Formatting::new().foreground(Color::Green).write_color(&mut stdout, "HI MOM\n").unwrap();
+    /// ```
+    /// 
+    /// [`set_color_choice`]: fn.set_color_choice.html
+    /// [`when`]: struct.Formatting.html#method.when
+    pub fn write_color<W: WriteColor + ?Sized>(&self, w: &mut W, text: &str) -> io::Result<()> {
+        if self.disabled {
+            return w.write_all(text.as_bytes());
+        }
+        w.set_color(&ColorSpec::from(self))?;
+        w.write_all(text.as_bytes())?;
+        w.reset()
+    }
+}
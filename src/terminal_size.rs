@@ -0,0 +1,101 @@
+//! Asking the terminal how big it is.
+
+/// The size of the terminal as `(columns, rows)`, or `None` if there's no terminal to ask.
+/// 
+/// stdout is asked first, then stderr and stdin, so this still works when one of them is
+/// redirected. It uses `ioctl` on Unix and `GetConsoleScreenBufferInfo` on Windows, and always
+/// returns `None` elsewhere.
+/// 
+/// Needs the `terminal-size` feature, which is on by default.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let (columns, _) = terminal_size().unwrap_or((80, 24));
+/// println!("{}", wrap("some rather long help text", columns as usize));
+/// ```
+pub fn terminal_size() -> Option<(u16, u16)> {
+    sys::terminal_size()
+}
+
+#[cfg(unix)]
+mod sys {
+    pub fn terminal_size() -> Option<(u16, u16)> {
+        [libc::STDOUT_FILENO, libc::STDERR_FILENO, libc::STDIN_FILENO].into_iter().find_map(|fd| {
+            let mut size = libc::winsize { ws_row: 0, ws_col: 0, ws_xpixel: 0, ws_ypixel: 0 };
+            // SAFETY: TIOCGWINSZ only writes a `winsize` to the pointer, and `size` outlives the
+            // call.
+            let result = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size as *mut libc::winsize) };
+            (result == 0 && size.ws_col > 0 && size.ws_row > 0).then_some((size.ws_col, size.ws_row))
+        })
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use core::ffi::c_void;
+
+    type Handle = *mut c_void;
+
+    const STD_INPUT_HANDLE: u32 = -10i32 as u32;
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const STD_ERROR_HANDLE: u32 = -12i32 as u32;
+    const INVALID_HANDLE_VALUE: Handle = -1isize as Handle;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Coord {
+        x: i16,
+        y: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmallRect {
+        left: i16,
+        top: i16,
+        right: i16,
+        bottom: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct ConsoleScreenBufferInfo {
+        size: Coord,
+        cursor_position: Coord,
+        attributes: u16,
+        window: SmallRect,
+        maximum_window_size: Coord,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetStdHandle(std_handle: u32) -> Handle;
+        fn GetConsoleScreenBufferInfo(console_output: Handle, info: *mut ConsoleScreenBufferInfo) -> i32;
+    }
+
+    pub fn terminal_size() -> Option<(u16, u16)> {
+        [STD_OUTPUT_HANDLE, STD_ERROR_HANDLE, STD_INPUT_HANDLE].into_iter().find_map(|std_handle| {
+            let mut info = ConsoleScreenBufferInfo::default();
+            // SAFETY: This only reads the process's own standard handles, and `info` outlives
+            // the call that writes to it.
+            let ok = unsafe {
+                let handle = GetStdHandle(std_handle);
+                !handle.is_null() && handle != INVALID_HANDLE_VALUE && GetConsoleScreenBufferInfo(handle, &mut info) != 0
+            };
+            // The visible window, not the whole scrollback buffer.
+            let columns = info.window.right - info.window.left + 1;
+            let rows = info.window.bottom - info.window.top + 1;
+            (ok && columns > 0 && rows > 0).then_some((columns as u16, rows as u16))
+        })
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod sys {
+    pub fn terminal_size() -> Option<(u16, u16)> {
+        None
+    }
+}
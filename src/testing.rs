@@ -0,0 +1,99 @@
+//! Helpers for tests of colored output, e.g. snapshot tests.
+//! 
+//! Comparing strings full of escape codes with `assert_eq!` gives you two walls of `\u{1b}[31m`
+//! to squint at. [`diff`] shows exactly which characters changed instead.
+//! 
+//! [`diff`]: fn.diff.html
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::{Color, Formatting, StyledString, Styles};
+
+const REMOVED: Formatting = Formatting::new().with_foreground(Color::Red).with_style(Styles::Strikethrough);
+const ADDED: Formatting = Formatting::new().with_foreground(Color::Green).with_style(Styles::Underline);
+
+/// The character-level difference between `expected` and `actual`.
+/// 
+/// Characters only in `expected` are red and struck through, characters only in `actual` are
+/// green and underlined, and the rest is left plain. Control characters (like the escape that
+/// starts every escape code) are shown escaped, so the result is safe to print.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::testing;
+/// 
+/// let diff = testing::diff("\x1B[31mHI\x1B[0m", "\x1B[32mHI\x1B[0m");
+/// assert_eq!(diff.plain(), "\\u{1b}[312mHI\\u{1b}[0m");
+/// println!("{}", diff);
+/// ```
+pub fn diff(expected: &str, actual: &str) -> StyledString {
+    let expected: Vec<char> = expected.chars().collect();
+    let actual: Vec<char> = actual.chars().collect();
+    let (n, m) = (expected.len(), actual.len());
+
+    // `common[i * (m + 1) + j]` is the length of the longest common subsequence of
+    // `expected[i..]` and `actual[j..]`.
+    let mut common = vec![0usize; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            common[i * (m + 1) + j] = if expected[i] == actual[j] {
+                common[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                common[(i + 1) * (m + 1) + j].max(common[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let mut diff = StyledString::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            diff.push_str(&escaped(expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && common[(i + 1) * (m + 1) + j] >= common[i * (m + 1) + j + 1]) {
+            diff.push_styled(&escaped(expected[i]), &REMOVED);
+            i += 1;
+        } else {
+            diff.push_styled(&escaped(actual[j]), &ADDED);
+            j += 1;
+        }
+    }
+    diff
+}
+
+/// Panic with a [`diff`] if `expected` and `actual` aren't the same.
+/// 
+/// A drop-in for `assert_eq!` on strings with escape codes in them.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let actual = Formatting::new().foreground(Color::Red).apply_to("HI");
+/// testing::assert_same("\x1B[31mHI\x1B[0m", &actual);
+/// ```
+/// 
+/// [`diff`]: fn.diff.html
+#[track_caller]
+pub fn assert_same(expected: &str, actual: &str) {
+    if expected != actual {
+        panic!("strings differ (expected vs. actual):\n{}", diff(expected, actual));
+    }
+}
+
+fn escaped(c: char) -> String {
+    let mut escaped = String::new();
+    if c.is_control() && c != '\n' {
+        let _ = write!(escaped, "{}", c.escape_debug());
+    } else {
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,484 @@
+//! Working with text that may already have escape sequences in it.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::ansi::{self, Token};
+use crate::Formatting;
+
+/// Remove all escape sequences (colors, styles, cursor movement, hyperlinks, ...) from `text`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let formatted_text = Formatting::new().foreground(Color::Red).apply_to("HI MOM");
+/// assert_eq!(strip(&formatted_text), "HI MOM");
+/// ```
+pub fn strip(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    // Writing to a `String` can't fail.
+    let _ = strip_into(text, &mut stripped);
+    stripped
+}
+
+/// Like [`strip`], but writes the plain text to `w` instead of allocating a new `String`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let mut log = String::from("log: ");
+/// strip_into(&"HI MOM".red().to_string(), &mut log).unwrap();
+/// assert_eq!(log, "log: HI MOM");
+/// ```
+/// 
+/// [`strip`]: fn.strip.html
+pub fn strip_into<W: fmt::Write + ?Sized>(text: &str, w: &mut W) -> fmt::Result {
+    for token in ansi::tokens(text) {
+        if let Token::Text(text) = token {
+            w.write_str(text)?;
+        }
+    }
+    Ok(())
+}
+
+/// How many columns `text` takes up in a terminal, ignoring escape sequences.
+/// 
+/// By default every character counts as one column. With the `unicode-width` feature, wide
+/// characters (e.g. CJK) count as two and zero-width ones (e.g. combining accents) as none.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let formatted_text = Formatting::new().foreground(Color::Red).apply_to("HI MOM");
+/// assert_eq!(formatted_text.len(), 15);
+/// assert_eq!(visible_width(&formatted_text), 6);
+/// ```
+pub fn visible_width(text: &str) -> usize {
+    ansi::tokens(text)
+        .map(|token| match token {
+            Token::Text(text) => str_width(text),
+            Token::Escape(_) => 0,
+        })
+        .sum()
+}
+
+/// Add spaces to the left of `text` until it's `width` columns wide, right-aligning it.
+/// 
+/// Escape sequences don't count towards the width, so colored text lines up properly.
+/// Text that's already at least `width` wide is returned as is.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let formatted_text = Formatting::new().foreground(Color::Red).apply_to("42");
+/// assert_eq!(pad_left(&formatted_text, 5), format!("   {}", formatted_text));
+/// ```
+pub fn pad_left(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(visible_width(text));
+    format!("{}{}", " ".repeat(padding), text)
+}
+
+/// Add spaces to the right of `text` until it's `width` columns wide, left-aligning it.
+/// 
+/// See [`pad_left`].
+/// 
+/// [`pad_left`]: fn.pad_left.html
+pub fn pad_right(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(visible_width(text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// Add spaces on both sides of `text` until it's `width` columns wide, centering it.
+/// 
+/// If the padding can't be split evenly, the extra space goes on the right. See [`pad_left`].
+/// 
+/// [`pad_left`]: fn.pad_left.html
+pub fn center(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(visible_width(text));
+    let left = padding / 2;
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(padding - left))
+}
+
+/// Wrap `text` so no line is wider than `width` columns, breaking at whitespace.
+/// 
+/// Escape sequences don't count towards the width and are never split. Colors and styles that
+/// are on at a line break are reset before it and turned back on after it, so each line stands
+/// on its own (e.g. in a pager, or next to other columns). Existing newlines are kept, whitespace
+/// at the breaks is dropped, and words longer than `width` are split wherever they hit the edge.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let text = format!("one {} three", "two three four".red());
+/// assert_eq!(
+///     wrap(&text, 9),
+///     "one \x1B[31mtwo\x1B[0m\n\x1B[31mthree\x1B[0m\n\x1B[31mfour\x1B[0m\nthree"
+/// );
+/// ```
+pub fn wrap(text: &str, width: usize) -> String {
+    let mut wrapper = Wrapper { width: width.max(1), ..Wrapper::default() };
+    for token in ansi::tokens(text) {
+        match token {
+            Token::Escape(escape) if wrapper.word_width == 0 => wrapper.gap.push(Piece::Escape(escape)),
+            Token::Escape(escape) => wrapper.word.push(Piece::Escape(escape)),
+            Token::Text(text) => {
+                for c in text.chars() {
+                    if c == '\n' {
+                        wrapper.finish_word();
+                        wrapper.drop_gap();
+                        wrapper.output.push('\n');
+                        wrapper.line_width = 0;
+                    } else if c.is_whitespace() {
+                        wrapper.finish_word();
+                        wrapper.gap.push(Piece::Char(c));
+                        wrapper.gap_width += char_width(c);
+                    } else {
+                        wrapper.word.push(Piece::Char(c));
+                        wrapper.word_width += char_width(c);
+                    }
+                }
+            }
+        }
+    }
+    wrapper.finish_word();
+    wrapper.drop_gap();
+    wrapper.output
+}
+
+/// Cut `text` down to at most `max_width` columns, ending it with `ellipsis` if anything was cut.
+/// 
+/// Escape sequences don't count towards the width. The ellipsis gets whatever colors and styles
+/// are on where the text is cut, and then they're reset, so nothing leaks into what comes next.
+/// Text that already fits is returned as is. If `max_width` is narrower than `ellipsis` itself,
+/// you get as much of the ellipsis as fits.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let text = format!("{} world", "hello".red());
+/// assert_eq!(truncate_visible(&text, 4, "…"), "\x1B[31mhel…\x1B[0m");
+/// assert_eq!(truncate_visible(&text, 11, "…"), text);
+/// ```
+pub fn truncate_visible(text: &str, max_width: usize, ellipsis: &str) -> String {
+    if visible_width(text) <= max_width {
+        return text.to_string();
+    }
+    let ellipsis_width = visible_width(ellipsis);
+    if ellipsis_width > max_width {
+        return truncate_visible(ellipsis, max_width, "");
+    }
+
+    let room = max_width - ellipsis_width;
+    let mut truncated = String::with_capacity(text.len());
+    let mut width = 0;
+    let mut active = ActiveCodes::default();
+    'tokens: for token in ansi::tokens(text) {
+        match token {
+            Token::Escape(escape) => {
+                active.update(escape);
+                truncated.push_str(escape);
+            }
+            Token::Text(text) => {
+                for c in text.chars() {
+                    width += char_width(c);
+                    if width > room {
+                        break 'tokens;
+                    }
+                    truncated.push(c);
+                }
+            }
+        }
+    }
+    truncated.push_str(ellipsis);
+    if !active.is_empty() {
+        truncated.push_str("\x1B[0m");
+    }
+    truncated
+}
+
+/// Apply `formatting` to every occurrence of `pattern` in `text`, leaving the rest as it was.
+/// 
+/// `text` can already have colors and styles in it. Matches are found in its visible text, so
+/// escape sequences don't get in the way, and whatever was on before a match is turned back on
+/// after it. Matches don't overlap, and an empty `pattern` matches nothing.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let found = Formatting::new().foreground(Color::Red).bold().build();
+/// assert_eq!(highlight("to be or not to be", "be", &found), "to \x1B[31;1mbe\x1B[0m or not to \x1B[31;1mbe\x1B[0m");
+/// 
+/// let line = format!("{}: not found", "src/lib.rs".blue());
+/// assert_eq!(highlight(&line, "lib", &found), "\x1B[34msrc/\x1B[31;1mlib\x1B[0m\x1B[34m.rs\x1B[0m: not found");
+/// ```
+pub fn highlight(text: &str, pattern: &str, formatting: &Formatting) -> String {
+    if pattern.is_empty() {
+        return text.to_string();
+    }
+    let plain = strip(text);
+    let matches = plain.match_indices(pattern).map(|(start, found)| start..start + found.len());
+    highlight_ranges(text, matches, formatting)
+}
+
+/// Like [`highlight`], but for every match of a regular expression.
+/// 
+/// Empty matches are skipped. Needs the `regex` feature.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use regex::Regex;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// let number = Formatting::new().foreground(Color::Cyan).build();
+/// let text = highlight_regex("took 35ms for 2 files", &Regex::new(r"\d+").unwrap(), &number);
+/// assert_eq!(text, "took \x1B[36m35\x1B[0mms for \x1B[36m2\x1B[0m files");
+/// ```
+/// 
+/// [`highlight`]: fn.highlight.html
+#[cfg(feature = "regex")]
+pub fn highlight_regex(text: &str, regex: &regex::Regex, formatting: &Formatting) -> String {
+    let plain = strip(text);
+    let matches = regex.find_iter(&plain).filter(|found| !found.is_empty()).map(|found| found.range());
+    highlight_ranges(text, matches, formatting)
+}
+
+/// Apply `formatting` to `ranges` of the visible text of `text`. The ranges must be in order and
+/// not overlap.
+pub(crate) fn highlight_ranges<I: Iterator<Item = Range<usize>>>(text: &str, ranges: I, formatting: &Formatting) -> String {
+    if !formatting.colorize(None) {
+        return text.to_string();
+    }
+    let (prefix, suffix) = (formatting.prefix(), formatting.suffix());
+    let mut ranges = ranges.peekable();
+    let mut highlighted = String::with_capacity(text.len());
+    let mut active = ActiveCodes::default();
+    let mut offset = 0;
+    let mut inside = false;
+    for token in ansi::tokens(text) {
+        let text = match token {
+            Token::Escape(escape) => {
+                active.update(escape);
+                highlighted.push_str(escape);
+                // Keep the highlight on top of whatever the text changes to.
+                if inside {
+                    highlighted.push_str(&prefix);
+                }
+                continue;
+            }
+            Token::Text(text) => text,
+        };
+        for (i, c) in text.char_indices() {
+            let position = offset + i;
+            if !inside && ranges.peek().is_some_and(|range| range.start == position) {
+                highlighted.push_str(&prefix);
+                inside = true;
+            }
+            highlighted.push(c);
+            if inside && ranges.peek().is_some_and(|range| range.end == position + c.len_utf8()) {
+                highlighted.push_str(&suffix);
+                active.write_to(&mut highlighted);
+                ranges.next();
+                inside = false;
+            }
+        }
+        offset += text.len();
+    }
+    highlighted
+}
+
+#[derive(Clone, Copy)]
+enum Piece<'a> {
+    Char(char),
+    Escape(&'a str),
+}
+
+/// The state of a [`wrap`] in progress.
+/// 
+/// The whitespace before the current word is held back in `gap` until we know whether the word
+/// fits on the line, since it's dropped if the word goes on the next line instead.
+#[derive(Default)]
+struct Wrapper<'a> {
+    width: usize,
+    output: String,
+    line_width: usize,
+    gap: Vec<Piece<'a>>,
+    gap_width: usize,
+    word: Vec<Piece<'a>>,
+    word_width: usize,
+    active: ActiveCodes<'a>,
+}
+
+impl<'a> Wrapper<'a> {
+    fn finish_word(&mut self) {
+        if self.word_width == 0 {
+            return;
+        }
+        if self.line_width > 0 && self.line_width + self.gap_width + self.word_width > self.width {
+            // Escape sequences in the gap belong to the word, so they go after the break.
+            let gap = core::mem::take(&mut self.gap);
+            self.break_line();
+            self.gap = gap;
+            self.drop_gap();
+        } else {
+            for piece in core::mem::take(&mut self.gap) {
+                self.push(piece);
+            }
+            self.line_width += self.gap_width;
+            self.gap_width = 0;
+        }
+        for piece in core::mem::take(&mut self.word) {
+            if let Piece::Char(c) = piece {
+                // A word that's wider than a whole line has to be split.
+                let width = char_width(c);
+                if self.line_width > 0 && self.line_width + width > self.width {
+                    self.break_line();
+                }
+                self.line_width += width;
+            }
+            self.push(piece);
+        }
+        self.word_width = 0;
+    }
+
+    /// Throw away the whitespace in `gap`, keeping its escape sequences.
+    fn drop_gap(&mut self) {
+        for piece in core::mem::take(&mut self.gap) {
+            if let Piece::Escape(_) = piece {
+                self.push(piece);
+            }
+        }
+        self.gap_width = 0;
+    }
+
+    fn break_line(&mut self) {
+        if !self.active.is_empty() {
+            self.output.push_str("\x1B[0m");
+        }
+        self.output.push('\n');
+        self.active.write_to(&mut self.output);
+        self.line_width = 0;
+    }
+
+    fn push(&mut self, piece: Piece<'a>) {
+        match piece {
+            Piece::Char(c) => self.output.push(c),
+            Piece::Escape(escape) => {
+                self.active.update(escape);
+                self.output.push_str(escape);
+            }
+        }
+    }
+}
+
+/// Split `text` into lines that each stand on their own: colors and styles still on at the end of
+/// a line are reset there and turned back on at the start of the next one.
+pub(crate) fn styled_lines(text: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut active = ActiveCodes::default();
+    for token in ansi::tokens(text) {
+        match token {
+            Token::Escape(escape) => {
+                active.update(escape);
+                line.push_str(escape);
+            }
+            Token::Text(text) => {
+                let mut parts = text.split('\n');
+                if let Some(first) = parts.next() {
+                    line.push_str(first);
+                }
+                for part in parts {
+                    if !active.is_empty() {
+                        line.push_str("\x1B[0m");
+                    }
+                    lines.push(core::mem::take(&mut line));
+                    active.write_to(&mut line);
+                    line.push_str(part);
+                }
+            }
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+/// The color/style escape sequences in effect, i.e. the ones since the last reset.
+#[derive(Default)]
+pub(crate) struct ActiveCodes<'a>(Vec<&'a str>);
+
+impl<'a> ActiveCodes<'a> {
+    /// Take `escape` into account, if it's a color/style sequence.
+    pub(crate) fn update(&mut self, escape: &'a str) {
+        let params = match escape.strip_prefix("\x1B[").and_then(|e| e.strip_suffix('m')) {
+            Some(params) => params,
+            None => return,
+        };
+        if params.is_empty() || params == "0" {
+            self.0.clear();
+        } else {
+            if params.starts_with("0;") {
+                self.0.clear();
+            }
+            self.0.push(escape);
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Turn them all back on.
+    pub(crate) fn write_to(&self, output: &mut String) {
+        for escape in &self.0 {
+            output.push_str(escape);
+        }
+    }
+}
+
+/// The width of plain text, without any escape sequences.
+pub(crate) fn str_width(text: &str) -> usize {
+    #[cfg(feature = "unicode-width")]
+    {
+        unicode_width::UnicodeWidthStr::width(text)
+    }
+    #[cfg(not(feature = "unicode-width"))]
+    {
+        text.chars().count()
+    }
+}
+
+/// The width of a single plain character.
+pub(crate) fn char_width(c: char) -> usize {
+    #[cfg(feature = "unicode-width")]
+    {
+        unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+    }
+    #[cfg(not(feature = "unicode-width"))]
+    {
+        let _ = c;
+        1
+    }
+}
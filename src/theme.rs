@@ -0,0 +1,316 @@
+//! Themes map semantic names like `"error"` to concrete `Formatting`s.
+//! 
+//! That way the rest of your program just says what something *is*, and the colors can be
+//! changed in one place (or by your users at runtime). With the `toml` feature, themes can also
+//! be loaded from a file with [`Theme::from_path`].
+//! 
+//! With the `std` feature there's also a global theme, the registry, for styles the whole program
+//! shares. Names added with [`register`] can be used as tags in [`markup::render`] and as
+//! placeholder styles in [`cformat!`], without passing a `Theme` around.
+//! 
+//! [`Theme::from_path`]: struct.Theme.html#method.from_path
+//! [`register`]: fn.register.html
+//! [`markup::render`]: ../markup/fn.render.html
+//! [`cformat!`]: ../macro.cformat.html
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use std::env;
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+
+use crate::palette::okabe_ito;
+use crate::{parse, Color, Formatting, Styles};
+
+/// A set of named `Formatting`s.
+/// 
+/// `Theme::default()` comes with `"error"`, `"warning"`, `"success"`, `"info"`, `"heading"`, and
+/// `"hint"`. `Theme::new()` starts out empty.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let mut theme = Theme::default();
+/// println!("{} file not found", theme.apply("error", "error:"));
+/// 
+/// theme.set("error", Formatting::new().foreground(Color::Magenta).build());
+/// println!("{} file not found", theme.apply("error", "error:"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    styles: BTreeMap<String, Formatting>,
+}
+
+impl Theme {
+    /// Create a theme with nothing in it.
+    pub const fn new() -> Theme {
+        Theme { styles: BTreeMap::new() }
+    }
+
+    /// Add or replace the `Formatting` for `name`.
+    pub fn set(&mut self, name: impl Into<String>, formatting: Formatting) -> &mut Theme {
+        self.styles.insert(name.into(), formatting);
+        self
+    }
+
+    /// The `Formatting` for `name`, if there is one.
+    pub fn get(&self, name: &str) -> Option<&Formatting> {
+        self.styles.get(name)
+    }
+
+    /// Remove `name`, returning its `Formatting`.
+    pub fn remove(&mut self, name: &str) -> Option<Formatting> {
+        self.styles.remove(name)
+    }
+
+    /// Whether there's a `Formatting` for `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.styles.contains_key(name)
+    }
+
+    /// All the names in the theme, in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.styles.keys().map(String::as_str)
+    }
+
+    /// Copy everything from `other` into this theme, replacing any names that are in both.
+    /// 
+    /// Handy for loading user overrides on top of your defaults.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let mut overrides = Theme::new();
+    /// overrides.set("hint", Formatting::new().foreground(Color::Blue).build());
+    /// 
+    /// let mut theme = Theme::default();
+    /// theme.extend(overrides);
+    /// assert_eq!(theme.apply("hint", "text"), "\x1B[34mtext\x1B[0m");
+    /// ```
+    pub fn extend(&mut self, other: Theme) -> &mut Theme {
+        self.styles.extend(other.styles);
+        self
+    }
+
+    /// Read a theme from an `LS_COLORS` / `GREP_COLORS` style string, like `"error=1;31:warn=33"`.
+    /// 
+    /// Entries are separated by `:`, and each one is a name, `=`, and either SGR codes (`1;31`) or
+    /// a spec like `bold red` (see [`Formatting::from_spec`]). Entries that can't be read are
+    /// skipped, so one typo doesn't throw out the whole thing.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let theme = Theme::from_colors_str("error=1;31:warning=bold yellow:oops");
+    /// assert_eq!(theme.apply("error", "text"), "\x1B[31;1mtext\x1B[0m");
+    /// assert_eq!(theme.apply("warning", "text"), "\x1B[33;1mtext\x1B[0m");
+    /// assert!(!theme.contains("oops"));
+    /// ```
+    /// 
+    /// [`Formatting::from_spec`]: ../struct.Formatting.html#method.from_spec
+    pub fn from_colors_str(colors: &str) -> Theme {
+        let mut theme = Theme::new();
+        for entry in colors.split(':') {
+            let Some((name, value)) = entry.split_once('=') else {
+                continue;
+            };
+            let (name, value) = (name.trim(), value.trim());
+            if name.is_empty() || value.is_empty() {
+                continue;
+            }
+            let formatting = if value.bytes().all(|b| b.is_ascii_digit() || b == b';') {
+                Some(parse::sgr_formatting(value))
+            } else {
+                Formatting::from_spec(value).ok()
+            };
+            if let Some(formatting) = formatting {
+                theme.set(name, formatting);
+            }
+        }
+        theme
+    }
+
+    /// Read a theme from the environment variable `var` (see [`from_colors_str`]), or an empty
+    /// theme if it isn't set.
+    /// 
+    /// This lets your users recolor your program without touching its code. Layer it on top of
+    /// your defaults with [`extend`].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// let mut theme = Theme::default();
+    /// theme.extend(Theme::from_env("MYAPP_COLORS"));
+    /// eprintln!("{} file not found", theme.apply("error", "error:"));
+    /// ```
+    /// 
+    /// [`from_colors_str`]: struct.Theme.html#method.from_colors_str
+    /// [`extend`]: struct.Theme.html#method.extend
+    #[cfg(feature = "std")]
+    pub fn from_env(var: &str) -> Theme {
+        match env::var(var) {
+            Ok(colors) => Theme::from_colors_str(&colors),
+            Err(_) => Theme::new(),
+        }
+    }
+
+    /// Swap every color in the theme for the closest [Okabe–Ito] color, so the styles stay
+    /// distinguishable for people with color blindness.
+    /// 
+    /// Grays, black, white, and `Color::Default` are left alone. The six basic colors each get a
+    /// different replacement, so e.g. a red `"error"` and a green `"success"` end up as vermillion
+    /// and bluish green instead of two similar browns. There are only seven Okabe–Ito colors
+    /// though, so the bright colors share them: bright yellow becomes yellow, but the others get
+    /// the same replacement as their basic color, except bright blue, which becomes sky blue like
+    /// cyan. Use [`palette::simulate`] to check the result.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// use coloring::palette::okabe_ito;
+    /// 
+    /// let mut theme = Theme::default();
+    /// theme.make_colorblind_safe();
+    /// let error = Formatting::new().foreground(okabe_ito::VERMILLION).styles([Styles::Bold]).build();
+    /// assert_eq!(theme.get("error"), Some(&error));
+    /// ```
+    /// 
+    /// [Okabe–Ito]: ../palette/okabe_ito/index.html
+    /// [`palette::simulate`]: ../palette/fn.simulate.html
+    pub fn make_colorblind_safe(&mut self) -> &mut Theme {
+        for formatting in self.styles.values_mut() {
+            formatting.fg = colorblind_safe(formatting.fg);
+            formatting.bg = colorblind_safe(formatting.bg);
+        }
+        self
+    }
+
+    /// Apply the `Formatting` for `name` to text.
+    /// 
+    /// Names that aren't in the theme leave the text as is.
+    pub fn apply(&self, name: &str, text: &str) -> String {
+        match self.get(name) {
+            Some(formatting) => formatting.apply_to(text),
+            None => text.to_string(),
+        }
+    }
+}
+
+/// The Okabe–Ito color closest to `color`, or `color` itself if it's a gray.
+fn colorblind_safe(color: Color) -> Color {
+    let Some((r, g, b)) = color.rgb() else {
+        return color;
+    };
+    if r.max(g).max(b) - r.min(g).min(b) < 48 {
+        return color;
+    }
+    let distance = |other: &Color| {
+        let (r2, g2, b2) = other.rgb().unwrap_or_default();
+        [(r, r2), (g, g2), (b, b2)].map(|(a, b)| (a as i32 - b as i32).pow(2)).iter().sum::<i32>()
+    };
+    okabe_ito::ALL.into_iter().min_by_key(distance).unwrap_or(color)
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        let mut theme = Theme::new();
+        theme
+            .set("error", Formatting::new().foreground(Color::Red).styles([Styles::Bold]).build())
+            .set("warning", Formatting::new().foreground(Color::Yellow).styles([Styles::Bold]).build())
+            .set("success", Formatting::new().foreground(Color::Green).build())
+            .set("info", Formatting::new().foreground(Color::Cyan).build())
+            .set("heading", Formatting::new().styles([Styles::Bold, Styles::Underline]).build())
+            .set("hint", Formatting::new().foreground(Color::BrightBlack).build());
+        theme
+    }
+}
+
+#[cfg(feature = "std")]
+static REGISTRY: RwLock<Theme> = RwLock::new(Theme::new());
+
+/// Add or replace the `Formatting` for `name` in the registry, for the whole program.
+/// 
+/// Needs the `std` feature.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// theme::register("heading", Formatting::new().foreground(Color::Blue).styles([Styles::Bold]).build());
+/// 
+/// assert_eq!(markup::render("<heading>Usage</heading>"), "\x1B[34;1mUsage\x1B[0m");
+/// assert_eq!(cformat!("{:heading}", "Usage"), "\x1B[34;1mUsage\x1B[0m");
+/// ```
+#[cfg(feature = "std")]
+pub fn register(name: impl Into<String>, formatting: Formatting) {
+    REGISTRY.write().unwrap_or_else(PoisonError::into_inner).set(name, formatting);
+}
+
+/// Remove `name` from the registry, returning its `Formatting`.
+/// 
+/// Needs the `std` feature.
+#[cfg(feature = "std")]
+pub fn unregister(name: &str) -> Option<Formatting> {
+    REGISTRY.write().unwrap_or_else(PoisonError::into_inner).remove(name)
+}
+
+/// The registry's `Formatting` for `name`, if there is one.
+/// 
+/// Needs the `std` feature.
+#[cfg(feature = "std")]
+pub fn registered(name: &str) -> Option<Formatting> {
+    with_registry(|registry| registry.get(name).cloned())
+}
+
+/// Replace the whole registry with `theme`, returning what was in it.
+/// 
+/// Handy for loading a user's theme at startup, e.g. `Theme::default()` extended with
+/// [`Theme::from_env`].
+/// 
+/// Needs the `std` feature.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// let mut theme = Theme::default();
+/// theme.extend(Theme::from_env("MYAPP_COLORS"));
+/// theme::set_registry(theme);
+/// 
+/// println!("{}", markup::render("<error>error:</error> file not found"));
+/// ```
+/// 
+/// [`Theme::from_env`]: struct.Theme.html#method.from_env
+#[cfg(feature = "std")]
+pub fn set_registry(theme: Theme) -> Theme {
+    core::mem::replace(&mut *REGISTRY.write().unwrap_or_else(PoisonError::into_inner), theme)
+}
+
+/// A copy of everything in the registry.
+/// 
+/// Needs the `std` feature.
+#[cfg(feature = "std")]
+pub fn registry() -> Theme {
+    with_registry(Theme::clone)
+}
+
+/// Run `f` with the registry, without copying it.
+#[cfg(feature = "std")]
+pub(crate) fn with_registry<R>(f: impl FnOnce(&Theme) -> R) -> R {
+    f(&REGISTRY.read().unwrap_or_else(PoisonError::into_inner))
+}
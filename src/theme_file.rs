@@ -0,0 +1,189 @@
+//! Reading a [`Theme`] from a TOML file.
+//! 
+//! [`Theme`]: struct.Theme.html
+
+use alloc::string::{String, ToString};
+use core::error::Error;
+use core::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use toml::{Table, Value};
+
+use crate::{spec, Color, Formatting, ParseColorError, ParseSpecError, Theme};
+
+/// The error returned when [`Theme::from_toml_str`] or [`Theme::from_path`] can't read a theme.
+/// 
+/// Every variant but `Io` and `Toml` says which entry was wrong. When there's an underlying
+/// error, `Display` only says what failed, and [`source`] has the details.
+/// 
+/// [`source`]: #method.source
+/// [`Theme::from_toml_str`]: struct.Theme.html#method.from_toml_str
+/// [`Theme::from_path`]: struct.Theme.html#method.from_path
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ThemeFileError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The file isn't valid TOML.
+    Toml(toml::de::Error),
+    /// An entry is a string, but not a spec [`Formatting::from_spec`] understands.
+    /// 
+    /// [`Formatting::from_spec`]: struct.Formatting.html#method.from_spec
+    BadSpec { name: String, error: ParseSpecError },
+    /// An entry's `fg` or `bg` isn't a color.
+    BadColor { name: String, error: ParseColorError },
+    /// An entry's `styles` has something in it that isn't a style name.
+    BadStyle { name: String, style: String },
+    /// An entry has a key other than `fg`, `bg`, and `styles`.
+    UnknownKey { name: String, key: String },
+    /// An entry, or one of its keys, has the wrong type of value, e.g. a number.
+    BadValue { name: String, expected: &'static str },
+}
+
+impl fmt::Display for ThemeFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeFileError::Io(_) => f.write_str("couldn't read theme"),
+            ThemeFileError::Toml(_) => f.write_str("theme isn't valid TOML"),
+            ThemeFileError::BadSpec { name, .. } => write!(f, "theme entry {:?}: invalid spec", name),
+            ThemeFileError::BadColor { name, .. } => write!(f, "theme entry {:?}: invalid color", name),
+            ThemeFileError::BadStyle { name, style } => write!(f, "theme entry {:?}: unknown style {:?}", name, style),
+            ThemeFileError::UnknownKey { name, key } => {
+                write!(f, "theme entry {:?}: unknown key {:?} (expected `fg`, `bg`, or `styles`)", name, key)
+            }
+            ThemeFileError::BadValue { name, expected } => write!(f, "theme entry {:?}: expected {}", name, expected),
+        }
+    }
+}
+
+impl Error for ThemeFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ThemeFileError::Io(error) => Some(error),
+            ThemeFileError::Toml(error) => Some(error),
+            ThemeFileError::BadSpec { error, .. } => Some(error),
+            ThemeFileError::BadColor { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ThemeFileError {
+    fn from(error: io::Error) -> ThemeFileError {
+        ThemeFileError::Io(error)
+    }
+}
+
+impl From<toml::de::Error> for ThemeFileError {
+    fn from(error: toml::de::Error) -> ThemeFileError {
+        ThemeFileError::Toml(error)
+    }
+}
+
+impl Theme {
+    /// Read a theme from TOML, where each key is a name and each value is either a spec like
+    /// `"bold red"` (see [`Formatting::from_spec`]) or a table with any of `fg`, `bg`, and
+    /// `styles`, the same as `Formatting`'s serde form.
+    /// 
+    /// Unlike [`from_colors_str`], mistakes aren't skipped: the first bad entry is returned as an
+    /// error naming it.
+    /// 
+    /// Needs the `toml` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use coloring::*;
+    /// 
+    /// # let _color = override_scope(ColorChoice::Always);
+    /// let theme = Theme::from_toml_str(r##"
+    ///     error = "bold red"
+    /// 
+    ///     [heading]
+    ///     fg = "#ff8700"
+    ///     styles = ["bold", "underline"]
+    /// "##).unwrap();
+    /// assert_eq!(theme.apply("error", "text"), "\x1B[31;1mtext\x1B[0m");
+    /// 
+    /// let error = Theme::from_toml_str("error = \"bold octarine\"").unwrap_err();
+    /// assert_eq!(error.to_string(), r#"theme entry "error": invalid spec"#);
+    /// let source = std::error::Error::source(&error).unwrap();
+    /// assert_eq!(source.to_string(), r#"invalid style spec "bold octarine": unknown word "octarine""#);
+    /// ```
+    /// 
+    /// [`Formatting::from_spec`]: struct.Formatting.html#method.from_spec
+    /// [`from_colors_str`]: struct.Theme.html#method.from_colors_str
+    pub fn from_toml_str(toml: &str) -> Result<Theme, ThemeFileError> {
+        let table: Table = toml.parse()?;
+        let mut theme = Theme::new();
+        for (name, value) in table {
+            let formatting = entry_formatting(&name, value)?;
+            theme.set(name, formatting);
+        }
+        Ok(theme)
+    }
+
+    /// Read a theme from a TOML file, see [`from_toml_str`].
+    /// 
+    /// Needs the `toml` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```no_run
+    /// use coloring::*;
+    /// 
+    /// let mut theme = Theme::default();
+    /// theme.extend(Theme::from_path("theme.toml").unwrap());
+    /// ```
+    /// 
+    /// [`from_toml_str`]: struct.Theme.html#method.from_toml_str
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Theme, ThemeFileError> {
+        Theme::from_toml_str(&fs::read_to_string(path)?)
+    }
+}
+
+fn entry_formatting(name: &str, value: Value) -> Result<Formatting, ThemeFileError> {
+    let bad_value = |expected| ThemeFileError::BadValue { name: name.to_string(), expected };
+    let table = match value {
+        Value::String(spec) => {
+            return Formatting::from_spec(&spec).map_err(|error| ThemeFileError::BadSpec { name: name.to_string(), error });
+        }
+        Value::Table(table) => table,
+        _ => return Err(bad_value("a spec string or a table")),
+    };
+
+    let mut formatting = Formatting::new();
+    for (key, value) in table {
+        match key.as_str() {
+            "fg" | "bg" => {
+                let Value::String(color) = value else {
+                    return Err(bad_value("`fg` and `bg` to be color strings"));
+                };
+                let color: Color = color.parse().map_err(|error| ThemeFileError::BadColor { name: name.to_string(), error })?;
+                if key == "fg" {
+                    formatting.foreground(color);
+                } else {
+                    formatting.background(color);
+                }
+            }
+            "styles" => {
+                let Value::Array(styles) = value else {
+                    return Err(bad_value("`styles` to be a list of style names"));
+                };
+                for style in styles {
+                    let Value::String(style) = style else {
+                        return Err(bad_value("`styles` to be a list of style names"));
+                    };
+                    match spec::parse_style(&style) {
+                        Some(style) => formatting.add_style(style),
+                        None => return Err(ThemeFileError::BadStyle { name: name.to_string(), style }),
+                    };
+                }
+            }
+            _ => return Err(ThemeFileError::UnknownKey { name: name.to_string(), key }),
+        }
+    }
+    Ok(formatting)
+}
@@ -0,0 +1,122 @@
+//! `ToStyled`, for types that know how to show themselves in color.
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::{StyledString, Theme};
+
+/// A type that can be shown as styled text, using the names in a [`Theme`] for its colors.
+/// 
+/// Implement [`style`] for your own types, e.g. log records or test results, so the way they look
+/// is defined in one place and follows whatever theme the program uses. Looking styles up by
+/// name instead of hardcoding them means users can recolor everything with their own theme.
+/// 
+/// Types that also implement `Display` get [`Colorize::style`] too, which `value.style(...)`
+/// picks first. Call it as `ToStyled::style(&value, &theme)` for those, or use the other methods
+/// here.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// # let _color = override_scope(ColorChoice::Always);
+/// struct TestResult {
+///     name: &'static str,
+///     passed: bool,
+/// }
+/// 
+/// impl ToStyled for TestResult {
+///     fn style(&self, theme: &Theme) -> StyledString {
+///         let (status, name) = if self.passed { ("ok", "success") } else { ("FAILED", "error") };
+///         let mut line = StyledString::from(format!("test {} ... ", self.name));
+///         line.push_styled(status, theme.get(name).unwrap_or(&Formatting::new()));
+///         line
+///     }
+/// }
+/// 
+/// let theme = Theme::default();
+/// let result = TestResult { name: "parses_specs", passed: true };
+/// assert_eq!(result.to_styled_string(&theme), "test parses_specs ... \x1B[32mok\x1B[0m");
+/// println!("{}", result.with_theme(&theme));
+/// ```
+/// 
+/// [`Theme`]: struct.Theme.html
+/// [`style`]: trait.ToStyled.html#tymethod.style
+/// [`Colorize::style`]: trait.Colorize.html#method.style
+pub trait ToStyled {
+    /// This value as styled text, with the styles from `theme`.
+    fn style(&self, theme: &Theme) -> StyledString;
+
+    /// This value as text with escape codes, with the styles from `theme`.
+    fn to_styled_string(&self, theme: &Theme) -> String {
+        self.style(theme).to_string()
+    }
+
+    /// Something that displays this value with the styles from `theme`, for `format!` and
+    /// `println!`.
+    fn with_theme<'a>(&'a self, theme: &'a Theme) -> WithTheme<'a, Self>
+    where
+        Self: Sized,
+    {
+        WithTheme { value: self, theme }
+    }
+
+    /// This value as styled text, with the styles in the registry (see [`theme::register`]).
+    /// 
+    /// Needs the `std` feature.
+    /// 
+    /// [`theme::register`]: theme/fn.register.html
+    #[cfg(feature = "std")]
+    fn styled(&self) -> StyledString {
+        crate::theme::with_registry(|registry| self.style(registry))
+    }
+}
+
+impl<T: ToStyled + ?Sized> ToStyled for &T {
+    fn style(&self, theme: &Theme) -> StyledString {
+        (**self).style(theme)
+    }
+}
+
+impl<T: ToStyled + ?Sized> ToStyled for &mut T {
+    fn style(&self, theme: &Theme) -> StyledString {
+        (**self).style(theme)
+    }
+}
+
+impl<T: ToStyled + ?Sized> ToStyled for Box<T> {
+    fn style(&self, theme: &Theme) -> StyledString {
+        (**self).style(theme)
+    }
+}
+
+/// Already styled, so the theme isn't needed.
+impl ToStyled for StyledString {
+    fn style(&self, _theme: &Theme) -> StyledString {
+        self.clone()
+    }
+}
+
+/// A value displayed with a theme, returned by [`ToStyled::with_theme`].
+/// 
+/// [`ToStyled::with_theme`]: trait.ToStyled.html#method.with_theme
+pub struct WithTheme<'a, T: ?Sized> {
+    value: &'a T,
+    theme: &'a Theme,
+}
+
+impl<T: ToStyled + ?Sized> fmt::Display for WithTheme<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.style(self.theme).fmt(f)
+    }
+}
+
+impl<T: ?Sized> Clone for WithTheme<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for WithTheme<'_, T> {}
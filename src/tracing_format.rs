@@ -0,0 +1,106 @@
+//! An event formatter for [`tracing-subscriber`](https://docs.rs/tracing-subscriber), coloring
+//! levels, spans, and targets with a [`Theme`].
+//! 
+//! Levels use the same names as the [`logger`](../logger/index.html) module: `"error"`,
+//! `"warning"`, `"info"`, then `"debug"` and `"trace"` falling back to `"hint"`. Span names use
+//! `"heading"` and targets use `"hint"`. Lines look like
+//! `ERROR request{id=7}: my_app::db: connection refused`.
+//! 
+//! Needs the `tracing` feature.
+//! 
+//! [`Theme`]: ../struct.Theme.html
+
+use core::fmt;
+
+use tracing_core::{Event, Level, Subscriber};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, FormattedFields};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::{Formatting, Theme};
+
+/// Formats `tracing` events as single colored lines, for `tracing_subscriber::fmt()`.
+/// 
+/// Escape codes are left out when the subscriber has ANSI turned off (`.with_ansi(false)`), or
+/// when [`set_color_choice`] says so.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// use coloring::tracing_format::EventFormatter;
+/// 
+/// tracing_subscriber::fmt().event_format(EventFormatter::new()).init();
+/// ```
+/// 
+/// [`set_color_choice`]: ../fn.set_color_choice.html
+#[derive(Clone, Debug)]
+pub struct EventFormatter {
+    theme: Theme,
+}
+
+impl EventFormatter {
+    /// A formatter using `Theme::default()`.
+    pub fn new() -> EventFormatter {
+        EventFormatter { theme: Theme::default() }
+    }
+
+    /// A formatter using `theme`.
+    pub fn with_theme(theme: Theme) -> EventFormatter {
+        EventFormatter { theme }
+    }
+
+    fn level_formatting(&self, level: &Level) -> Option<&Formatting> {
+        match *level {
+            Level::ERROR => self.theme.get("error"),
+            Level::WARN => self.theme.get("warning"),
+            Level::INFO => self.theme.get("info"),
+            Level::DEBUG => self.theme.get("debug").or_else(|| self.theme.get("hint")),
+            _ => self.theme.get("trace").or_else(|| self.theme.get("hint")),
+        }
+    }
+}
+
+impl Default for EventFormatter {
+    fn default() -> EventFormatter {
+        EventFormatter::new()
+    }
+}
+
+/// Write `text` with `formatting` if there is one and escape codes are wanted, padded to `width`.
+fn write_part(writer: &mut Writer<'_>, formatting: Option<&Formatting>, text: &str, width: usize) -> fmt::Result {
+    match formatting {
+        Some(formatting) if writer.has_ansi_escapes() => write!(writer, "{:<width$}", formatting.paint(text), width = width),
+        _ => write!(writer, "{:<width$}", text, width = width),
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for EventFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(&self, ctx: &FmtContext<'_, S, N>, mut writer: Writer<'_>, event: &Event<'_>) -> fmt::Result {
+        let metadata = event.metadata();
+        write_part(&mut writer, self.level_formatting(metadata.level()), metadata.level().as_str(), 5)?;
+        writer.write_char(' ')?;
+
+        if let Some(scope) = ctx.event_scope() {
+            for span in scope.from_root() {
+                write_part(&mut writer, self.theme.get("heading"), span.name(), 0)?;
+                let extensions = span.extensions();
+                if let Some(fields) = extensions.get::<FormattedFields<N>>() {
+                    if !fields.is_empty() {
+                        write!(writer, "{{{}}}", fields)?;
+                    }
+                }
+                writer.write_str(": ")?;
+            }
+        }
+
+        write_part(&mut writer, self.theme.get("hint"), metadata.target(), 0)?;
+        writer.write_str(": ")?;
+        ctx.field_format().format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+}
@@ -0,0 +1,74 @@
+//! Windows console support.
+//! 
+//! Windows 10 and up understand escape codes, but only after the console has been switched into
+//! "virtual terminal" mode. Older consoles can't show them at all.
+
+use std::io;
+
+/// Switch stdout and stderr into virtual terminal mode so escape codes are understood.
+/// 
+/// Call this once at startup. It only fails if neither stream could be switched, i.e. on consoles
+/// that are too old or if there's no console at all, in which case you should stick to plain
+/// text. On other platforms this does nothing.
+/// 
+/// # Example
+/// 
+/// ```
+/// use coloring::*;
+/// 
+/// if coloring::windows::enable_ansi().is_err() {
+///     set_override(false);
+/// }
+/// ```
+pub fn enable_ansi() -> io::Result<()> {
+    #[cfg(windows)]
+    {
+        // Both get a try, since one of them is often redirected to a file, which isn't a console.
+        let stdout = sys::enable_virtual_terminal(sys::STD_OUTPUT_HANDLE);
+        let stderr = sys::enable_virtual_terminal(sys::STD_ERROR_HANDLE);
+        stdout.or(stderr)
+    }
+    #[cfg(not(windows))]
+    Ok(())
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::ffi::c_void;
+    use std::io;
+
+    type Handle = *mut c_void;
+
+    pub const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    pub const STD_ERROR_HANDLE: u32 = -12i32 as u32;
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+    const INVALID_HANDLE_VALUE: Handle = -1isize as Handle;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetStdHandle(std_handle: u32) -> Handle;
+        fn GetConsoleMode(console_handle: Handle, mode: *mut u32) -> i32;
+        fn SetConsoleMode(console_handle: Handle, mode: u32) -> i32;
+    }
+
+    pub fn enable_virtual_terminal(std_handle: u32) -> io::Result<()> {
+        // SAFETY: These calls only read and set flags on the process's own standard handles,
+        // and `mode` outlives the call that writes to it.
+        unsafe {
+            let handle = GetStdHandle(std_handle);
+            if handle.is_null() || handle == INVALID_HANDLE_VALUE {
+                return Err(io::Error::last_os_error());
+            }
+            let mut mode = 0;
+            if GetConsoleMode(handle, &mut mode) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING == 0
+                && SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) == 0
+            {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "std")]
+
+use coloring::*;
+
+mod common;
+
+// The settings are global, so everything touching them is in one test.
+#[test]
+fn accessibility_settings() {
+    let _color = common::colors_on();
+    let blinking = Formatting::new().foreground(Color::Red).styles([Styles::Bold, Styles::RapidBlink]).build();
+    let faint = Formatting::new().styles([Styles::Faint, Styles::Blink]).reset_mode(ResetMode::Targeted).build();
+    assert_eq!(blinking.apply_to("a"), "\x1B[31;1;6ma\x1B[0m");
+
+    set_accessibility(Accessibility { no_blink: true, ..Default::default() });
+    assert_eq!(accessibility(), Accessibility { no_blink: true, high_contrast: false });
+    assert_eq!(blinking.apply_to("a"), "\x1B[31;1ma\x1B[0m");
+    assert_eq!(faint.apply_to("a"), "\x1B[2ma\x1B[22m");
+    assert_eq!(Formatting::new().styles([Styles::Blink]).reset_mode(ResetMode::Targeted).build().apply_to("a"), "a");
+
+    set_accessibility(Accessibility { no_blink: true, high_contrast: true });
+    assert_eq!(faint.apply_to("a"), "a");
+    let readable = Formatting::new().foreground(Color::White).background(Color::Blue).build();
+    assert_eq!(readable.apply_to("a"), "\x1B[37;44ma\x1B[0m");
+    let unreadable = Formatting::new().foreground(Color::Blue).background(Color::Black).build();
+    assert_eq!(unreadable.apply_to("a"), "\x1B[97;40ma\x1B[0m");
+    assert!(!"a".blue().to_string().starts_with("\x1B[34m"));
+    assert_eq!("a".on_blue().to_string(), "\x1B[44ma\x1B[0m");
+    assert_eq!(faint.apply_to_html("a"), "a");
+    assert!(!blinking.apply_to_html("a").contains("blink"));
+
+    set_accessibility(Accessibility::default());
+    assert_eq!(blinking.apply_to("a"), "\x1B[31;1;6ma\x1B[0m");
+    assert_eq!(unreadable.apply_to("a"), "\x1B[34;40ma\x1B[0m");
+}
@@ -0,0 +1,28 @@
+use coloring::*;
+use coloring::detect::Background;
+
+#[test]
+fn picks_by_background() {
+    let color = AdaptiveColor::new(Color::Blue, Color::BrightCyan);
+    assert_eq!(color.for_background(Background::Light), Color::Blue);
+    assert_eq!(color.for_background(Background::Dark), Color::BrightCyan);
+    assert_eq!(Color::from(color), color.resolve());
+}
+
+#[test]
+fn colorfgbg() {
+    assert_eq!(Background::from_colorfgbg("15;0"), Some(Background::Dark));
+    assert_eq!(Background::from_colorfgbg("0;15"), Some(Background::Light));
+    assert_eq!(Background::from_colorfgbg("0;7"), Some(Background::Light));
+    assert_eq!(Background::from_colorfgbg("7;8"), Some(Background::Dark));
+    assert_eq!(Background::from_colorfgbg("12;default;11"), Some(Background::Light));
+    assert_eq!(Background::from_colorfgbg(""), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn background_from_color() {
+    assert_eq!(Background::from_color(Color::RGB { r: 40, g: 42, b: 54 }), Some(Background::Dark));
+    assert_eq!(Background::from_color(Color::BrightWhite), Some(Background::Light));
+    assert_eq!(Background::from_color(Color::Default), None);
+}
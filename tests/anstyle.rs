@@ -0,0 +1,32 @@
+#![cfg(feature = "anstyle")]
+
+use anstyle::{Ansi256Color, AnsiColor, Effects, RgbColor, Style};
+use coloring::*;
+
+#[test]
+fn to_anstyle() {
+    let formatting = Formatting::new().foreground(Color::Red).background(Color::Grayscale(0)).bold().overline().build();
+    let style = Style::from(&formatting);
+    assert_eq!(style.get_fg_color(), Some(anstyle::Color::Ansi(AnsiColor::Red)));
+    assert_eq!(style.get_bg_color(), Some(anstyle::Color::Ansi256(Ansi256Color(232))));
+    assert_eq!(style.get_effects(), Effects::BOLD);
+    assert_eq!(Style::from(Formatting::new()), Style::new());
+}
+
+#[test]
+fn from_anstyle() {
+    let style = Style::new()
+        .fg_color(Some(anstyle::Color::Rgb(RgbColor(1, 2, 3))))
+        .effects(Effects::ITALIC | Effects::BLINK | Effects::HIDDEN);
+    let expected = Formatting::new()
+        .foreground(Color::RGB { r: 1, g: 2, b: 3 })
+        .styles([Styles::Italic, Styles::Blink, Styles::Invisible])
+        .build();
+    assert_eq!(Formatting::from(style), expected);
+}
+
+#[test]
+fn round_trip() {
+    let formatting = Formatting::new().foreground(Color::BrightCyan).background(Color::Colors256(99)).curly_underline().build();
+    assert_eq!(Formatting::from(Style::from(&formatting)), formatting);
+}
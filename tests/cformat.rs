@@ -0,0 +1,49 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn styled_placeholders() {
+    let _color = common::colors_on();
+    assert_eq!(cformat!("{:red+bold} {}", "ERROR", 42), "\x1B[31;1mERROR\x1B[0m 42");
+    assert_eq!(cformat!("{:bold on_blue}", "a"), "\x1B[44;1ma\x1B[0m");
+    assert_eq!(cformat!("{:bright_green+on_black}", "a"), "\x1B[92;40ma\x1B[0m");
+}
+
+#[test]
+fn positions_and_braces() {
+    let _color = common::colors_on();
+    assert_eq!(cformat!("{1:red}{0}{:} {{{}}}", "a", "b"), "\x1B[31mb\x1B[0maa {b}");
+    assert_eq!(cformat!("plain",), "plain");
+}
+
+#[test]
+#[should_panic(expected = "bad style")]
+fn bad_style() {
+    cformat!("{:octarine}", "a");
+}
+
+#[test]
+#[should_panic(expected = "argument that isn't there")]
+fn missing_argument() {
+    // `cformat!("{} {}", "a")` doesn't compile; this is the check that stops it.
+    coloring::__check_cformat("{} {}", 1);
+}
+
+#[test]
+#[should_panic(expected = "unmatched `{`")]
+fn unmatched_brace() {
+    coloring::__check_cformat("{", 1);
+}
+
+#[test]
+#[should_panic(expected = "only styles")]
+fn width_spec() {
+    coloring::__check_cformat("{:>10}", 1);
+}
+
+#[test]
+#[should_panic(expected = "never used")]
+fn unused_argument() {
+    coloring::__check_cformat("{}", 2);
+}
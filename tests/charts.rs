@@ -0,0 +1,48 @@
+#![cfg(feature = "std")]
+
+use coloring::*;
+
+#[test]
+fn sparkline() {
+    let _guard = override_scope(ColorChoice::Always);
+    assert_eq!(
+        charts::sparkline(&[0.0, f64::NAN, 7.0]),
+        "\x1B[38;2;0;175;0m▁ \x1B[38;2;215;0;0m█\x1B[0m"
+    );
+    assert_eq!(charts::sparkline(&[f64::NAN]), " ");
+    assert_eq!(charts::sparkline(&[]), "");
+}
+
+#[test]
+fn sparkline_plain() {
+    let _guard = override_scope(ColorChoice::Never);
+    assert_eq!(charts::sparkline(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]), "▁▂▃▄▅▆▇█");
+    assert_eq!(charts::sparkline(&[3.0, 3.0]), "▁▁");
+}
+
+#[test]
+fn hbar() {
+    let plain = Formatting::new();
+    assert_eq!(charts::hbar(1.0, 8.0, 1, &plain), "▏");
+    assert_eq!(charts::hbar(0.0, 8.0, 3, &plain), "   ");
+    assert_eq!(charts::hbar(-2.0, 8.0, 2, &plain), "  ");
+    assert_eq!(charts::hbar(f64::NAN, 8.0, 2, &plain), "  ");
+    assert_eq!(charts::hbar(1.0, 0.0, 2, &plain), "  ");
+    assert_eq!(charts::hbar(5.0, 8.0, 0, &plain), "");
+
+    let _guard = override_scope(ColorChoice::Always);
+    let red = Formatting::new().foreground(Color::Red).build();
+    assert_eq!(charts::hbar(1.0, 2.0, 3, &red), "\x1B[31m█▌\x1B[0m ");
+}
+
+#[test]
+fn bar_chart() {
+    let _guard = override_scope(ColorChoice::Always);
+    let label = "b".bold().to_string();
+    let chart = charts::bar_chart([("aa", 2.0), (label.as_str(), 4.0)], 10);
+    assert_eq!(
+        chart,
+        "aa \x1B[38;2;0;175;0m██▌\x1B[0m   2\n\x1B[1mb\x1B[0m  \x1B[38;2;215;0;0m█████\x1B[0m 4\n"
+    );
+    assert_eq!(charts::bar_chart(Vec::<(&str, f64)>::new(), 10), "");
+}
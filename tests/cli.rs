@@ -0,0 +1,54 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_coloring"))
+        .args(args)
+        .env_remove("NO_COLOR")
+        .env_remove("FORCE_COLOR")
+        .env_remove("CLICOLOR_FORCE")
+        .env_remove("CLICOLOR")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str], stdin: &str) -> String {
+    let output = run(args, stdin);
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn styles_arguments() {
+    let args = ["--color", "always", "--fg", "red", "--bg", "blue", "--style", "bold", "--style", "italic", "HI", "MOM"];
+    assert_eq!(stdout(&args, ""), "\x1B[31;44;1;3mHI MOM\x1B[0m\n");
+    assert_eq!(stdout(&["--color", "always", "-n", "--fg", "green", "--", "--fg"], ""), "\x1B[32m--fg\x1B[0m");
+}
+
+#[test]
+fn styles_stdin() {
+    assert_eq!(stdout(&["--color", "always", "--fg", "cyan"], "a\nb\n"), "\x1B[36ma\x1B[0m\n\x1B[36mb\x1B[0m\n");
+}
+
+#[test]
+fn plain_when_piped() {
+    assert_eq!(stdout(&["--fg", "red", "HI"], ""), "HI\n");
+    assert_eq!(stdout(&["--color", "never", "--fg", "red", "HI"], ""), "HI\n");
+}
+
+#[test]
+fn bad_arguments() {
+    for args in [&["--fg", "nope"][..], &["--fg"], &["--color", "sometimes"], &["--bogus"]] {
+        let output = run(args, "");
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(String::from_utf8(output.stderr).unwrap().starts_with("coloring: "));
+    }
+    assert!(stdout(&["--help"], "").starts_with("Usage: coloring"));
+}
@@ -0,0 +1,12 @@
+//! Shared by the integration tests.
+
+/// Turn colors on for the current thread until the returned guard is dropped, so tests checking
+/// escape codes pass no matter what `NO_COLOR` and friends are set to in the environment.
+#[cfg(feature = "std")]
+pub fn colors_on() -> impl Sized {
+    coloring::override_scope(coloring::ColorChoice::Always)
+}
+
+/// Without `std` the environment isn't read and colors are always on, so there's nothing to do.
+#[cfg(not(feature = "std"))]
+pub fn colors_on() -> impl Sized {}
@@ -0,0 +1,77 @@
+#![cfg(feature = "std")]
+
+use std::io::{self, Read};
+
+use coloring::*;
+
+/// A reader that hands out at most `step` bytes at a time, to split escapes and characters.
+struct Trickle<'a> {
+    data: &'a [u8],
+    step: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.step.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+fn plain(data: &[u8], step: usize) -> Vec<u8> {
+    let mut output = Vec::new();
+    convert::to_plain(Trickle { data, step }, &mut output).unwrap();
+    output
+}
+
+#[test]
+fn to_plain_any_chunking() {
+    let text = format!("{} é→ {}\n\x1B]8;;http://x\x1B\\link\x1B]8;;\x07", "red".red().bold(), "✓".on_blue());
+    for step in [1, 2, 3, 5, 64] {
+        assert_eq!(String::from_utf8(plain(text.as_bytes(), step)).unwrap(), "red é→ ✓\nlink", "step {}", step);
+    }
+}
+
+#[test]
+fn to_plain_keeps_invalid_utf8_and_cut_off_escapes() {
+    assert_eq!(plain(b"a\xFFb\x1B[31", 1), b"a\xFFb");
+    assert_eq!(plain(b"\xE2\x82", 1), b"\xE2\x82");
+    assert_eq!(plain(b"", 1), b"");
+}
+
+fn html(data: &[u8], step: usize, options: &convert::HtmlOptions) -> String {
+    let mut output = Vec::new();
+    convert::to_html(Trickle { data, step }, &mut output, options).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn to_html_one_span_per_run() {
+    let text = "\x1B[31mred \x1B[1mbold\x1B[22m red\x1B[0m & \x1B[38;5;208mX\x1B[m";
+    let expected = concat!(
+        "<span style=\"color:#cd0000\">red </span>",
+        "<span style=\"color:#cd0000;font-weight:bold\">bold</span>",
+        "<span style=\"color:#cd0000\"> red</span> &amp; ",
+        "<span style=\"color:#ff8700\">X</span>",
+    );
+    for step in [1, 4, 100] {
+        assert_eq!(html(text.as_bytes(), step, &convert::HtmlOptions::new()), expected);
+    }
+}
+
+#[test]
+fn to_html_classes() {
+    let mut options = convert::HtmlOptions::new();
+    options.classes(true);
+    let text = "\x1B[38;2;1;2;3;44;4:3ma\x1B[7mb\x1B[0m\x1B[7mc";
+    assert_eq!(
+        html(text.as_bytes(), 3, &options),
+        concat!(
+            "<span class=\"ansi-bg-blue ansi-curly-underline\" style=\"color:#010203\">a</span>",
+            "<span class=\"ansi-fg-blue ansi-curly-underline\" style=\"background-color:#010203\">b</span>",
+            "<span class=\"ansi-invert\">c</span>",
+        )
+    );
+    assert!(convert::stylesheet().contains(".ansi-invert{filter:invert(100%)}"));
+}
@@ -0,0 +1,55 @@
+use coloring::cursor::{self, Command};
+
+mod common;
+
+#[test]
+fn movement() {
+    assert_eq!(cursor::move_up(2).to_string(), "\x1B[2A");
+    assert_eq!(cursor::move_down(1).to_string(), "\x1B[1B");
+    assert_eq!(cursor::move_right(10).to_string(), "\x1B[10C");
+    assert_eq!(cursor::move_left(65535).to_string(), "\x1B[65535D");
+    assert_eq!(cursor::move_to(65535, 0).to_string(), "\x1B[65536;1H");
+    assert_eq!(cursor::move_to_column(0).to_string(), "\x1B[1G");
+}
+
+#[test]
+fn zero_moves_write_nothing() {
+    assert_eq!(cursor::move_up(0).to_string(), "");
+    assert_eq!(cursor::move_left(0).to_string(), "");
+}
+
+#[test]
+fn erasing_and_visibility() {
+    let all = [
+        cursor::erase_line(),
+        cursor::erase_to_end_of_line(),
+        cursor::erase_screen(),
+        cursor::save_cursor(),
+        cursor::restore_cursor(),
+        cursor::hide(),
+        cursor::show(),
+    ];
+    let text: String = all.iter().map(Command::to_string).collect();
+    assert_eq!(text, "\x1B[2K\x1B[K\x1B[2J\x1B7\x1B8\x1B[?25l\x1B[?25h");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_to() {
+    let mut buffer = Vec::new();
+    cursor::move_to(2, 3).write_to(&mut buffer).unwrap();
+    assert_eq!(buffer, b"\x1B[3;4H");
+}
+
+#[test]
+fn status_line() {
+    let _color = common::colors_on();
+    let mut status = cursor::StatusLine::new(10);
+    assert_eq!(status.begin(), "\n\x1B[1A\x1B7\x1B[1;9r\x1B8");
+    assert_eq!(status.update("ok"), "\x1B7\x1B[10;1H\x1B[2K\x1B[7mok\x1B[0m\x1B8");
+    assert_eq!(status.end(), "\x1B7\x1B[r\x1B[10;1H\x1B[2K\x1B8");
+
+    status.formatting(coloring::Formatting::new().foreground(coloring::Color::Green).build());
+    assert_eq!(status.update("ok"), "\x1B7\x1B[10;1H\x1B[2K\x1B[32mok\x1B[0m\x1B8");
+    assert_eq!(cursor::StatusLine::new(0).begin(), "\n\x1B[1A\x1B7\x1B[1;1r\x1B8");
+}
@@ -0,0 +1,50 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn renders_colors_and_styles() {
+    let _color = common::colors_on();
+    let text = Formatting::new().foreground(Color::BrightCyan).background(Color::Colors256(120)).add_style(Styles::Underline).apply_to("HI");
+    assert_eq!(debug_render(&text), "<fg:bright_cyan bg:256:120 underline>HI<reset>");
+
+    let text = Formatting::new().foreground(Color::RGB { r: 255, g: 0, b: 170 }).apply_to("HI");
+    assert_eq!(debug_render(&text), "<fg:#ff00aa>HI<reset>");
+}
+
+#[test]
+fn renders_resets() {
+    assert_eq!(debug_render("\x1B[23;49mHI\x1B[m"), "<no_italic bg:default>HI<>");
+    assert_eq!(debug_render("\x1B[39;22m"), "<fg:default no_bold>");
+}
+
+#[test]
+fn keeps_unknown_codes_and_other_escapes() {
+    assert_eq!(debug_render("\x1B[58;5;9m"), "<58;5;9>");
+    assert_eq!(debug_render("\x1B[2KHI"), "\x1B[2KHI");
+    assert_eq!(debug_parse("<58;5;9 bold>"), "\x1B[58;5;9;1m");
+}
+
+#[test]
+fn escapes_angle_brackets() {
+    assert_eq!(debug_render("a < b"), "a << b");
+    assert_eq!(debug_parse("a << b <<fg:red>>"), "a < b <fg:red>>");
+}
+
+#[test]
+fn leaves_unknown_tags() {
+    assert_eq!(debug_parse("<b>HI</b> <fg:nope>"), "<b>HI</b> <fg:nope>");
+    assert_eq!(debug_parse("unclosed <bold"), "unclosed <bold");
+}
+
+#[test]
+fn round_trips() {
+    let texts = [
+        Formatting::new().foreground(Color::Red).background(Color::Grayscale(12)).add_style(Styles::Bold).apply_to("HI <MOM>"),
+        format!("{} and {}", "left".blue().italic(), "right".on_yellow()),
+        "\x1B[m\x1B[0;22;55m\x1B[2J plain <<".to_string(),
+    ];
+    for text in &texts {
+        assert_eq!(&debug_parse(&debug_render(text)), text);
+    }
+}
@@ -0,0 +1,47 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn banner() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().foreground(Color::Black).background(Color::Yellow).build();
+    assert_eq!(decor::banner_with_width("abc", &formatting, 8), "\x1B[30;43m  abc   \x1B[0m");
+    assert_eq!(decor::banner_with_width("abcdef", &formatting, 4), "\x1B[30;43mabcdef\x1B[0m");
+
+    let styled = format!("a{}c", "b".bold());
+    assert_eq!(strip(&decor::banner_with_width(&styled, &formatting, 5)), " abc ");
+    assert!(visible_width(&decor::banner("abc", &formatting)) > 0);
+}
+
+#[test]
+fn hr() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().foreground(Color::Blue).build();
+    assert_eq!(decor::hr_with_width('-', &formatting, 3), "\x1B[34m---\x1B[0m");
+    assert_eq!(visible_width(&decor::hr('─', &formatting)), visible_width(&decor::banner("", &formatting)));
+}
+
+#[test]
+fn boxed() {
+    assert_eq!(decor::boxed("hi", decor::BoxStyle::Ascii, &Formatting::new()), "+----+\n| hi |\n+----+");
+    assert_eq!(decor::boxed("", decor::BoxStyle::Light, &Formatting::new()), "┌──┐\n│  │\n└──┘");
+    assert_eq!(decor::boxed("a\nbcd", decor::BoxStyle::Double, &Formatting::new()), "╔═════╗\n║ a   ║\n║ bcd ║\n╚═════╝");
+}
+
+#[test]
+fn boxed_styles() {
+    let _color = common::colors_on();
+    let border = Formatting::new().foreground(Color::Red).build();
+    assert_eq!(
+        decor::boxed("hi", decor::BoxStyle::Heavy, &border),
+        "\x1B[31m┏━━━━┓\x1B[0m\n\x1B[31m┃\x1B[0m hi \x1B[31m┃\x1B[0m\n\x1B[31m┗━━━━┛\x1B[0m"
+    );
+
+    // Styles spanning lines are closed before the border and reopened after it.
+    let text = "\x1B[1mbold\nstill\x1B[0m";
+    assert_eq!(
+        decor::boxed(text, decor::BoxStyle::Ascii, &Formatting::new()),
+        "+-------+\n| \x1B[1mbold\x1B[0m  |\n| \x1B[1mstill\x1B[0m |\n+-------+"
+    );
+}
@@ -0,0 +1,24 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn palette_shows_everything() {
+    let _color = common::colors_on();
+    let demo = demo::palette();
+    let plain = strip(&demo);
+    assert!(plain.starts_with("Basic colors\nblack          Sample        \n"));
+    assert!(plain.contains("bright white   Sample"));
+    assert!(plain.contains("256 colors\n  0   1   2 "));
+    assert!(plain.contains("bold faint italic underline"));
+    assert!(!plain.contains("reset") && !plain.contains("font"));
+
+    for index in 0..=255 {
+        assert!(demo.contains(&format!("48;5;{}m", index)), "{}", index);
+    }
+    assert!(demo.contains("\x1B[91mSample\x1B[0m"));
+    assert!(demo.contains("\x1B[4:3mcurly underline\x1B[0m"));
+    for line in demo.lines() {
+        assert!(visible_width(line) <= 80, "{:?}", line);
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg(all(feature = "derive", feature = "std"))]
+
+use coloring::*;
+
+#[derive(Styled)]
+struct Entry {
+    #[styled(fg = "cyan")]
+    time: &'static str,
+    #[styled(fg = "red", bold, curly_underline)]
+    level: &'static str,
+    message: &'static str,
+    #[styled(skip)]
+    _id: u64,
+}
+
+#[derive(Styled)]
+#[styled(fg = "blue", separator = ", ")]
+struct Point(i32, #[styled(dim)] i32);
+
+#[derive(Styled)]
+#[styled(bold)]
+struct Marker;
+
+#[derive(Styled)]
+#[styled(separator = "/")]
+enum Outcome<T> {
+    #[styled(fg = "green")]
+    Ok,
+    #[styled(fg = "yellow", separator = " x")]
+    Retried(T, u32),
+    Failed { #[styled(bg = "#ff0000")] reason: T },
+}
+
+#[test]
+fn structs() {
+    let _color = override_scope(ColorChoice::Always);
+    let entry = Entry { time: "12:00", level: "ERROR", message: "disk full", _id: 7 };
+    assert_eq!(entry.to_string(), "\x1B[36m12:00\x1B[0m \x1B[31;1;4:3mERROR\x1B[0m disk full");
+    assert_eq!(Point(3, -1).to_string(), "\x1B[34m3, \x1B[2m-1\x1B[0m\x1B[0m");
+    assert_eq!(Marker.to_string(), "\x1B[1mMarker\x1B[0m");
+}
+
+#[test]
+fn enums() {
+    let _color = override_scope(ColorChoice::Always);
+    assert_eq!(Outcome::<&str>::Ok.to_string(), "\x1B[32mOk\x1B[0m");
+    assert_eq!(Outcome::Retried("fetch", 3).to_string(), "\x1B[33mfetch x3\x1B[0m");
+    assert_eq!(
+        Outcome::Failed { reason: "timeout" }.to_string(),
+        "\x1B[48;2;255;0;0mtimeout\x1B[0m"
+    );
+}
+
+#[test]
+fn no_colors() {
+    let _plain = override_scope(ColorChoice::Never);
+    let entry = Entry { time: "12:00", level: "WARN", message: "slow", _id: 0 };
+    assert_eq!(entry.to_string(), "12:00 WARN slow");
+    assert_eq!(format!("[{}]", Point(1, 2)), "[1, 2]");
+}
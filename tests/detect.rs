@@ -0,0 +1,80 @@
+use coloring::detect::*;
+
+#[test]
+fn not_a_terminal() {
+    let caps = TerminalCaps::from_vars(Some("xterm-256color"), Some("truecolor"), false);
+    assert_eq!(caps.depth, ColorDepth::NoColor);
+}
+
+#[test]
+fn dumb() {
+    let caps = TerminalCaps::from_vars(Some("dumb"), None, true);
+    assert_eq!(caps.depth, ColorDepth::NoColor);
+}
+
+#[test]
+fn basic() {
+    let caps = TerminalCaps::from_vars(Some("xterm"), None, true);
+    assert_eq!(caps.depth, ColorDepth::Basic);
+}
+
+#[test]
+fn colors_256() {
+    let caps = TerminalCaps::from_vars(Some("screen-256color"), None, true);
+    assert_eq!(caps.depth, ColorDepth::Ansi256);
+}
+
+#[test]
+fn truecolor() {
+    let caps = TerminalCaps::from_vars(Some("xterm-256color"), Some("24bit"), true);
+    assert_eq!(caps.depth, ColorDepth::TrueColor);
+    assert!(caps.supports(ColorDepth::Ansi256));
+}
+
+#[test]
+fn hyperlinks() {
+    let vars = |pairs: &'static [(&'static str, &'static str)]| {
+        move |name: &str| pairs.iter().find(|(n, _)| *n == name).map(|(_, v)| v.to_string())
+    };
+    assert!(supports_hyperlinks_with(vars(&[("TERM", "xterm-kitty")])));
+    assert!(supports_hyperlinks_with(vars(&[("VTE_VERSION", "6003")])));
+    assert!(!supports_hyperlinks_with(vars(&[("VTE_VERSION", "4200")])));
+    assert!(!supports_hyperlinks_with(vars(&[("TERM", "xterm-256color")])));
+    assert!(!supports_hyperlinks_with(vars(&[("WT_SESSION", "x"), ("FORCE_HYPERLINK", "0")])));
+}
+
+#[cfg(feature = "terminal-size")]
+#[test]
+fn terminal_size() {
+    // Tests usually run without a terminal, so all we can check is that a size makes sense.
+    if let Some((columns, rows)) = coloring::terminal_size() {
+        assert!(columns > 0 && rows > 0);
+    }
+}
+
+#[test]
+fn force_color_levels() {
+    assert_eq!(ColorDepth::from_force_color("0"), ColorDepth::NoColor);
+    assert_eq!(ColorDepth::from_force_color("false"), ColorDepth::NoColor);
+    assert_eq!(ColorDepth::from_force_color("1"), ColorDepth::Basic);
+    assert_eq!(ColorDepth::from_force_color("true"), ColorDepth::Basic);
+    assert_eq!(ColorDepth::from_force_color("2"), ColorDepth::Ansi256);
+    assert_eq!(ColorDepth::from_force_color(" 3 "), ColorDepth::TrueColor);
+    assert_eq!(ColorDepth::from_force_color("4"), ColorDepth::TrueColor);
+}
+
+#[test]
+fn env_precedence() {
+    let vars = |set: &'static [(&'static str, &'static str)]| {
+        move |name: &str| set.iter().find(|(n, _)| *n == name).map(|(_, value)| value.to_string())
+    };
+    assert!(coloring::colors_enabled_with(vars(&[]), true));
+    assert!(!coloring::colors_enabled_with(vars(&[]), false));
+    assert!(coloring::colors_enabled_with(vars(&[("FORCE_COLOR", ""), ("NO_COLOR", "1")]), false));
+    assert!(!coloring::colors_enabled_with(vars(&[("FORCE_COLOR", "0"), ("CLICOLOR_FORCE", "1")]), true));
+    assert!(coloring::colors_enabled_with(vars(&[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]), false));
+    assert!(!coloring::colors_enabled_with(vars(&[("CLICOLOR_FORCE", "0"), ("NO_COLOR", "1")]), true));
+    assert!(coloring::colors_enabled_with(vars(&[("NO_COLOR", ""), ("CLICOLOR", "1")]), true));
+    assert!(!coloring::colors_enabled_with(vars(&[("CLICOLOR", "0")]), true));
+    assert!(!coloring::colors_enabled_with(vars(&[("CLICOLOR", "1")]), false));
+}
@@ -0,0 +1,45 @@
+use coloring::effects;
+
+mod common;
+
+#[test]
+fn rainbow() {
+    let _color = common::colors_on();
+    assert_eq!(
+        effects::rainbow("abc", 120.0),
+        "\x1B[38;2;255;0;0ma\x1B[38;2;0;255;0mb\x1B[38;2;0;0;255mc\x1B[0m"
+    );
+}
+
+#[test]
+fn rainbow_restarts_lines() {
+    let _color = common::colors_on();
+    assert_eq!(
+        effects::rainbow("ab\na", 120.0),
+        "\x1B[38;2;255;0;0ma\x1B[38;2;0;255;0mb\n\x1B[38;2;255;0;0ma\x1B[0m"
+    );
+}
+
+#[test]
+fn lolcat_spreads_lines() {
+    let _color = common::colors_on();
+    assert_eq!(
+        effects::lolcat("a\na", 120.0, 1.0),
+        "\x1B[38;2;255;0;0ma\n\x1B[38;2;0;255;0ma\x1B[0m"
+    );
+}
+
+#[test]
+fn skips_escapes_and_whitespace() {
+    let _color = common::colors_on();
+    assert_eq!(
+        effects::rainbow("\x1B[1ma b", 120.0),
+        "\x1B[1m\x1B[38;2;255;0;0ma \x1B[38;2;0;0;255mb\x1B[0m"
+    );
+}
+
+#[test]
+fn empty() {
+    assert_eq!(effects::rainbow("", 10.0), "");
+    assert_eq!(effects::rainbow(" \n ", 10.0), " \n ");
+}
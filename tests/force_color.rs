@@ -0,0 +1,19 @@
+#![cfg(feature = "std")]
+
+use coloring::detect::{ColorDepth, TerminalCaps};
+use coloring::*;
+
+// One test, since the environment is process-wide and only read once.
+#[test]
+fn force_color() {
+    std::env::set_var("FORCE_COLOR", "2");
+    std::env::set_var("NO_COLOR", "1");
+    // Tests don't run with stdout on a terminal, but `FORCE_COLOR` doesn't care.
+    assert!(colors_enabled_for(Stream::Stdout));
+    assert_eq!("text".red().to_string(), "\x1B[31mtext\x1B[0m");
+    assert_eq!(TerminalCaps::detect().depth, ColorDepth::Ansi256);
+
+    set_color_choice(ColorChoice::Never);
+    assert!(!colors_enabled_for(Stream::Stdout));
+    set_color_choice(ColorChoice::Auto);
+}
@@ -0,0 +1,34 @@
+use coloring::*;
+use coloring::gradient::Gradient;
+
+mod common;
+
+#[test]
+fn endpoints() {
+    let _color = common::colors_on();
+    let gradient = Gradient::new(Color::RGB { r: 0, g: 0, b: 0 }, Color::RGB { r: 255, g: 100, b: 10 });
+    assert_eq!(
+        gradient.apply_to("abc"),
+        "\x1B[38;2;0;0;0ma\x1B[38;2;128;50;5mb\x1B[38;2;255;100;10mc\x1B[0m"
+    );
+}
+
+#[test]
+fn skips_whitespace() {
+    let _color = common::colors_on();
+    let gradient = Gradient::new(Color::RGB { r: 0, g: 0, b: 0 }, Color::RGB { r: 100, g: 100, b: 100 });
+    assert_eq!(gradient.apply_to("a b"), "\x1B[38;2;0;0;0ma \x1B[38;2;100;100;100mb\x1B[0m");
+}
+
+#[test]
+fn single_character() {
+    let _color = common::colors_on();
+    let gradient = Gradient::new(Color::Red, Color::Blue);
+    assert_eq!(gradient.apply_to("a"), "\x1B[38;2;205;0;0ma\x1B[0m");
+}
+
+#[test]
+fn at() {
+    let gradient = Gradient::new(Color::Black, Color::BrightWhite);
+    assert!(matches!(gradient.at(2.0), Color::RGB { r: 255, g: 255, b: 255 }));
+}
@@ -0,0 +1,37 @@
+use coloring::*;
+use coloring::gradient::Gradient;
+
+#[test]
+fn heatmap_ends() {
+    assert_eq!(
+        heatmap::heatmap(&[5.0, -1.0, f64::INFINITY]),
+        [Color::RGB { r: 215, g: 0, b: 0 }, Color::RGB { r: 0, g: 175, b: 0 }, Color::Default]
+    );
+    assert!(heatmap::heatmap(&[]).is_empty());
+}
+
+#[test]
+fn heatmap_flat() {
+    let ramp = Gradient::new(Color::Black, Color::BrightWhite);
+    assert_eq!(heatmap::heatmap_with(&[2.0, 2.0], &ramp), [Color::RGB { r: 0, g: 0, b: 0 }; 2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sparkline() {
+    let _guard = override_scope(ColorChoice::Always);
+    assert_eq!(
+        heatmap::sparkline(&[0.0, f64::NAN, 7.0]),
+        "\x1B[38;2;0;175;0m▁ \x1B[38;2;215;0;0m█\x1B[0m"
+    );
+    assert_eq!(heatmap::sparkline(&[f64::NAN]), " ");
+    assert_eq!(heatmap::sparkline(&[]), "");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sparkline_plain() {
+    let _guard = override_scope(ColorChoice::Never);
+    assert_eq!(heatmap::sparkline(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]), "▁▂▃▄▅▆▇█");
+    assert_eq!(heatmap::sparkline(&[3.0, 3.0]), "▁▁");
+}
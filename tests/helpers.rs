@@ -0,0 +1,40 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn colorize_diff() {
+    let _color = common::colors_on();
+    let diff = "diff --git a/x b/x\n--- a/x\n+++ b/x\n@@ -1,2 +1,2 @@ fn main\n context\n-old\n+new\n";
+    assert_eq!(
+        helpers::colorize_diff(diff),
+        "\x1B[1mdiff --git a/x b/x\x1B[0m\n\x1B[1m--- a/x\x1B[0m\n\x1B[1m+++ b/x\x1B[0m\n\
+         \x1B[36m@@ -1,2 +1,2 @@ fn main\x1B[0m\n context\n\x1B[31m-old\x1B[0m\n\x1B[32m+new\x1B[0m\n"
+    );
+}
+
+#[test]
+fn colorize_diff_without_trailing_newline() {
+    let _color = common::colors_on();
+    assert_eq!(helpers::colorize_diff("+a\n\n-b"), "\x1B[32m+a\x1B[0m\n\n\x1B[31m-b\x1B[0m");
+}
+
+#[test]
+fn by_threshold() {
+    let formats = [
+        Formatting::new().foreground(Color::Green).build(),
+        Formatting::new().foreground(Color::Yellow).build(),
+        Formatting::new().foreground(Color::Red).build(),
+    ];
+    let band = |value| helpers::by_threshold(value, &[100.0, 500.0], &formats);
+    assert_eq!(band(-5.0), formats[0]);
+    assert_eq!(band(99.9), formats[0]);
+    assert_eq!(band(100.0), formats[1]);
+    assert_eq!(band(499.0), formats[1]);
+    assert_eq!(band(500.0), formats[2]);
+    assert_eq!(band(f64::NAN), formats[2]);
+
+    assert_eq!(helpers::by_threshold(7, &[1, 2, 3], &formats[..2]), formats[1]);
+    assert_eq!(helpers::by_threshold(7, &[1], &[]), Formatting::new());
+    assert_eq!(helpers::by_threshold("m", &["n"], &formats), formats[0]);
+}
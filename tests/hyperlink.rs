@@ -0,0 +1,13 @@
+use coloring::*;
+
+// Everything lives in one test since the choice is process-wide.
+#[test]
+fn hyperlink_choice_toggles_links() {
+    set_hyperlink_choice(ColorChoice::Always);
+    assert_eq!(hyperlink_choice(), ColorChoice::Always);
+    assert_eq!(hyperlink("a", "https://x.y/z"), "\x1B]8;;https://x.y/z\x1B\\a\x1B]8;;\x1B\\");
+    assert_eq!(hyperlink("a", "https://x.y/\x1B\x07z"), "\x1B]8;;https://x.y/z\x1B\\a\x1B]8;;\x1B\\");
+
+    set_hyperlink_choice(ColorChoice::Never);
+    assert_eq!(hyperlink("a", "https://x.y/z"), "a");
+}
@@ -1,55 +1,767 @@
 use coloring::*;
 
+mod common;
+
 #[test]
 fn fg() {
+    let _color = common::colors_on();
     let fg = Formatting::new().foreground(Color::Blue).apply_to("text");
     assert_eq!(fg, "\x1B[34mtext\x1B[0m");
 }
 
 #[test]
 fn bg() {
+    let _color = common::colors_on();
     let bg = Formatting::new().background(Color::Blue).apply_to("text");
     assert_eq!(bg, "\x1B[44mtext\x1B[0m");
 }
 
 #[test]
 fn style() {
+    let _color = common::colors_on();
     let styled = Formatting::new().styles(vec![Styles::Bold]).apply_to("text");
     assert_eq!(styled, "\x1B[1mtext\x1B[0m");
 }
 
 #[test]
 fn styles() {
+    let _color = common::colors_on();
     let styled = Formatting::new().styles(vec![Styles::Bold, Styles::Invert]).apply_to("text");
     assert_eq!(styled, "\x1B[1;7mtext\x1B[0m");
 }
 
 #[test]
 fn fg_bg() {
+    let _color = common::colors_on();
     let formatted = Formatting::new().foreground(Color::Blue).background(Color::Blue).apply_to("text");
     assert_eq!(formatted, "\x1B[34;44mtext\x1B[0m");
 }
 
 #[test]
 fn fg_style() {
+    let _color = common::colors_on();
     let formatted = Formatting::new().foreground(Color::Blue).styles(vec![Styles::Bold]).apply_to("text");
     assert_eq!(formatted, "\x1B[34;1mtext\x1B[0m");
 }
 
 #[test]
 fn bg_style() {
+    let _color = common::colors_on();
     let formatted = Formatting::new().background(Color::Blue).styles(vec![Styles::Bold]).apply_to("text");
     assert_eq!(formatted, "\x1B[44;1mtext\x1B[0m");
 }
 
 #[test]
 fn all() {
+    let _color = common::colors_on();
     let formatted = Formatting::new().foreground(Color::Blue).background(Color::Blue).styles(vec![Styles::Bold]).apply_to("text");
     assert_eq!(formatted, "\x1B[34;44;1mtext\x1B[0m");
 }
 
 #[test]
 fn none() {
+    let _color = common::colors_on();
     let formatted = Formatting::new().apply_to("text");
     assert_eq!(formatted, "\x1B[mtext\x1B[0m");
 }
+
+#[test]
+fn paint() {
+    let _color = common::colors_on();
+    let mut formatting = Formatting::new();
+    formatting.foreground(Color::Blue).styles(vec![Styles::Bold]);
+    assert_eq!(format!("{}", formatting.paint("text")), "\x1B[34;1mtext\x1B[0m");
+}
+
+#[test]
+fn paint_matches_apply_to() {
+    let mut formatting = Formatting::new();
+    formatting.foreground(Color::RGB { r: 1, g: 2, b: 3 }).background(Color::Colors256(200));
+    assert_eq!(formatting.paint("text").to_string(), formatting.apply_to("text"));
+}
+
+#[test]
+fn downgrade_rgb_to_256() {
+    let _color = common::colors_on();
+    use coloring::detect::ColorDepth;
+    let formatted = Formatting::new().foreground(Color::RGB { r: 0, g: 135, b: 255 }).downgrade_to(ColorDepth::Ansi256).apply_to("text");
+    assert_eq!(formatted, "\x1B[38;5;33mtext\x1B[0m");
+}
+
+#[test]
+fn downgrade_gray_to_256() {
+    let _color = common::colors_on();
+    use coloring::detect::ColorDepth;
+    let formatted = Formatting::new().background(Color::RGB { r: 128, g: 128, b: 128 }).downgrade_to(ColorDepth::Ansi256).apply_to("text");
+    assert_eq!(formatted, "\x1B[48;5;244mtext\x1B[0m");
+}
+
+#[test]
+fn downgrade_to_basic() {
+    let _color = common::colors_on();
+    use coloring::detect::ColorDepth;
+    let formatted = Formatting::new().foreground(Color::RGB { r: 250, g: 10, b: 10 }).background(Color::Colors256(21)).downgrade_to(ColorDepth::Basic).apply_to("text");
+    assert_eq!(formatted, "\x1B[91;44mtext\x1B[0m");
+}
+
+#[test]
+fn downgrade_to_no_color() {
+    let _color = common::colors_on();
+    use coloring::detect::ColorDepth;
+    let formatted = Formatting::new().foreground(Color::Red).styles(vec![Styles::Bold]).downgrade_to(ColorDepth::NoColor).apply_to("text");
+    assert_eq!(formatted, "\x1B[1mtext\x1B[0m");
+}
+
+#[test]
+fn colorize_str() {
+    let _color = common::colors_on();
+    assert_eq!("text".red().bold().on_black().to_string(), "\x1B[31;40;1mtext\x1B[0m");
+}
+
+#[test]
+fn colorize_string() {
+    let _color = common::colors_on();
+    assert_eq!(String::from("text").on_blue().italic().underline().to_string(), "\x1B[44;3;4mtext\x1B[0m");
+}
+
+#[test]
+fn colorize_display() {
+    let _color = common::colors_on();
+    assert_eq!(42.color(Color::Colors256(9)).to_string(), "\x1B[38;5;9m42\x1B[0m");
+}
+
+#[test]
+fn colorize_paint_with() {
+    let _color = common::colors_on();
+    let mut formatting = Formatting::new();
+    formatting.foreground(Color::Green);
+    assert_eq!("text".paint_with(formatting).bold().to_string(), "\x1B[32;1mtext\x1B[0m");
+}
+
+#[test]
+fn nested() {
+    let _color = common::colors_on();
+    let inner = Formatting::new().foreground(Color::Red).apply_to("inner");
+    let outer = Formatting::new().background(Color::Blue).apply_to(&format!("a {} b", inner));
+    assert_eq!(outer, "\x1B[44ma \x1B[31minner\x1B[0m\x1B[44m b\x1B[0m");
+}
+
+#[test]
+fn nested_at_end() {
+    let _color = common::colors_on();
+    let inner = Formatting::new().foreground(Color::Red).apply_to("inner");
+    let outer = Formatting::new().background(Color::Blue).apply_to(&format!("a {}", inner));
+    assert_eq!(outer, "\x1B[44ma \x1B[31minner\x1B[0m\x1B[0m");
+}
+
+#[test]
+fn nested_colorize() {
+    let _color = common::colors_on();
+    let outer = format!("a {} b", "inner".red()).on_blue().to_string();
+    assert_eq!(outer, "\x1B[44ma \x1B[31minner\x1B[0m\x1B[44m b\x1B[0m");
+}
+
+#[test]
+fn nested_styled_value() {
+    let _color = common::colors_on();
+    let outer = "inner".red().bold().on_blue().to_string();
+    assert_eq!(outer, "\x1B[31;44;1minner\x1B[0m");
+    let outer = "inner".red().underline().to_string().italic().to_string();
+    assert_eq!(outer, "\x1B[3m\x1B[31;4minner\x1B[0m\x1B[0m");
+}
+
+#[test]
+fn parse_color_names() {
+    assert!(matches!("red".parse(), Ok(Color::Red)));
+    assert!(matches!("Bright_Cyan".parse(), Ok(Color::BrightCyan)));
+    assert!(matches!("bright-cyan".parse(), Ok(Color::BrightCyan)));
+    assert!(matches!("bright cyan".parse(), Ok(Color::BrightCyan)));
+}
+
+#[test]
+fn parse_color_hex() {
+    assert!(matches!("#ffcc00".parse(), Ok(Color::RGB { r: 255, g: 204, b: 0 })));
+    assert!(matches!("ffcc00".parse(), Ok(Color::RGB { r: 255, g: 204, b: 0 })));
+    assert!(matches!("#fc0".parse(), Ok(Color::RGB { r: 255, g: 204, b: 0 })));
+    assert!("fc0".parse::<Color>().is_err());
+    assert!("bad".parse::<Color>().is_err());
+}
+
+#[test]
+fn parse_color_rgb() {
+    assert!(matches!("rgb(1, 2,3)".parse(), Ok(Color::RGB { r: 1, g: 2, b: 3 })));
+    assert!("rgb(1, 2)".parse::<Color>().is_err());
+    assert!("rgb(1, 2, 3, 4)".parse::<Color>().is_err());
+    assert!("rgb(1, 2, 300)".parse::<Color>().is_err());
+}
+
+#[test]
+fn parse_color_256() {
+    assert!(matches!("120".parse(), Ok(Color::Colors256(120))));
+    assert!(matches!("256:7".parse(), Ok(Color::Colors256(7))));
+    assert!("256".parse::<Color>().is_err());
+}
+
+#[test]
+fn parse_color_error() {
+    let err = "octarine".parse::<Color>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid color: \"octarine\"");
+}
+
+#[test]
+fn spec() {
+    let _color = common::colors_on();
+    let formatting = Formatting::from_spec("bold red on blue").unwrap();
+    assert_eq!(formatting.apply_to("text"), "\x1B[31;44;1mtext\x1B[0m");
+}
+
+#[test]
+fn spec_any_order() {
+    let _color = common::colors_on();
+    let formatting: Formatting = "on #000000 underline 208 dim".parse().unwrap();
+    assert_eq!(formatting.apply_to("text"), "\x1B[38;5;208;48;2;0;0;0;2;4mtext\x1B[0m");
+}
+
+#[test]
+fn spec_bright() {
+    let _color = common::colors_on();
+    let formatting = Formatting::from_spec("bright red on bright black").unwrap();
+    assert_eq!(formatting.apply_to("text"), "\x1B[91;100mtext\x1B[0m");
+}
+
+#[test]
+fn spec_rgb_with_spaces() {
+    let _color = common::colors_on();
+    let formatting = Formatting::from_spec("rgb(1, 2, 3) on rgb( 4,5, 6 )").unwrap();
+    assert_eq!(formatting.apply_to("text"), "\x1B[38;2;1;2;3;48;2;4;5;6mtext\x1B[0m");
+}
+
+#[test]
+fn spec_empty() {
+    assert_eq!(Formatting::from_spec("").unwrap().apply_to("text"), Formatting::new().apply_to("text"));
+}
+
+#[test]
+fn spec_errors() {
+    assert!(Formatting::from_spec("bold on").is_err());
+    assert!(Formatting::from_spec("red blue").is_err());
+    assert!(Formatting::from_spec("on red on blue").is_err());
+    assert!(Formatting::from_spec("bad").is_err());
+    assert!(Formatting::from_spec("bold red on bed").is_err());
+    let err = Formatting::from_spec("bold sparkly").unwrap_err();
+    assert_eq!(err.to_string(), "invalid style spec \"bold sparkly\": unknown word \"sparkly\"");
+}
+
+#[test]
+fn try_from_spec() {
+    assert_eq!(Formatting::try_from_spec("bold red"), Ok(Formatting::from_spec("bold red").unwrap()));
+    let err = Formatting::try_from_spec("red blue").unwrap_err();
+    assert!(matches!(err, Error::ParseSpec(_)));
+    let err = Formatting::try_from_spec("italic dashed_underline double_underline").unwrap_err();
+    assert_eq!(err.to_string(), "conflicting styles: double underline and dashed underline");
+}
+
+#[test]
+fn validate_styles() {
+    let valid = |styles: &[Styles]| Formatting::new().styles(styles.iter().copied()).validate().is_ok();
+    assert!(valid(&[Styles::Bold, Styles::Italic, Styles::CurlyUnderline, Styles::Overline, Styles::AltFont2]));
+    assert!(!valid(&[Styles::Frame, Styles::Encircle]));
+    assert!(!valid(&[Styles::DefaultFont, Styles::AltFont1]));
+    assert!(!valid(&[Styles::AltFont1, Styles::AltFont9]));
+    assert!(!valid(&[Styles::Reset, Styles::Italic]));
+    assert!(valid(&[Styles::Reset]));
+}
+
+#[test]
+fn try_cube() {
+    assert_eq!(Color::try_cube(0, 2, 5), Ok(Color::Colors256(33)));
+    assert_eq!(Color::try_cube(9, 0, 0), Err(Error::OutOfRange { what: "color cube red", value: 9, max: 5 }));
+}
+
+#[test]
+fn error_from_parse_errors() {
+    fn parse(color: &str) -> Result<Color, Error> {
+        Ok(color.parse::<Color>()?)
+    }
+    let err = parse("octarine").unwrap_err();
+    assert_eq!(err.to_string(), "couldn't parse a color");
+    assert_eq!(std::error::Error::source(&err).unwrap().to_string(), "invalid color: \"octarine\"");
+}
+
+#[test]
+fn targeted_reset() {
+    let _color = common::colors_on();
+    let formatted = Formatting::new().foreground(Color::Blue).background(Color::Red).styles(vec![Styles::Bold, Styles::Faint, Styles::Invert]).reset_mode(ResetMode::Targeted).apply_to("text");
+    assert_eq!(formatted, "\x1B[34;41;1;2;7mtext\x1B[39;49;22;27m");
+}
+
+#[test]
+fn targeted_reset_none() {
+    let formatted = Formatting::new().reset_mode(ResetMode::Targeted).apply_to("text");
+    assert_eq!(formatted, "text");
+}
+
+#[test]
+fn targeted_reset_keeps_outer() {
+    let _color = common::colors_on();
+    let inner = Formatting::new().foreground(Color::Red).reset_mode(ResetMode::Targeted).apply_to("inner");
+    let outer = Formatting::new().styles(vec![Styles::Bold]).apply_to(&format!("a {} b", inner));
+    assert_eq!(outer, "\x1B[1ma \x1B[31minner\x1B[39m b\x1B[0m");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_to() {
+    let _color = common::colors_on();
+    let mut buffer: Vec<u8> = Vec::new();
+    Formatting::new().foreground(Color::Blue).write_to(&mut buffer, "text").unwrap();
+    assert_eq!(buffer, b"\x1B[34mtext\x1B[0m");
+}
+
+#[test]
+fn write_to_fmt() {
+    let _color = common::colors_on();
+    let mut buffer = String::from("a ");
+    Formatting::new().background(Color::Blue).write_to_fmt(&mut buffer, "text").unwrap();
+    assert_eq!(buffer, "a \x1B[44mtext\x1B[0m");
+}
+
+#[test]
+fn extended_underlines() {
+    let _color = common::colors_on();
+    let formatted = Formatting::new().styles(vec![Styles::DoubleUnderline, Styles::CurlyUnderline, Styles::DottedUnderline, Styles::DashedUnderline]).apply_to("text");
+    assert_eq!(formatted, "\x1B[4:2;4:3;4:4;4:5mtext\x1B[0m");
+}
+
+#[test]
+fn style_discriminants_are_sgr_codes() {
+    assert_eq!(Styles::Bold as u8, 1);
+    assert_eq!(Styles::Invert as u8, 7);
+    assert_eq!(Styles::Strikethrough as u8, 9);
+    assert_eq!(Styles::RapidBlink as u8, 6);
+    assert_eq!(Styles::DoubleUnderline as u8, 21);
+    let set = StyleSet::new().with(Styles::Invert).with(Styles::AltFont9).with(Styles::DashedUnderline);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [Styles::Invert, Styles::DashedUnderline, Styles::AltFont9]);
+}
+
+#[test]
+fn extended_underline_compat() {
+    let _color = common::colors_on();
+    let formatted = Formatting::new().foreground(Color::Red).styles(vec![Styles::CurlyUnderline]).compat(true).apply_to("text");
+    assert_eq!(formatted, "\x1B[31;4mtext\x1B[0m");
+}
+
+#[test]
+fn extended_underline_targeted_reset() {
+    let _color = common::colors_on();
+    let formatted = Formatting::new().styles(vec![Styles::DottedUnderline]).reset_mode(ResetMode::Targeted).apply_to("text");
+    assert_eq!(formatted, "\x1B[4:4mtext\x1B[24m");
+}
+
+#[test]
+fn color_to_256() {
+    assert!(matches!(Color::RGB { r: 95, g: 135, b: 175 }.to_256(), Color::Colors256(67)));
+    assert!(matches!(Color::RGB { r: 238, g: 238, b: 238 }.to_256(), Color::Colors256(255)));
+    assert!(matches!(Color::Black.to_256(), Color::Colors256(0)));
+    assert!(matches!(Color::Default.to_256(), Color::Default));
+}
+
+#[test]
+fn color_to_basic() {
+    assert!(matches!(Color::Colors256(46).to_basic(), Color::BrightGreen));
+    assert!(matches!(Color::Colors256(4).to_basic(), Color::Blue));
+    assert!(matches!(Color::Cyan.to_basic(), Color::Cyan));
+}
+
+#[test]
+fn color_to_rgb() {
+    assert!(matches!(Color::Colors256(244).to_rgb(), Color::RGB { r: 128, g: 128, b: 128 }));
+    assert!(matches!(Color::Colors256(16).to_rgb(), Color::RGB { r: 0, g: 0, b: 0 }));
+    assert!(matches!(Color::Blue.to_rgb(), Color::RGB { r: 0, g: 0, b: 238 }));
+}
+
+#[test]
+fn color_from_hsl() {
+    assert!(matches!(Color::from_hsl(0.0, 1.0, 0.5), Color::RGB { r: 255, g: 0, b: 0 }));
+    assert!(matches!(Color::from_hsl(240.0, 1.0, 0.25), Color::RGB { r: 0, g: 0, b: 128 }));
+    assert!(matches!(Color::from_hsl(123.0, 0.0, 1.0), Color::RGB { r: 255, g: 255, b: 255 }));
+    assert!(matches!(Color::from_hsl(-240.0, 0.5, 0.5), Color::RGB { r: 64, g: 191, b: 64 }));
+}
+
+#[test]
+fn color_hsl() {
+    assert!(matches!(Color::hsl(120.0, 1.0, 0.5), Color::RGB { r: 0, g: 255, b: 0 }));
+    assert!(matches!(Color::hsl(0.0, 0.0, 0.5), Color::RGB { r: 128, g: 128, b: 128 }));
+}
+
+#[test]
+fn color_hsv() {
+    assert!(matches!(Color::hsv(0.0, 1.0, 1.0), Color::RGB { r: 255, g: 0, b: 0 }));
+    assert!(matches!(Color::hsv(300.0, 0.5, 1.0), Color::RGB { r: 255, g: 128, b: 255 }));
+    assert!(matches!(Color::hsv(420.0, 1.0, 1.0), Color::RGB { r: 255, g: 255, b: 0 }));
+    assert!(matches!(Color::hsv(10.0, 1.0, 0.0), Color::RGB { r: 0, g: 0, b: 0 }));
+}
+
+#[test]
+fn build() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().foreground(Color::Blue).styles(vec![Styles::Bold]).build();
+    assert_eq!(formatting.apply_to("text"), "\x1B[34;1mtext\x1B[0m");
+}
+
+#[test]
+fn build_is_a_copy() {
+    let _color = common::colors_on();
+    let mut base = Formatting::new();
+    base.foreground(Color::Blue);
+    let built = base.build();
+    base.background(Color::Red);
+    assert_eq!(built.apply_to("text"), "\x1B[34mtext\x1B[0m");
+    assert_eq!(base.apply_to("text"), "\x1B[34;41mtext\x1B[0m");
+}
+
+const CONST_FORMATTING: Formatting = Formatting::new()
+    .with_foreground(Color::Red)
+    .with_background(Color::Colors256(8))
+    .with_styles(&[Styles::Italic, Styles::Bold])
+    .with_style(Styles::Underline);
+
+#[test]
+fn const_construction() {
+    let _color = common::colors_on();
+    assert_eq!(CONST_FORMATTING.apply_to("text"), "\x1B[31;48;5;8;1;3;4mtext\x1B[0m");
+}
+
+#[test]
+fn const_reset_mode() {
+    let _color = common::colors_on();
+    const TARGETED: Formatting = Formatting::new().with_style(Styles::Bold).with_reset_mode(ResetMode::Targeted);
+    assert_eq!(TARGETED.apply_to("text"), "\x1B[1mtext\x1B[22m");
+}
+
+#[test]
+fn styles_deduplicated_and_ordered() {
+    let _color = common::colors_on();
+    let formatted = Formatting::new().styles(vec![Styles::Invert, Styles::Bold, Styles::Invert]).apply_to("text");
+    assert_eq!(formatted, "\x1B[1;7mtext\x1B[0m");
+}
+
+#[test]
+fn merge_overrides_colors() {
+    let _color = common::colors_on();
+    let base = Formatting::new().foreground(Color::Blue).background(Color::Red).build();
+    let over = Formatting::new().foreground(Color::Green).build();
+    assert_eq!(base.merge(&over).apply_to("text"), "\x1B[32;41mtext\x1B[0m");
+    assert_eq!(over.merge(&base).apply_to("text"), "\x1B[34;41mtext\x1B[0m");
+}
+
+#[test]
+fn merge_combines_styles() {
+    let _color = common::colors_on();
+    let base = Formatting::new().styles(vec![Styles::Bold]).build();
+    let over = Formatting::new().styles(vec![Styles::Italic]).reset_mode(ResetMode::Targeted).build();
+    assert_eq!((&base + &over).apply_to("text"), "\x1B[1;3mtext\x1B[22;23m");
+}
+
+#[test]
+fn add_assign() {
+    let _color = common::colors_on();
+    let mut formatting = Formatting::new().foreground(Color::Blue).build();
+    formatting += &Formatting::new().styles(vec![Styles::Underline]).build();
+    assert_eq!(formatting.apply_to("text"), "\x1B[34;4mtext\x1B[0m");
+}
+
+#[test]
+fn style_methods() {
+    let _color = common::colors_on();
+    let formatted = Formatting::new().strikethrough().bold().italic().apply_to("text");
+    assert_eq!(formatted, "\x1B[1;3;9mtext\x1B[0m");
+}
+
+#[test]
+fn style_methods_add_to_styles() {
+    let _color = common::colors_on();
+    let formatted = Formatting::new().styles(vec![Styles::Blink]).dim().curly_underline().apply_to("text");
+    assert_eq!(formatted, "\x1B[2;5;4:3mtext\x1B[0m");
+}
+
+#[test]
+fn add_and_remove_style() {
+    let _color = common::colors_on();
+    let mut formatting = Formatting::new().styles(vec![Styles::Bold, Styles::Bold]).build();
+    formatting.add_style(Styles::Underline).add_style(Styles::Bold);
+    assert_eq!(formatting.apply_to("text"), "\x1B[1;4mtext\x1B[0m");
+    formatting.remove_style(Styles::Bold).remove_style(Styles::Italic);
+    assert_eq!(formatting.apply_to("text"), "\x1B[4mtext\x1B[0m");
+}
+
+#[test]
+fn style_set() {
+    let mut set = StyleSet::new().with(Styles::Strikethrough).with(Styles::Faint);
+    assert!(set.insert(Styles::Bold));
+    assert!(!set.insert(Styles::Bold));
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![Styles::Bold, Styles::Faint, Styles::Strikethrough]);
+    assert!(set.remove(Styles::Faint));
+    assert!(!set.contains(Styles::Faint));
+    assert_eq!(Formatting::new().styles(set).build().style_set(), set);
+}
+
+#[test]
+fn clear_everything() {
+    let _color = common::colors_on();
+    let base = Formatting::new()
+        .foreground(Color::Red)
+        .background(Color::Blue)
+        .styles(vec![Styles::Bold])
+        .build();
+    let formatted = base.build().clear_foreground().clear_background().clear_styles().apply_to("text");
+    assert_eq!(formatted, "\x1B[mtext\x1B[0m");
+    assert_eq!(base.apply_to("text"), "\x1B[31;44;1mtext\x1B[0m");
+}
+
+#[test]
+fn equality_and_hash() {
+    use std::collections::HashSet;
+
+    let a = Formatting::new().foreground(Color::Red).styles(vec![Styles::Bold, Styles::Italic]).build();
+    let b = Formatting::new().styles(vec![Styles::Italic, Styles::Bold]).foreground(Color::Red).build();
+    assert_eq!(a, b);
+    assert_ne!(a, Formatting::new().foreground(Color::Colors256(1)).styles(vec![Styles::Bold, Styles::Italic]).build());
+    assert_ne!(Color::Red, Color::Colors256(1));
+    assert_eq!(Color::RGB { r: 1, g: 2, b: 3 }, Color::RGB { r: 1, g: 2, b: 3 });
+
+    let set: HashSet<Formatting> = [a.clone(), b, Formatting::new()].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&a));
+}
+
+#[test]
+fn ordering() {
+    assert!(Color::Default < Color::Black);
+    assert!(Color::Colors256(3) < Color::Colors256(200));
+    assert!(Styles::Bold < Styles::Italic);
+}
+
+#[test]
+fn display_names() {
+    assert_eq!(Color::Red.to_string(), "red");
+    assert_eq!(Color::BrightMagenta.to_string(), "bright magenta");
+    assert_eq!(Color::Colors256(120).to_string(), "256:120");
+    assert_eq!(format!("[{:>8}]", Color::Default), "[ default]");
+    assert_eq!(Styles::DashedUnderline.to_string(), "dashed underline");
+    for color in [Color::BrightBlack, Color::Colors256(7), Color::RGB { r: 1, g: 2, b: 3 }] {
+        assert_eq!(color.to_string().parse::<Color>().unwrap(), color);
+    }
+}
+
+#[test]
+fn ansi_codes() {
+    assert_eq!(Color::Red.to_ansi_code(), "31");
+    assert_eq!(Color::Red.to_ansi_bg_code(), "41");
+    assert_eq!(Color::Colors256(42).to_ansi_code(), "38;5;42");
+    assert_eq!(Color::RGB { r: 1, g: 2, b: 3 }.to_ansi_bg_code(), "48;2;1;2;3");
+    assert_eq!(Color::Default.to_ansi_bg_code(), "49");
+    assert_eq!(Styles::Invert.to_ansi_code(), "7");
+    assert_eq!(Styles::DoubleUnderline.to_ansi_code(), "4:2");
+}
+
+#[test]
+fn extra_sgr_styles() {
+    let _color = common::colors_on();
+    let formatted = Formatting::new()
+        .styles(vec![Styles::AltFont3, Styles::Overline, Styles::RapidBlink, Styles::Encircle, Styles::Frame])
+        .apply_to("text");
+    assert_eq!(formatted, "\x1B[6;53;51;52;13mtext\x1B[0m");
+    assert_eq!(Styles::DefaultFont.to_ansi_code(), "10");
+    assert_eq!(Styles::AltFont9.to_ansi_code(), "19");
+}
+
+#[test]
+fn extra_sgr_styles_compat_and_reset() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new()
+        .styles(vec![Styles::RapidBlink, Styles::Overline, Styles::Frame, Styles::AltFont1])
+        .compat(true)
+        .reset_mode(ResetMode::Targeted)
+        .build();
+    assert_eq!(formatting.apply_to("text"), "\x1B[5;53;51;11mtext\x1B[25;55;54;10m");
+}
+
+#[test]
+fn extra_sgr_style_names() {
+    let _color = common::colors_on();
+    assert_eq!(Styles::RapidBlink.to_string(), "rapid blink");
+    assert_eq!(Styles::AltFont2.to_string(), "alt font 2");
+    let formatting = Formatting::from_spec("overline encircle alt_font_5").unwrap();
+    assert_eq!(formatting.apply_to("text"), "\x1B[53;52;15mtext\x1B[0m");
+}
+
+#[test]
+fn style_off_codes() {
+    assert_eq!(Styles::Bold.off(), "\x1B[22m");
+    assert_eq!(Styles::DottedUnderline.off(), "\x1B[24m");
+    assert_eq!(Styles::Encircle.off(), "\x1B[54m");
+    assert_eq!(Styles::AltFont4.off(), "\x1B[10m");
+    assert_eq!(Styles::Reset.off(), "");
+    assert_eq!(Styles::Overline.off_code(), Some(55));
+}
+
+#[test]
+fn apply_to_display() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().foreground(Color::Blue).bold().build();
+    assert_eq!(formatting.apply_to_display(&1.5), "\x1B[34;1m1.5\x1B[0m");
+    assert_eq!(formatting.apply_to_display("text"), formatting.apply_to("text"));
+    let nested = format!("a{}b", "red".red());
+    assert_eq!(formatting.apply_to_display(&nested), formatting.apply_to(&nested));
+}
+
+#[test]
+fn per_line() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().background(Color::Red).per_line(true).build();
+    assert_eq!(formatting.apply_to("a\nb"), "\x1B[41ma\x1B[0m\n\x1B[41mb\x1B[0m");
+    assert_eq!(formatting.apply_to("a\n\nb\n"), "\x1B[41ma\x1B[0m\n\n\x1B[41mb\x1B[0m\n");
+    assert_eq!(formatting.apply_to_display(&"a\nb"), "\x1B[41ma\x1B[0m\n\x1B[41mb\x1B[0m");
+}
+
+#[test]
+fn per_line_targeted() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().foreground(Color::Red).per_line(true).reset_mode(ResetMode::Targeted).build();
+    assert_eq!(formatting.apply_to("a\nb"), "\x1B[31ma\x1B[39m\n\x1B[31mb\x1B[39m");
+    assert_eq!(Formatting::new().per_line(true).apply_to("a\nb"), "\x1B[ma\x1B[0m\n\x1B[mb\x1B[0m");
+}
+
+#[test]
+fn fill_line() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().background(Color::Green).fill_line(true).per_line(true).build();
+    assert_eq!(formatting.apply_to("a\nb"), "\x1B[42ma\x1B[K\x1B[0m\n\x1B[42mb\x1B[K\x1B[0m");
+    assert_eq!(Formatting::new().foreground(Color::Green).fill_line(true).apply_to("a"), "\x1B[32ma\x1B[0m");
+}
+
+#[test]
+fn when() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().foreground(Color::Red).when(false).build();
+    assert_eq!(formatting.apply_to("a"), "a");
+    assert_eq!(formatting.apply_to_display(&1), "1");
+    assert_eq!(format!("[{:>3}]", formatting.paint("a")), "[  a]");
+    assert_eq!(formatting.prefix(), "");
+    assert_eq!(formatting.suffix(), "");
+    assert_eq!(format!("{}", "a".red().when(false)), "a");
+    assert_eq!(Formatting::new().bold().merge(&formatting).apply_to("a"), "a");
+
+    let formatting = Formatting::new().foreground(Color::Red).when(false).when(true).build();
+    assert_eq!(formatting.apply_to("a"), "\x1B[31ma\x1B[0m");
+}
+
+#[test]
+fn apply_if() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().foreground(Color::Red).build();
+    assert_eq!(formatting.apply_if(true, "a"), "\x1B[31ma\x1B[0m");
+    assert_eq!(formatting.apply_if(false, "a"), "a");
+}
+
+#[test]
+fn grayscale() {
+    let _color = common::colors_on();
+    assert_eq!(Formatting::new().foreground(Color::Grayscale(0)).apply_to("text"), "\x1B[38;5;232mtext\x1B[0m");
+    assert_eq!(Formatting::new().background(Color::Grayscale(99)).apply_to("text"), "\x1B[48;5;255mtext\x1B[0m");
+    assert_eq!(Color::Grayscale(10).to_rgb(), Color::RGB { r: 108, g: 108, b: 108 });
+    assert_eq!(Color::gray_percent(-5.0), Color::Grayscale(0));
+    assert_eq!(Color::gray_percent(25.0), Color::Grayscale(6));
+}
+
+#[test]
+fn grayscale_names() {
+    assert_eq!(Color::Grayscale(7).to_string(), "gray:7");
+    assert_eq!("grey:23".parse::<Color>().unwrap(), Color::Grayscale(23));
+    assert!("gray:24".parse::<Color>().is_err());
+}
+
+#[test]
+fn cube() {
+    assert_eq!(Color::cube(0, 0, 0), Some(Color::Colors256(16)));
+    assert_eq!(Color::cube(5, 5, 5), Some(Color::Colors256(231)));
+    assert_eq!(Color::cube(1, 2, 3).map(|c| c.to_rgb()), Some(Color::RGB { r: 95, g: 135, b: 175 }));
+    assert_eq!(Color::cube(0, 9, 0), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn contrast() {
+    assert_eq!(Color::Black.luminance(), Some(0.0));
+    assert_eq!(Color::Default.luminance(), None);
+    let ratio = Color::RGB { r: 255, g: 255, b: 255 }.contrast_ratio(&Color::Black).unwrap();
+    assert!((ratio - 21.0).abs() < 0.001);
+    assert_eq!(Color::BrightWhite.contrast_fg(), Color::Black);
+    assert_eq!(Color::Blue.contrast_fg(), Color::BrightWhite);
+    assert_eq!(Color::Grayscale(20).contrast_fg(), Color::Black);
+    assert_eq!(Color::Default.contrast_fg(), Color::Default);
+}
+
+#[test]
+fn lighten_darken_mix() {
+    assert_eq!(Color::Red.lighten(0.0), Color::RGB { r: 205, g: 0, b: 0 });
+    assert_eq!(Color::Colors256(21).darken(1.0), Color::RGB { r: 0, g: 0, b: 0 });
+    assert_eq!(Color::Black.lighten(2.0), Color::RGB { r: 255, g: 255, b: 255 });
+    assert_eq!(Color::Default.darken(0.5), Color::Default);
+    assert_eq!(Color::Blue.mix(&Color::Default, 0.5), Color::Blue);
+    assert_eq!(Color::Black.mix(&Color::Grayscale(23), 0.5), Color::RGB { r: 119, g: 119, b: 119 });
+}
+
+#[test]
+fn prefix_and_suffix() {
+    let _color = common::colors_on();
+    let formatting = Formatting::new().foreground(Color::Red).bold().build();
+    assert_eq!(formatting.prefix(), "\x1B[31;1m");
+    assert_eq!(formatting.suffix(), "\x1B[0m");
+    assert_eq!(Formatting::new().prefix().as_str(), "\x1B[m");
+
+    let targeted = Formatting::new().background(Color::Blue).italic().reset_mode(ResetMode::Targeted).build();
+    assert_eq!(targeted.suffix(), "\x1B[49;23m");
+
+    // The longest possible prefix still fits.
+    let everything = Formatting::new()
+        .foreground(Color::RGB { r: 255, g: 255, b: 255 })
+        .background(Color::RGB { r: 255, g: 255, b: 255 })
+        .styles([
+            Styles::Reset, Styles::Bold, Styles::Faint, Styles::Italic, Styles::Underline,
+            Styles::Blink, Styles::Invert, Styles::Invisible, Styles::Strikethrough,
+            Styles::DoubleUnderline, Styles::CurlyUnderline, Styles::DottedUnderline,
+            Styles::DashedUnderline, Styles::RapidBlink, Styles::Overline, Styles::Frame,
+            Styles::Encircle, Styles::DefaultFont, Styles::AltFont1, Styles::AltFont2,
+            Styles::AltFont3, Styles::AltFont4, Styles::AltFont5, Styles::AltFont6,
+            Styles::AltFont7, Styles::AltFont8, Styles::AltFont9,
+        ])
+        .build();
+    assert_eq!(everything.prefix().as_str(), everything.apply_to("").strip_suffix("\x1B[0m").unwrap());
+}
+
+#[test]
+fn build_caches_prefix() {
+    let _color = common::colors_on();
+    let mut built = Formatting::new().foreground(Color::Red).bold().build();
+    assert_eq!(built.apply_to("text"), "\x1B[31;1mtext\x1B[0m");
+    assert_eq!(built, Formatting::new().foreground(Color::Red).bold().clone());
+
+    // Changing a built `Formatting` doesn't leave the old codes behind.
+    built.background(Color::Blue).compat(true);
+    assert_eq!(built.apply_to("text"), "\x1B[31;44;1mtext\x1B[0m");
+    assert_eq!(built.prefix(), "\x1B[31;44;1m");
+}
+
+#[test]
+fn padding_ignores_escapes() {
+    let _color = common::colors_on();
+    let red = Formatting::new().foreground(Color::Red).build();
+    assert_eq!(format!("{:>6}|", red.paint("HI")), "\x1B[31m    HI\x1B[0m|");
+    assert_eq!(format!("{:*^6}|", red.paint("HI")), "\x1B[31m**HI**\x1B[0m|");
+    assert_eq!(format!("{:.1}|", red.paint("HI")), "\x1B[31mH\x1B[0m|");
+    assert_eq!(format!("{:4}|", 7.blue()), "\x1B[34m   7\x1B[0m|");
+    assert_eq!(format!("{:4}|", "7".blue()), "\x1B[34m7   \x1B[0m|");
+    assert_eq!(format!("{:<6}|", "HI".bold().on_blue()), "\x1B[44;1mHI    \x1B[0m|");
+}
@@ -0,0 +1,32 @@
+use coloring::*;
+use coloring::iter::StyleWith;
+
+mod common;
+
+fn by_level(line: &str) -> Formatting {
+    match line.split_whitespace().next() {
+        Some("ERROR") => Formatting::new().foreground(Color::Red).build(),
+        Some("WARN") => Formatting::new().foreground(Color::Yellow).build(),
+        _ => Formatting::new(),
+    }
+}
+
+#[test]
+fn styles_each_line() {
+    let _color = common::colors_on();
+    let lines: Vec<String> = ["ERROR a", "ok", "WARN b"].iter().style_with(by_level).collect();
+    assert_eq!(lines, ["\x1B[31mERROR a\x1B[0m", "ok", "\x1B[33mWARN b\x1B[0m"]);
+}
+
+#[test]
+fn lazy_and_sized() {
+    let mut calls = 0;
+    let mut lines = vec![String::from("ERROR a"), String::from("b")].into_iter().style_with(|line| {
+        calls += 1;
+        by_level(line)
+    });
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines.next_back().as_deref(), Some("b"));
+    drop(lines);
+    assert_eq!(calls, 1);
+}
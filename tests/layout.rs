@@ -0,0 +1,48 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn grid_fills_columns_first() {
+    let cells = ["one", "two", "three", "four", "five", "six", "seven"];
+    assert_eq!(layout::grid_with_width(cells, 20), "one    four  seven\ntwo    five\nthree  six\n");
+    assert_eq!(layout::grid_with_width(cells, 80), "one  two  three  four  five  six  seven\n");
+}
+
+#[test]
+fn grid_narrow_and_empty() {
+    assert_eq!(layout::grid_with_width(["toolong", "x"], 3), "toolong\nx\n");
+    assert_eq!(layout::grid_with_width(Vec::<String>::new(), 80), "");
+}
+
+#[test]
+fn grid_ignores_escape_codes() {
+    let _color = common::colors_on();
+    let red = Formatting::new().foreground(Color::Red).build();
+    let cells = [red.apply_to("abcd"), "e".to_string(), "f".to_string()];
+    assert_eq!(
+        layout::grid_with_width(&cells, 9),
+        "\x1B[31mabcd\x1B[0m  f\ne\n"
+    );
+}
+
+#[test]
+fn side_by_side_wraps_each_side() {
+    let _color = common::colors_on();
+    let left = format!("{} two three", "one".green());
+    let right = "x";
+    assert_eq!(
+        layout::side_by_side(&left, right, 19),
+        "\x1B[32mone\x1B[0m two  │ x\nthree    │\n"
+    );
+}
+
+#[test]
+fn side_by_side_carries_styles_over() {
+    let _color = common::colors_on();
+    let left = format!("{}", "aaa bbb".red());
+    assert_eq!(
+        layout::side_by_side(&left, "c\nd\ne", 11),
+        "\x1B[31maaa\x1B[0m  │ c\n\x1B[31mbbb\x1B[0m  │ d\n     │ e\n"
+    );
+}
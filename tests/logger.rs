@@ -0,0 +1,33 @@
+#![cfg(feature = "logger")]
+
+use coloring::logger::{format_record, Logger};
+use coloring::*;
+use log::{Level, LevelFilter, Log};
+
+mod common;
+
+fn record_line(level: Level, theme: &Theme) -> String {
+    format_record(&log::Record::builder().level(level).target("app").args(format_args!("msg")).build(), theme)
+}
+
+#[test]
+fn levels() {
+    let _color = common::colors_on();
+    let theme = Theme::default();
+    assert_eq!(record_line(Level::Warn, &theme), "\x1B[33;1mWARN \x1B[0m \x1B[90mapp\x1B[0m: msg");
+    assert_eq!(record_line(Level::Info, &theme), "\x1B[36mINFO \x1B[0m \x1B[90mapp\x1B[0m: msg");
+    assert_eq!(record_line(Level::Trace, &theme), "\x1B[90mTRACE\x1B[0m \x1B[90mapp\x1B[0m: msg");
+}
+
+#[test]
+fn missing_names_stay_plain() {
+    assert_eq!(record_line(Level::Debug, &Theme::new()), "DEBUG app: msg");
+}
+
+#[test]
+fn max_level() {
+    let mut logger = Logger::new();
+    logger.max_level(LevelFilter::Warn);
+    assert!(logger.enabled(&log::Metadata::builder().level(Level::Error).build()));
+    assert!(!logger.enabled(&log::Metadata::builder().level(Level::Info).build()));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "markdown")]
+
+use coloring::*;
+
+mod common;
+
+fn theme() -> Theme {
+    let mut theme = Theme::new();
+    theme
+        .set("heading", Formatting::new().foreground(Color::Blue).build())
+        .set("heading2", Formatting::new().foreground(Color::Green).build())
+        .set("strong", Formatting::new().foreground(Color::Red).build())
+        .set("emphasis", Formatting::new().styles([Styles::Italic]).build())
+        .set("code", Formatting::new().foreground(Color::Yellow).build())
+        .set("bullet", Formatting::new().foreground(Color::Cyan).build());
+    theme
+}
+
+#[test]
+fn headings() {
+    let _color = common::colors_on();
+    let theme = theme();
+    assert_eq!(markdown::render_with("# Title #", &theme), "\x1B[34mTitle\x1B[0m");
+    assert_eq!(markdown::render_with("## Part", &theme), "\x1B[32mPart\x1B[0m");
+    assert_eq!(markdown::render_with("### C#", &theme), "\x1B[34mC#\x1B[0m");
+    assert_eq!(
+        markdown::render_with("# Use **this**", &theme),
+        "\x1B[34mUse \x1B[0m\x1B[31mthis\x1B[0m"
+    );
+    assert_eq!(markdown::render_with("#hashtag", &theme), "#hashtag");
+    assert_eq!(markdown::render_with("####### seven", &theme), "####### seven");
+}
+
+#[test]
+fn emphasis() {
+    let _color = common::colors_on();
+    let theme = theme();
+    assert_eq!(markdown::render_with("*a* _b_", &theme), "\x1B[3ma\x1B[0m \x1B[3mb\x1B[0m");
+    assert_eq!(markdown::render_with("__a__", &theme), "\x1B[31ma\x1B[0m");
+    assert_eq!(
+        markdown::render_with("*a **b** c*", &theme),
+        "\x1B[3ma \x1B[0m\x1B[31;3mb\x1B[0m\x1B[3m c\x1B[0m"
+    );
+    assert_eq!(markdown::render_with("snake_case_name", &theme), "snake_case_name");
+    assert_eq!(markdown::render_with("2 * 3 * 4", &theme), "2 * 3 * 4");
+    assert_eq!(markdown::render_with("**unclosed", &theme), "**unclosed");
+    assert_eq!(markdown::render_with("\\*not\\*", &theme), "*not*");
+}
+
+#[test]
+fn code() {
+    let _color = common::colors_on();
+    let theme = theme();
+    assert_eq!(markdown::render_with("`**x**`", &theme), "\x1B[33m**x**\x1B[0m");
+    assert_eq!(markdown::render_with("`` a`b ``", &theme), "\x1B[33ma`b\x1B[0m");
+    assert_eq!(markdown::render_with("`open", &theme), "`open");
+    assert_eq!(
+        markdown::render_with("before\n```rust\nlet *x* = 1;\n```\nafter\n", &theme),
+        "before\n\x1B[33mlet *x* = 1;\x1B[0m\nafter\n"
+    );
+    assert_eq!(markdown::render_with("~~~\n```\n~~~~\n", &theme), "\x1B[33m```\x1B[0m\n");
+}
+
+#[test]
+fn lists() {
+    let _color = common::colors_on();
+    let theme = theme();
+    assert_eq!(
+        markdown::render_with("- one\n  * *two*\n", &theme),
+        "\x1B[36m•\x1B[0m one\n  \x1B[36m•\x1B[0m \x1B[3mtwo\x1B[0m\n"
+    );
+    assert_eq!(markdown::render_with("10. ten", &theme), "\x1B[36m10.\x1B[0m ten");
+    assert_eq!(markdown::render_with("-1 and 2020.", &theme), "-1 and 2020.");
+}
+
+#[test]
+fn defaults() {
+    let _color = common::colors_on();
+    let text = markdown::render("# Hi\r\n*there*");
+    assert_eq!(text, "\x1B[1;4mHi\x1B[0m\n\x1B[3mthere\x1B[0m");
+}
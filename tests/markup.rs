@@ -0,0 +1,53 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn angle_and_curly_tags() {
+    let _color = common::colors_on();
+    assert_eq!(markup::render("<red>a</red> b"), "\x1B[31ma\x1B[0m b");
+    assert_eq!(markup::render("<256:1>a</> <#aadddd>b</>"), "\x1B[38;5;1ma\x1B[0m \x1B[38;2;170;221;221mb\x1B[0m");
+    assert_eq!(markup::render("{red}a{/red} b"), "\x1B[31ma\x1B[0m b");
+}
+
+#[test]
+fn nesting_layers_formatting() {
+    let _color = common::colors_on();
+    assert_eq!(
+        markup::render("<red>a<bold on white>b</bold on white>c</red>"),
+        "\x1B[31ma\x1B[0m\x1B[31;47;1mb\x1B[0m\x1B[31mc\x1B[0m"
+    );
+}
+
+#[test]
+fn closing_outer_tag_closes_inner() {
+    let _color = common::colors_on();
+    assert_eq!(markup::render("<red><bold>a</red>b"), "\x1B[31;1ma\x1B[0mb");
+    assert_eq!(markup::render("{red}{bold}a{/}b{/}c"), "\x1B[31;1ma\x1B[0m\x1B[31mb\x1B[0mc");
+}
+
+#[test]
+fn unknown_tags_are_text() {
+    assert_eq!(markup::render("<div>{}</div> 1 < 2"), "<div>{}</div> 1 < 2");
+    assert_eq!(markup::render("row {1} of {12}: press <add>"), "row {1} of {12}: press <add>");
+    assert_eq!(markup::strip("<bad red>x</bad red>"), "<bad red>x</bad red>");
+    assert_eq!(markup::render("</red>a"), "</red>a");
+}
+
+#[test]
+fn escaped_tags() {
+    assert_eq!(markup::render("\\<red>a\\{/red}"), "<red>a{/red}");
+}
+
+#[test]
+fn theme_names() {
+    let _color = common::colors_on();
+    let mut theme = Theme::new();
+    theme.set("warn", Formatting::new().foreground(Color::Yellow).build());
+    assert_eq!(markup::render_with("<warn>a</warn>", &theme), "\x1B[33ma\x1B[0m");
+}
+
+#[test]
+fn strip() {
+    assert_eq!(markup::strip("<red>a</red> {bold}b{/}"), "a b");
+}
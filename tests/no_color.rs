@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+
+use coloring::*;
+
+// Everything lives in one test since the override and environment are process-wide.
+#[test]
+fn no_color() {
+    // These would beat `NO_COLOR` if they were set.
+    std::env::remove_var("FORCE_COLOR");
+    std::env::remove_var("CLICOLOR_FORCE");
+    std::env::set_var("NO_COLOR", "1");
+    let mut formatting = Formatting::new();
+    formatting.foreground(Color::Red);
+    assert_eq!(formatting.apply_to("text"), "text");
+    assert_eq!(formatting.paint("text").to_string(), "text");
+
+    set_override(true);
+    assert_eq!(formatting.apply_to("text"), "\x1B[31mtext\x1B[0m");
+
+    set_override(false);
+    assert_eq!(formatting.apply_to("text"), "text");
+
+    unset_override();
+    assert_eq!(formatting.apply_to("text"), "text");
+
+    set_color_choice(ColorChoice::Always);
+    assert_eq!(color_choice(), ColorChoice::Always);
+    assert_eq!(formatting.paint_for(Stream::Stderr, "text").to_string(), "\x1B[31mtext\x1B[0m");
+    assert!(colors_enabled_for(Stream::Stdout));
+
+    set_color_choice(ColorChoice::Never);
+    assert_eq!(formatting.paint_for(Stream::Stderr, "text").to_string(), "text");
+    assert_eq!("text".red().to_string(), "text");
+
+    // NO_COLOR wins regardless of whether the stream is a terminal.
+    set_color_choice(ColorChoice::Auto);
+    assert!(!colors_enabled_for(Stream::Stdout));
+    assert!(!colors_enabled_for(Stream::Stderr));
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "std")]
+
+use coloring::*;
+
+#[test]
+fn nested_scopes() {
+    let red = Formatting::new().foreground(Color::Red).build();
+    let _always = override_scope(ColorChoice::Always);
+    {
+        let _never = override_scope(ColorChoice::Never);
+        assert_eq!(red.apply_to("a"), "a");
+        {
+            let _always = override_scope(ColorChoice::Always);
+            assert_eq!(red.apply_to("a"), "\x1B[31ma\x1B[0m");
+            assert!(colors_enabled_for(Stream::Stdout));
+        }
+        assert_eq!(red.apply_to("a"), "a");
+        assert!(!colors_enabled_for(Stream::Stderr));
+    }
+    // Back to the outer scope.
+    assert_eq!(red.apply_to("a"), "\x1B[31ma\x1B[0m");
+}
+
+#[test]
+fn other_threads_unaffected() {
+    // What the global choice gives, whatever the environment has to say about it.
+    let global = {
+        let _auto = override_scope(ColorChoice::Auto);
+        "a".red().to_string()
+    };
+    let _never = override_scope(ColorChoice::Never);
+    assert_eq!("a".red().to_string(), "a");
+    let other = std::thread::spawn(|| "a".red().to_string()).join().unwrap();
+    assert_eq!(other, global);
+}
+
+#[test]
+fn auto_follows_stdout() {
+    let _auto = override_scope(ColorChoice::Auto);
+    let expected = if colors_enabled_for(Stream::Stdout) { "\x1B[31ma\x1B[0m" } else { "a" };
+    assert_eq!("a".red().to_string(), expected);
+    assert_eq!(Formatting::new().foreground(Color::Red).apply_to("a"), expected);
+}
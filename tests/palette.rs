@@ -0,0 +1,111 @@
+use coloring::*;
+use coloring::palette::web;
+
+mod common;
+
+#[test]
+fn web_constants() {
+    assert!(matches!(web::TOMATO, Color::RGB { r: 255, g: 99, b: 71 }));
+    assert!(matches!(web::REBECCAPURPLE, Color::RGB { r: 102, g: 51, b: 153 }));
+}
+
+#[test]
+fn web_from_name() {
+    assert!(matches!(web::from_name("LightGoldenrodYellow"), Some(Color::RGB { r: 250, g: 250, b: 210 })));
+    assert!(matches!(web::from_name("grey"), Some(Color::RGB { r: 128, g: 128, b: 128 })));
+    assert!(web::from_name("bright_red").is_none());
+}
+
+#[test]
+fn web_names_parse() {
+    assert!(matches!("cornflowerblue".parse(), Ok(Color::RGB { r: 100, g: 149, b: 237 })));
+    // The basic colors take priority.
+    assert!(matches!("red".parse(), Ok(Color::Red)));
+    assert!(matches!("beige".parse(), Ok(Color::RGB { r: 245, g: 245, b: 220 })));
+    for name in ["darkorange", "dark_orange", "dark-orange", "Dark Orange"] {
+        assert!(matches!(name.parse(), Ok(Color::RGB { r: 255, g: 140, b: 0 })), "{}", name);
+    }
+}
+
+#[test]
+fn theme_palettes() {
+    let _color = common::colors_on();
+    use coloring::palette::{dracula, gruvbox, nord, solarized};
+
+    assert!(matches!(dracula::PURPLE, Color::RGB { r: 189, g: 147, b: 249 }));
+    assert!(matches!(solarized::BASE03, Color::RGB { r: 0, g: 43, b: 54 }));
+    assert!(matches!(nord::NORD11, Color::RGB { r: 191, g: 97, b: 106 }));
+    assert!(matches!(gruvbox::BRIGHT_ORANGE, Color::RGB { r: 254, g: 128, b: 25 }));
+    assert_eq!(Formatting::new().foreground(nord::NORD8).apply_to("x"), "\x1B[38;2;136;192;208mx\x1B[0m");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn simulate() {
+    use coloring::palette::{self, ColorBlindness};
+
+    let distance = |a: Color, b: Color| match (a, b) {
+        (Color::RGB { r, g, b }, Color::RGB { r: r2, g: g2, b: b2 }) => {
+            [(r, r2), (g, g2), (b, b2)].iter().map(|&(x, y)| (x as i32 - y as i32).pow(2)).sum::<i32>()
+        }
+        _ => panic!("not RGB"),
+    };
+    let red = palette::simulate(ColorBlindness::Deuteranopia, Color::BrightRed);
+    let green = palette::simulate(ColorBlindness::Deuteranopia, Color::Green);
+    let vermillion = palette::simulate(ColorBlindness::Deuteranopia, palette::okabe_ito::VERMILLION);
+    let bluish_green = palette::simulate(ColorBlindness::Deuteranopia, palette::okabe_ito::BLUISH_GREEN);
+    assert!(distance(red, green) * 3 < distance(vermillion, bluish_green));
+
+    // Grays look the same to everyone.
+    let gray = Color::RGB { r: 100, g: 100, b: 100 };
+    for blindness in [ColorBlindness::Protanopia, ColorBlindness::Tritanopia, ColorBlindness::Achromatopsia] {
+        assert!(distance(palette::simulate(blindness, gray), gray) < 30);
+    }
+    assert_eq!(palette::simulate(ColorBlindness::Protanopia, Color::Default), Color::Default);
+}
+
+#[test]
+fn make_colorblind_safe() {
+    use coloring::palette::okabe_ito;
+
+    let mut theme = Theme::new();
+    theme
+        .set("red", Formatting::new().foreground(Color::Red).background(Color::BrightBlack).build())
+        .set("green", Formatting::new().foreground(Color::Green).build())
+        .set("yellow", Formatting::new().foreground(Color::Yellow).build())
+        .set("blue", Formatting::new().foreground(Color::Blue).build())
+        .set("magenta", Formatting::new().background(Color::Magenta).build())
+        .set("cyan", Formatting::new().foreground(Color::Cyan).build())
+        .set("plain", Formatting::new().foreground(Color::White).styles([Styles::Bold]).build());
+    theme.make_colorblind_safe();
+
+    let fg = |color| Some(Formatting::new().foreground(color).build());
+    assert_eq!(
+        theme.get("red").cloned(),
+        Some(Formatting::new().foreground(okabe_ito::VERMILLION).background(Color::BrightBlack).build())
+    );
+    assert_eq!(theme.get("green").cloned(), fg(okabe_ito::BLUISH_GREEN));
+    assert_eq!(theme.get("yellow").cloned(), fg(okabe_ito::ORANGE));
+    assert_eq!(theme.get("blue").cloned(), fg(okabe_ito::BLUE));
+    assert_eq!(theme.get("magenta").cloned(), Some(Formatting::new().background(okabe_ito::REDDISH_PURPLE).build()));
+    assert_eq!(theme.get("cyan").cloned(), fg(okabe_ito::SKY_BLUE));
+    assert_eq!(theme.get("plain").cloned(), Some(Formatting::new().foreground(Color::White).styles([Styles::Bold]).build()));
+}
+
+#[test]
+fn make_colorblind_safe_bright_colors() {
+    use coloring::palette::okabe_ito;
+
+    let replacement = |color| {
+        let mut theme = Theme::new();
+        theme.set("a", Formatting::new().foreground(color).build()).make_colorblind_safe();
+        theme.get("a").cloned()
+    };
+    let fg = |color| Some(Formatting::new().foreground(color).build());
+    assert_eq!(replacement(Color::BrightRed), fg(okabe_ito::VERMILLION));
+    assert_eq!(replacement(Color::BrightGreen), fg(okabe_ito::BLUISH_GREEN));
+    assert_eq!(replacement(Color::BrightYellow), fg(okabe_ito::YELLOW));
+    assert_eq!(replacement(Color::BrightBlue), fg(okabe_ito::SKY_BLUE));
+    assert_eq!(replacement(Color::BrightMagenta), fg(okabe_ito::REDDISH_PURPLE));
+    assert_eq!(replacement(Color::BrightCyan), fg(okabe_ito::SKY_BLUE));
+}
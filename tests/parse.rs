@@ -0,0 +1,72 @@
+use coloring::*;
+use coloring::parse::spans;
+
+mod common;
+
+fn render(text: &str) -> Vec<String> {
+    spans(text).into_iter().map(|(formatting, text)| formatting.apply_to(text)).collect()
+}
+
+#[test]
+fn plain() {
+    let _color = common::colors_on();
+    assert_eq!(render("text"), vec!["\x1B[mtext\x1B[0m"]);
+}
+
+#[test]
+fn round_trip() {
+    let mut formatting = Formatting::new();
+    formatting.foreground(Color::RGB { r: 1, g: 2, b: 3 }).background(Color::Colors256(200)).styles(vec![Styles::Bold, Styles::CurlyUnderline]);
+    let formatted = formatting.apply_to("text");
+    let spans = spans(&formatted);
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].1, "text");
+    assert_eq!(spans[0].0.apply_to("text"), formatted);
+}
+
+#[test]
+fn basic_and_bright() {
+    let _color = common::colors_on();
+    assert_eq!(render("\x1B[31;104ma\x1B[97;40mb"), vec!["\x1B[31;104ma\x1B[0m", "\x1B[97;40mb\x1B[0m"]);
+}
+
+#[test]
+fn state_carries_over() {
+    let _color = common::colors_on();
+    assert_eq!(
+        render("\x1B[1ma\x1B[31mb\x1B[22mc\x1B[39md\x1B[0me"),
+        vec!["\x1B[1ma\x1B[0m", "\x1B[31;1mb\x1B[0m", "\x1B[31mc\x1B[0m", "\x1B[md\x1B[0m", "\x1B[me\x1B[0m"]
+    );
+}
+
+#[test]
+fn colon_forms() {
+    let _color = common::colors_on();
+    assert_eq!(render("\x1B[38:2::1:2:3;48:5:9;4:3ma"), vec!["\x1B[38;2;1;2;3;48;5;9;4:3ma\x1B[0m"]);
+    assert_eq!(render("\x1B[4:3m\x1B[4:0ma"), vec!["\x1B[ma\x1B[0m"]);
+}
+
+#[test]
+fn underline_color_is_skipped() {
+    let _color = common::colors_on();
+    assert_eq!(render("\x1B[58;5;196;31ma"), vec!["\x1B[31ma\x1B[0m"]);
+    assert_eq!(render("\x1B[58;2;1;2;3;4ma\x1B[59mb"), vec!["\x1B[4ma\x1B[0m", "\x1B[4mb\x1B[0m"]);
+    assert_eq!(render("\x1B[58:5:5ma"), vec!["\x1B[ma\x1B[0m"]);
+}
+
+#[test]
+fn skips_other_escapes() {
+    let spans = spans("\x1B[2Ka\x1B[1Ab");
+    assert_eq!(spans.iter().map(|(_, text)| *text).collect::<Vec<_>>(), vec!["a", "b"]);
+}
+
+#[test]
+fn extra_styles_and_fonts() {
+    let _color = common::colors_on();
+    assert_eq!(render("\x1B[6;53;51;12ma\x1B[55;54;14mb\x1B[10mc"), vec![
+        "\x1B[6;53;51;12ma\x1B[0m",
+        "\x1B[6;14mb\x1B[0m",
+        "\x1B[6mc\x1B[0m",
+    ]);
+    assert_eq!(render("\x1B[5;6ma\x1B[25mb"), vec!["\x1B[5;6ma\x1B[0m", "\x1B[mb\x1B[0m"]);
+}
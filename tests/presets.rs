@@ -0,0 +1,26 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn presets_match_default_theme() {
+    let theme = Theme::default();
+    for (name, preset) in [
+        ("error", presets::ERROR),
+        ("warning", presets::WARNING),
+        ("success", presets::SUCCESS),
+        ("info", presets::INFO),
+    ] {
+        assert_eq!(theme.get(name).unwrap().apply_to("HI"), preset.apply_to("HI"), "{}", name);
+    }
+}
+
+#[test]
+fn preset_functions() {
+    let _color = common::colors_on();
+    assert_eq!(presets::error("HI").to_string(), "\x1B[31;1mHI\x1B[0m");
+    assert_eq!(presets::warn("HI").to_string(), "\x1B[33;1mHI\x1B[0m");
+    assert_eq!(presets::success("HI").to_string(), "\x1B[32mHI\x1B[0m");
+    assert_eq!(presets::info("HI").to_string(), "\x1B[36mHI\x1B[0m");
+    assert_eq!(format!("[{:^6}]", presets::success("ok")), "[\x1B[32m  ok  \x1B[0m]");
+}
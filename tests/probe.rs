@@ -0,0 +1,51 @@
+#![cfg(feature = "probe")]
+
+use coloring::detect::ColorDepth;
+use coloring::probe::{self, Report, BACKGROUND_QUERY, QUERY};
+use coloring::Color;
+
+#[test]
+fn query_ends_with_da1() {
+    assert!(QUERY.ends_with("\x1B[c"));
+    assert!(QUERY.contains("\x1B[0m"));
+}
+
+#[test]
+fn parse_truecolor() {
+    let report = Report::parse(b"\x1BP1$r38;2;1;2;3m\x1B\\\x1B[?64;1;2;4;22c").unwrap();
+    assert!(report.truecolor);
+    assert!(!report.styled_underlines);
+    assert_eq!(report.device_attributes, [64, 1, 2, 4, 22]);
+    assert_eq!(report.depth(), Some(ColorDepth::TrueColor));
+}
+
+#[test]
+fn parse_capabilities() {
+    let report = Report::parse(b"\x1BP1+r524742=38;5;255;536D756C78=1\x1B\\\x1B[?62c").unwrap();
+    assert!(report.truecolor);
+    assert!(report.styled_underlines);
+
+    // A palette color back, and capabilities it doesn't know.
+    let report = Report::parse(b"\x1BP1$r0;38;5;16m\x1B\\\x1BP0+r524742\x1B\\\x1B[?1;2c").unwrap();
+    assert_eq!(report, Report { device_attributes: vec![1, 2], ..Report::default() });
+    assert_eq!(report.depth(), None);
+}
+
+#[test]
+fn parse_needs_da1() {
+    assert_eq!(Report::parse(b""), None);
+    assert_eq!(Report::parse(b"\x1BP1$r38;2;1;2;3m\x1B\\"), None);
+    assert_eq!(Report::parse(b"\x1B[?62;22"), None);
+}
+
+#[test]
+fn parse_background() {
+    assert!(BACKGROUND_QUERY.starts_with("\x1B]11;?") && BACKGROUND_QUERY.ends_with("\x1B[c"));
+    let parse = probe::parse_background;
+    assert_eq!(parse(b"\x1B]11;rgb:ffff/ffff/ffff\x1B\\\x1B[?1;2c"), Some(Color::RGB { r: 255, g: 255, b: 255 }));
+    assert_eq!(parse(b"\x1B]11;rgb:0/8/f\x07"), Some(Color::RGB { r: 0, g: 136, b: 255 }));
+    assert_eq!(parse(b"\x1B]11;rgb:00/80/ff\x07"), Some(Color::RGB { r: 0, g: 128, b: 255 }));
+    assert_eq!(parse(b"\x1B]11;rgb:00/80\x07"), None);
+    assert_eq!(parse(b"\x1B]11;rgb:00/80/zz\x07"), None);
+    assert_eq!(parse(b"\x1B]11;rgb:12345/0/0\x07"), None);
+}
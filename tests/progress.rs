@@ -0,0 +1,60 @@
+use coloring::progress::ProgressBar;
+use coloring::*;
+
+mod common;
+
+#[test]
+fn colors_change_with_progress() {
+    let _color = common::colors_on();
+    let bar = ProgressBar::new(6);
+    assert_eq!(bar.render(0.0), "░░░░░░   0%");
+    assert_eq!(bar.render(0.2), "\x1B[31m█\x1B[0m░░░░░  20%");
+    assert_eq!(bar.render(0.5), "\x1B[33m███\x1B[0m░░░  50%");
+    assert_eq!(bar.render(1.0), "\x1B[32m██████\x1B[0m 100%");
+}
+
+#[test]
+fn out_of_range() {
+    let mut bar = ProgressBar::new(2);
+    bar.percentage(false);
+    assert_eq!(strip(&bar.render(-1.0)), "░░");
+    assert_eq!(strip(&bar.render(f32::NAN)), "░░");
+    assert_eq!(strip(&bar.render(7.0)), "██");
+}
+
+#[test]
+fn ratio() {
+    let mut bar = ProgressBar::new(4);
+    bar.fill('#').empty('-').stages([]);
+    assert_eq!(bar.render_ratio(1, 4), "#---  25%");
+    assert_eq!(bar.render_ratio(9, 4), "#### 100%");
+    assert_eq!(bar.render_ratio(0, 0), "#### 100%");
+}
+
+#[test]
+fn carriage_return() {
+    let mut bar = ProgressBar::new(3);
+    bar.fill('=').empty(' ').percentage(false).carriage_return(true);
+    assert_eq!(strip(&bar.render(0.7)), "\r== ");
+}
+
+#[test]
+fn spinner_frames() {
+    let _color = common::colors_on();
+    let spinner = progress::Spinner::line();
+    assert_eq!(spinner.len(), 4);
+    assert_eq!(spinner.frame(0), "\x1B[36m-\x1B[0m");
+    assert_eq!(strip(&spinner.frame(1)), "\\");
+    assert_eq!(spinner.frame(6), spinner.frame(2));
+    assert_eq!(progress::Spinner::dots().len(), 10);
+    assert_eq!(strip(&progress::Spinner::arc().frame(11)), "◟");
+}
+
+#[test]
+fn spinner_formatting() {
+    let _color = common::colors_on();
+    let mut spinner = progress::Spinner::new(&["a", "b"]);
+    spinner.formatting(Formatting::new().bold().build());
+    assert_eq!(spinner.frame(3), "\x1B[1mb\x1B[0m");
+    assert_eq!(progress::Spinner::new(&[]).frame(3), "");
+}
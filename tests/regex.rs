@@ -0,0 +1,65 @@
+#![cfg(feature = "regex")]
+
+use coloring::*;
+use regex::Regex;
+
+mod common;
+
+#[test]
+fn highlight_regex_matches() {
+    let _color = common::colors_on();
+    let found = Formatting::new().foreground(Color::Green).build();
+    let regex = Regex::new(r"[a-z]+\d").unwrap();
+    assert_eq!(highlight_regex("ab1 2 c3", &regex, &found), "\x1B[32mab1\x1B[0m 2 \x1B[32mc3\x1B[0m");
+    assert_eq!(highlight_regex(&"ab1".bold().to_string(), &regex, &found), "\x1B[1m\x1B[32mab1\x1B[0m\x1B[1m\x1B[0m");
+}
+
+#[test]
+fn highlight_regex_skips_empty_matches() {
+    let found = Formatting::new().foreground(Color::Green).build();
+    assert_eq!(highlight_regex("abc", &Regex::new("x*").unwrap(), &found), "abc");
+}
+
+#[test]
+fn rules_in_order() {
+    let _color = common::colors_on();
+    let mut rules = rules::Rules::new();
+    rules
+        .add("b+", Formatting::new().foreground(Color::Red).build())
+        .unwrap()
+        .add("a.*c", Formatting::new().foreground(Color::Blue).build())
+        .unwrap();
+    assert_eq!(strip(&rules.colorize("abbc d")), "abbc d");
+    assert_eq!(rules.colorize("abbc d"), "\x1B[34ma\x1B[31mbb\x1B[0m\x1B[34mc\x1B[0m d");
+    assert_eq!(rules.colorize("nothing"), "nothing");
+    assert!(rules::Rules::new().add("(", Formatting::new()).is_err());
+}
+
+#[test]
+fn colorize_reader() {
+    let _color = common::colors_on();
+    let mut rules = rules::Rules::new();
+    rules.add("ok", Formatting::new().foreground(Color::Green).build()).unwrap();
+    let input: &[u8] = b"ok\r\nnot ok\n\xFFok";
+    let mut output = Vec::new();
+    rules.colorize_reader(input, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "\x1B[32mok\x1B[0m\r\nnot \x1B[32mok\x1B[0m\n\u{FFFD}\x1B[32mok\x1B[0m"
+    );
+}
+
+#[test]
+fn process_long_lines() {
+    let _color = common::colors_on();
+    let mut rules = rules::Rules::new();
+    rules.add("é+", Formatting::new().foreground(Color::Green).build()).unwrap();
+    // Long enough to be split into pieces, with a two-byte character right at the first cut.
+    let input = format!("{}é{}\nnext é\n", "a".repeat(64 * 1024 - 1), "b".repeat(70 * 1024));
+    let mut output = Vec::new();
+    stream::process(input.as_bytes(), &mut output, &rules).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(strip(&output), input);
+    assert!(output.ends_with("\nnext \x1B[32mé\x1B[0m\n"));
+    assert_eq!(output.matches("\x1B[32mé\x1B[0m").count(), 2);
+}
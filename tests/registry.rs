@@ -0,0 +1,32 @@
+#![cfg(feature = "std")]
+
+use coloring::*;
+
+mod common;
+
+// The registry is global, so everything touching it is in one test.
+#[test]
+fn registered_names() {
+    let _color = common::colors_on();
+    let heading = Formatting::new().foreground(Color::Blue).styles([Styles::Bold]).build();
+    theme::register("heading", heading.clone());
+    assert_eq!(theme::registered("heading"), Some(heading));
+    assert_eq!(markup::render("<heading>a</heading> b"), "\x1B[34;1ma\x1B[0m b");
+    assert_eq!(markup::strip("{heading}a{/heading}"), "a");
+    assert_eq!(cformat!("{:heading} {}", "a", "b"), "\x1B[34;1ma\x1B[0m b");
+
+    // Registered names win over colors with the same name.
+    theme::register("red", Formatting::new().foreground(Color::Green).build());
+    assert_eq!(markup::render("<red>a</red>"), "\x1B[32ma\x1B[0m");
+
+    // render_with uses its own theme instead.
+    assert_eq!(markup::render_with("<heading>a</heading>", &Theme::new()), "<heading>a</heading>");
+
+    let previous = theme::set_registry(Theme::default());
+    assert!(previous.contains("heading"));
+    assert_eq!(markup::render("<red>a</red>"), "\x1B[31ma\x1B[0m");
+    assert_eq!(markup::render("<error>a</error>"), "\x1B[31;1ma\x1B[0m");
+    assert!(theme::unregister("error").is_some());
+    assert_eq!(theme::registered("error"), None);
+    assert_eq!(theme::registry().names().count(), 5);
+}
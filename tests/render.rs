@@ -0,0 +1,73 @@
+use coloring::*;
+use coloring::render;
+
+mod common;
+
+#[test]
+fn html_plain() {
+    assert_eq!(render::html("a < b & \"c\""), "a &lt; b &amp; &quot;c&quot;");
+}
+
+#[test]
+fn html_spans() {
+    let _color = common::colors_on();
+    let text = format!("{} and {}", "red".red(), "blue".on_color(Color::Colors256(21)).italic());
+    assert_eq!(
+        render::html(&text),
+        "<span style=\"color:#cd0000\">red</span> and <span style=\"background-color:#0000ff;font-style:italic\">blue</span>"
+    );
+}
+
+#[test]
+fn html_decorations() {
+    let html = Formatting::new().styles(vec![Styles::CurlyUnderline, Styles::Strikethrough]).apply_to_html("text");
+    assert_eq!(html, "<span style=\"text-decoration:line-through underline;text-decoration-style:wavy\">text</span>");
+}
+
+#[test]
+fn html_invert() {
+    let html = Formatting::new().foreground(Color::Black).background(Color::White).styles(vec![Styles::Invert]).apply_to_html("text");
+    assert_eq!(html, "<span style=\"color:#e5e5e5;background-color:#000000\">text</span>");
+}
+
+#[test]
+fn html_no_formatting() {
+    assert_eq!(Formatting::new().apply_to_html("<text>"), "&lt;text&gt;");
+}
+
+#[test]
+fn html_overline_and_frames() {
+    assert_eq!(
+        Formatting::new().styles(vec![Styles::Overline, Styles::Underline, Styles::Encircle]).apply_to_html("a"),
+        "<span style=\"text-decoration:underline overline;border:1px solid;border-radius:50%\">a</span>"
+    );
+}
+
+#[test]
+fn svg_grid() {
+    let _color = common::colors_on();
+    let text = format!("a{}\n{}\n", "bc".red().on_blue(), "x".invert());
+    assert_eq!(
+        render::svg(&text),
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"45\" height=\"54\" viewBox=\"0 0 45 54\" ",
+            "font-family=\"monospace\" font-size=\"15\">\n",
+            "<rect width=\"100%\" height=\"100%\" fill=\"#000000\"/>\n",
+            "<text x=\"9\" y=\"22\" textLength=\"9\" fill=\"#e5e5e5\" xml:space=\"preserve\">a</text>\n",
+            "<rect x=\"18\" y=\"9\" width=\"18\" height=\"18\" fill=\"#0000ee\"/>\n",
+            "<text x=\"18\" y=\"22\" textLength=\"18\" fill=\"#cd0000\" xml:space=\"preserve\">bc</text>\n",
+            "<rect x=\"9\" y=\"27\" width=\"9\" height=\"18\" fill=\"#e5e5e5\"/>\n",
+            "<text x=\"9\" y=\"40\" textLength=\"9\" fill=\"#000000\" xml:space=\"preserve\">x</text>\n",
+            "</svg>\n",
+        )
+    );
+}
+
+#[test]
+fn svg_styles() {
+    let _color = common::colors_on();
+    let text = Formatting::new().styles([Styles::Bold, Styles::CurlyUnderline, Styles::Strikethrough]).apply_to("<&>");
+    let svg = render::svg(&text);
+    assert!(svg.contains(" font-weight=\"bold\" text-decoration=\"line-through underline\">&lt;&amp;&gt;</text>"));
+    assert!(!render::svg(&"secret".invisible().to_string()).contains("secret"));
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "std")]
+
+use std::backtrace::Backtrace;
+use std::error::Error;
+use std::fmt;
+
+use coloring::*;
+
+mod common;
+
+#[derive(Debug)]
+struct Chain(&'static str, Option<Box<Chain>>);
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Error for Chain {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.1.as_deref().map(|cause| cause as &(dyn Error + 'static))
+    }
+}
+
+fn plain_theme() -> Theme {
+    let mut theme = Theme::new();
+    for name in ["error", "cause", "hint", "backtrace"] {
+        theme.set(name, Formatting::new());
+    }
+    theme
+}
+
+#[test]
+fn cause_chain() {
+    let error = Chain("couldn't load the config", Some(Box::new(Chain(
+        "couldn't read settings.toml",
+        Some(Box::new(Chain("permission denied\nfor user app", None))),
+    ))));
+    let text = report::Report::new(&error).hint("check the file's owner\nand its mode").theme(plain_theme()).render();
+    assert_eq!(
+        text,
+        concat!(
+            "error: couldn't load the config\n",
+            "  caused by: couldn't read settings.toml\n",
+            "    caused by: permission denied\n",
+            "               for user app\n",
+            "hint: check the file's owner\n",
+            "      and its mode\n",
+        )
+    );
+}
+
+#[test]
+fn styles() {
+    let _color = common::colors_on();
+    let error = Chain("outer", Some(Box::new(Chain("inner", None))));
+    let mut theme = Theme::new();
+    theme.set("cause", Formatting::new().foreground(Color::Magenta).build());
+    let text = report::Report::new(&error).theme(theme).to_string();
+    assert_eq!(text, "\x1B[31;1merror:\x1B[0m outer\n  \x1B[35mcaused by:\x1B[0m inner\n");
+}
+
+#[test]
+fn backtrace() {
+    let error = Chain("boom", None);
+    let text = report::Report::new(&error).backtrace(&Backtrace::disabled()).theme(plain_theme()).render();
+    assert_eq!(text, "error: boom\n");
+
+    let text = report::Report::new(&error).backtrace(&Backtrace::force_capture()).theme(plain_theme()).render();
+    assert!(text.starts_with("error: boom\nbacktrace:\n  "));
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "serde")]
+
+use coloring::*;
+
+mod common;
+
+#[test]
+fn color_names() {
+    assert_eq!(serde_json::to_string(&Color::BrightCyan).unwrap(), r#""bright_cyan""#);
+    assert_eq!(serde_json::to_string(&Color::Colors256(120)).unwrap(), r#""256:120""#);
+    assert_eq!(serde_json::to_string(&Color::RGB { r: 255, g: 0, b: 170 }).unwrap(), r##""#ff00aa""##);
+}
+
+#[test]
+fn bad_color() {
+    assert!(serde_json::from_str::<Color>(r#""octarine""#).is_err());
+    assert!(serde_json::from_str::<Color>(r#""256:999""#).is_err());
+}
+
+#[test]
+fn formatting_round_trip() {
+    let mut formatting = Formatting::new();
+    formatting.foreground(Color::Red).background(Color::RGB { r: 255, g: 0, b: 170 }).styles(vec![Styles::Bold]);
+    let json = serde_json::to_string(&formatting).unwrap();
+    assert_eq!(json, r##"{"fg":"red","bg":"#ff00aa","styles":["bold"]}"##);
+    let parsed: Formatting = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.apply_to("text"), formatting.apply_to("text"));
+}
+
+#[test]
+fn formatting_partial() {
+    let _color = common::colors_on();
+    let parsed: Formatting = serde_json::from_str(r#"{"fg":"256:120"}"#).unwrap();
+    assert_eq!(parsed.apply_to("text"), "\x1B[38;5;120mtext\x1B[0m");
+}
@@ -0,0 +1,48 @@
+use coloring::*;
+
+mod common;
+
+fn red() -> Formatting {
+    Formatting::new().foreground(Color::Red).build()
+}
+
+#[test]
+fn push_merges_matching_spans() {
+    let _color = common::colors_on();
+    let mut styled = StyledString::new();
+    styled.push_styled("a", &red());
+    styled.push_styled("b", &red());
+    styled.push_str("");
+    styled.push_str("c");
+    assert_eq!(styled.spans().count(), 2);
+    assert_eq!(styled.to_string(), "\x1B[31mab\x1B[0mc");
+}
+
+#[test]
+fn slice_by_characters() {
+    let _color = common::colors_on();
+    let mut styled = StyledString::from("héllo ");
+    styled.push_styled("wörld", &red());
+    assert_eq!(styled.len(), 11);
+    assert_eq!(styled.slice(1..3).to_string(), "él");
+    assert_eq!(styled.slice(4..=7).to_string(), "o \x1B[31mwö\x1B[0m");
+    assert_eq!(styled.slice(9..).plain(), "ld");
+    assert!(styled.slice(20..).is_empty());
+}
+
+#[test]
+fn concatenation() {
+    let _color = common::colors_on();
+    let mut styled = StyledString::from("a");
+    styled += "b";
+    styled += StyledString::from_ansi("\x1B[31mc\x1B[0m");
+    let styled = styled + "d";
+    assert_eq!(styled.plain(), "abcd");
+    assert_eq!(styled.to_string(), "ab\x1B[31mc\x1B[0md");
+}
+
+#[test]
+fn from_ansi_round_trip() {
+    let text = format!("{} and {}", "red".red(), "bold".bold());
+    assert_eq!(StyledString::from_ansi(&text).to_string(), text);
+}
@@ -0,0 +1,28 @@
+use coloring::*;
+use coloring::table::{Align, Table};
+
+mod common;
+
+#[test]
+fn columns_ignore_escape_codes() {
+    let _color = common::colors_on();
+    let mut table = Table::new();
+    table.add_row([StyledString::from_ansi(&"red".red().to_string()), StyledString::from("x")]);
+    table.add_row(["longer", "y"]);
+    assert_eq!(table.render(), "\x1B[31mred\x1B[0m     x\nlonger  y\n");
+}
+
+#[test]
+fn alignment_and_separator() {
+    let mut table = Table::new();
+    table.add_row(["a", "b", "c"]).add_row(["aaaa", "bbbb", "cccc"]);
+    table.align(1, Align::Center).align(2, Align::Right).separator(" | ");
+    assert_eq!(table.render(), "a    |  b   |    c\naaaa | bbbb | cccc\n");
+}
+
+#[test]
+fn ragged_rows() {
+    let mut table = Table::new();
+    table.add_row(["a", "b"]).add_row(["c"]).add_row(Vec::<String>::new());
+    assert_eq!(table.render(), "a  b\nc\n\n");
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "termcolor")]
+
+use coloring::*;
+use termcolor::{Ansi, ColorSpec, NoColor};
+
+#[test]
+fn color_spec() {
+    let formatting = Formatting::new().foreground(Color::BrightRed).background(Color::Blue).bold().italic().blink().build();
+    let mut expected = ColorSpec::new();
+    expected.set_fg(Some(termcolor::Color::Ansi256(9))).set_bg(Some(termcolor::Color::Blue)).set_bold(true).set_italic(true);
+    assert_eq!(ColorSpec::from(&formatting), expected);
+    assert!(ColorSpec::from(Formatting::new()).is_none());
+}
+
+#[test]
+fn write_color() {
+    let formatting = Formatting::new().foreground(Color::Green).underline().build();
+    let mut ansi = Ansi::new(Vec::new());
+    formatting.write_color(&mut ansi, "text").unwrap();
+    assert_eq!(String::from_utf8(ansi.into_inner()).unwrap(), "\x1B[0m\x1B[4m\x1B[32mtext\x1B[0m");
+
+    let mut plain = NoColor::new(Vec::new());
+    formatting.write_color(&mut plain, "text").unwrap();
+    assert_eq!(plain.into_inner(), b"text");
+
+    let mut ansi = Ansi::new(Vec::new());
+    formatting.clone().when(false).write_color(&mut ansi, "text").unwrap();
+    assert_eq!(ansi.into_inner(), b"text");
+}
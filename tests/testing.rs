@@ -0,0 +1,32 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn diff_marks_changes() {
+    let _color = common::colors_on();
+    let diff = testing::diff("kitten", "sitting");
+    assert_eq!(diff.plain(), "ksitteing");
+    assert_eq!(
+        diff.to_string(),
+        "\x1B[31;9mk\x1B[0m\x1B[32;4ms\x1B[0mitt\x1B[31;9me\x1B[0m\x1B[32;4mi\x1B[0mn\x1B[32;4mg\x1B[0m"
+    );
+}
+
+#[test]
+fn diff_escapes_control_characters() {
+    let diff = testing::diff("\x1B[1m", "\x1B[1m");
+    assert_eq!(diff.to_string(), "\\u{1b}[1m");
+    assert_eq!(testing::diff("", "").to_string(), "");
+}
+
+#[test]
+fn assert_same_passes() {
+    testing::assert_same("same", "same");
+}
+
+#[test]
+#[should_panic(expected = "strings differ")]
+fn assert_same_panics() {
+    testing::assert_same("one", "two");
+}
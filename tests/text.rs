@@ -0,0 +1,149 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn strip_sgr() {
+    let formatted = Formatting::new().foreground(Color::RGB { r: 1, g: 2, b: 3 }).styles(vec![Styles::Bold]).apply_to("text");
+    assert_eq!(strip(&formatted), "text");
+}
+
+#[test]
+fn strip_other_sequences() {
+    assert_eq!(strip("\x1B[2K\x1B[1Aa\x1B]8;;https://example.com\x1B\\b\x1B]8;;\x07c\x1B7"), "abc");
+}
+
+#[test]
+fn strip_plain() {
+    assert_eq!(strip("just text, ünïcödé"), "just text, ünïcödé");
+}
+
+#[test]
+fn strip_unfinished() {
+    assert_eq!(strip("text\x1B[31"), "text");
+}
+
+#[test]
+fn strip_into_writer() {
+    let mut out = String::new();
+    strip_into("\x1B[1ma\x1B[0mb", &mut out).unwrap();
+    assert_eq!(out, "ab");
+}
+
+#[test]
+fn width_ignores_escapes() {
+    let formatted = Formatting::new().foreground(Color::Colors256(100)).apply_to("text");
+    assert_eq!(visible_width(&formatted), 4);
+    assert_eq!(visible_width(&format!("{} {}", formatted, "more".bold())), 9);
+}
+
+#[test]
+fn width_counts_characters() {
+    assert_eq!(visible_width("ünï"), 3);
+}
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn width_unicode() {
+    assert_eq!(visible_width("\x1B[31m日本\x1B[0m"), 4);
+    assert_eq!(visible_width("e\u{301}"), 1);
+}
+
+#[test]
+fn padding() {
+    let formatted = "ab".red().to_string();
+    assert_eq!(pad_left(&formatted, 4), format!("  {}", formatted));
+    assert_eq!(pad_right(&formatted, 4), format!("{}  ", formatted));
+    assert_eq!(center(&formatted, 5), format!(" {}  ", formatted));
+}
+
+#[test]
+fn padding_too_wide() {
+    let formatted = "abcdef".red().to_string();
+    assert_eq!(pad_left(&formatted, 4), formatted);
+    assert_eq!(pad_right(&formatted, 4), formatted);
+    assert_eq!(center(&formatted, 4), formatted);
+}
+
+#[test]
+fn wrap_plain() {
+    assert_eq!(wrap("the quick brown fox", 10), "the quick\nbrown fox");
+    assert_eq!(wrap("the  quick\n\nbrown   fox ", 9), "the\nquick\n\nbrown\nfox");
+    assert_eq!(wrap("  indented text", 10), "  indented\ntext");
+    assert_eq!(wrap("", 10), "");
+}
+
+#[test]
+fn wrap_long_words() {
+    assert_eq!(wrap("abcdefgh ij", 3), "abc\ndef\ngh\nij");
+    assert_eq!(wrap("abc", 0), "a\nb\nc");
+}
+
+#[test]
+fn wrap_reopens_styles() {
+    let _color = common::colors_on();
+    let text = format!("{} {}", "one two".red(), "three".bold());
+    assert_eq!(wrap(&text, 5), "\x1B[31mone\x1B[0m\n\x1B[31mtwo\x1B[0m\n\x1B[1mthree\x1B[0m");
+
+    let text = "\x1B[1mbold \x1B[31mred\x1B[0m plain";
+    assert_eq!(wrap(text, 5), "\x1B[1mbold\x1B[0m\n\x1B[1m\x1B[31mred\x1B[0m\nplain");
+}
+
+#[test]
+fn wrap_keeps_width_of_styled_text() {
+    let text = "word ".repeat(20).red().to_string();
+    for line in wrap(&text, 12).lines() {
+        assert!(visible_width(line) <= 12, "{:?}", line);
+    }
+    assert_eq!(strip(&wrap(&text, 12)), strip(&wrap(&strip(&text), 12)));
+}
+
+#[test]
+fn truncate_plain() {
+    assert_eq!(truncate_visible("hello world", 8, "…"), "hello w…");
+    assert_eq!(truncate_visible("hello world", 8, "..."), "hello...");
+    assert_eq!(truncate_visible("hello", 5, "…"), "hello");
+    assert_eq!(truncate_visible("hello", 2, "..."), "..");
+    assert_eq!(truncate_visible("hello", 0, "…"), "");
+}
+
+#[test]
+fn truncate_closes_styles() {
+    let _color = common::colors_on();
+    let text = format!("{}{}", "ab".red(), "cd".bold());
+    assert_eq!(truncate_visible(&text, 3, "…"), "\x1B[31mab\x1B[0m\x1B[1m…\x1B[0m");
+    assert_eq!(truncate_visible(&text, 2, "…"), "\x1B[31ma…\x1B[0m");
+    assert_eq!(truncate_visible("\x1B[1mabc\x1B[0mdef", 4, "…"), "\x1B[1mabc\x1B[0m…");
+    assert_eq!(visible_width(&truncate_visible(&text, 3, "…")), 3);
+}
+
+#[test]
+fn highlight_plain() {
+    let _color = common::colors_on();
+    let found = Formatting::new().foreground(Color::Red).build();
+    assert_eq!(highlight("aXbXX", "X", &found), "a\x1B[31mX\x1B[0mb\x1B[31mX\x1B[0m\x1B[31mX\x1B[0m");
+    assert_eq!(highlight("aaaa", "aa", &found), "\x1B[31maa\x1B[0m\x1B[31maa\x1B[0m");
+    assert_eq!(highlight("abc", "", &found), "abc");
+    assert_eq!(highlight("abc", "x", &found), "abc");
+    assert_eq!(highlight("héllo", "é", &found), "h\x1B[31mé\x1B[0mllo");
+}
+
+#[test]
+fn highlight_styled() {
+    let _color = common::colors_on();
+    let found = Formatting::new().background(Color::Yellow).build();
+    let text = format!("{}{}", "ab".red(), "cd".bold());
+    assert_eq!(
+        highlight(&text, "bc", &found),
+        "\x1B[31ma\x1B[43mb\x1B[0m\x1B[43m\x1B[1m\x1B[43mc\x1B[0m\x1B[1md\x1B[0m"
+    );
+    assert_eq!(strip(&highlight(&text, "bc", &found)), "abcd");
+    assert_eq!(highlight(&text, "cd", &found), "\x1B[31mab\x1B[0m\x1B[1m\x1B[43mcd\x1B[0m\x1B[1m\x1B[0m");
+}
+
+#[test]
+fn highlight_targeted_reset() {
+    let _color = common::colors_on();
+    let found = Formatting::new().background(Color::Yellow).reset_mode(ResetMode::Targeted).build();
+    assert_eq!(highlight(&"abc".red().to_string(), "b", &found), "\x1B[31ma\x1B[43mb\x1B[49m\x1B[31mc\x1B[0m");
+}
@@ -0,0 +1,60 @@
+use coloring::*;
+
+mod common;
+
+#[test]
+fn default_theme() {
+    let _color = common::colors_on();
+    let theme = Theme::default();
+    assert_eq!(theme.apply("error", "text"), "\x1B[31;1mtext\x1B[0m");
+    assert_eq!(theme.apply("warning", "text"), "\x1B[33;1mtext\x1B[0m");
+    assert!(theme.contains("hint"));
+}
+
+#[test]
+fn unknown_name() {
+    assert_eq!(Theme::default().apply("nope", "text"), "text");
+    assert_eq!(Theme::new().apply("error", "text"), "text");
+}
+
+#[test]
+fn set_and_remove() {
+    let _color = common::colors_on();
+    let mut theme = Theme::new();
+    theme.set("link", Formatting::new().foreground(Color::Blue).styles(vec![Styles::Underline]).clone());
+    assert_eq!(theme.apply("link", "text"), "\x1B[34;4mtext\x1B[0m");
+    assert!(theme.remove("link").is_some());
+    assert_eq!(theme.names().count(), 0);
+}
+
+#[test]
+fn extend_overrides() {
+    let _color = common::colors_on();
+    let mut overrides = Theme::new();
+    overrides.set("error", Formatting::new().foreground(Color::Magenta).clone());
+    let mut theme = Theme::default();
+    theme.extend(overrides);
+    assert_eq!(theme.apply("error", "text"), "\x1B[35mtext\x1B[0m");
+    assert!(theme.contains("warning"));
+}
+
+#[test]
+fn from_colors_str() {
+    let _color = common::colors_on();
+    let theme = Theme::from_colors_str("error=01;31: warn = 38;5;208 :info=italic #00ff00:=1:bad=octarine:empty=");
+    assert_eq!(theme.apply("error", "text"), "\x1B[31;1mtext\x1B[0m");
+    assert_eq!(theme.apply("warn", "text"), "\x1B[38;5;208mtext\x1B[0m");
+    assert_eq!(theme.apply("info", "text"), "\x1B[38;2;0;255;0;3mtext\x1B[0m");
+    assert_eq!(theme.names().count(), 3);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_env() {
+    let _color = common::colors_on();
+    std::env::set_var("COLORING_TEST_THEME", "hint=34");
+    let mut theme = Theme::default();
+    theme.extend(Theme::from_env("COLORING_TEST_THEME"));
+    assert_eq!(theme.apply("hint", "text"), "\x1B[34mtext\x1B[0m");
+    assert_eq!(Theme::from_env("COLORING_TEST_THEME_UNSET").names().count(), 0);
+}
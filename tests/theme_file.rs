@@ -0,0 +1,63 @@
+#![cfg(feature = "toml")]
+
+use coloring::*;
+
+mod common;
+
+#[test]
+fn specs_and_tables() {
+    let _color = common::colors_on();
+    let theme = Theme::from_toml_str(
+        r#"
+        error = "bold red"
+        hint = "bright black"
+
+        [link]
+        fg = "blue"
+        bg = "256:236"
+        styles = ["underline", "italic"]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(theme.apply("error", "text"), "\x1B[31;1mtext\x1B[0m");
+    assert_eq!(theme.apply("link", "text"), "\x1B[34;48;5;236;3;4mtext\x1B[0m");
+    assert_eq!(theme.names().collect::<Vec<_>>(), ["error", "hint", "link"]);
+}
+
+#[test]
+fn errors_name_the_entry() {
+    let error = |toml: &str| Theme::from_toml_str(toml).unwrap_err().to_string();
+    assert_eq!(error("a = \"on\""), r#"theme entry "a": invalid spec"#);
+    assert_eq!(error("[a]\nfg = \"octarine\""), r#"theme entry "a": invalid color"#);
+    assert_eq!(error("[a]\nstyles = [\"loud\"]"), r#"theme entry "a": unknown style "loud""#);
+    assert_eq!(
+        error("[a]\ncolour = \"red\""),
+        r#"theme entry "a": unknown key "colour" (expected `fg`, `bg`, or `styles`)"#
+    );
+    assert_eq!(error("a = 3"), r#"theme entry "a": expected a spec string or a table"#);
+    assert!(matches!(Theme::from_toml_str("a = "), Err(ThemeFileError::Toml(_))));
+}
+
+#[test]
+fn details_are_in_the_source() {
+    let source = |toml: &str| {
+        let error = Theme::from_toml_str(toml).unwrap_err();
+        std::error::Error::source(&error).map(ToString::to_string)
+    };
+    assert_eq!(source("a = \"on\"").unwrap(), r#"invalid style spec "on": expected a color after `on`"#);
+    assert_eq!(source("[a]\nfg = \"octarine\"").unwrap(), r#"invalid color: "octarine""#);
+    assert!(source("a = ").is_some());
+    assert_eq!(source("a = 3"), None);
+}
+
+#[test]
+fn from_path() {
+    let _color = common::colors_on();
+    let path = std::env::temp_dir().join(format!("coloring-theme-{}.toml", std::process::id()));
+    std::fs::write(&path, "info = \"cyan\"\n").unwrap();
+    let theme = Theme::from_path(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(theme.unwrap().apply("info", "text"), "\x1B[36mtext\x1B[0m");
+
+    assert!(matches!(Theme::from_path(&path), Err(ThemeFileError::Io(_))));
+}
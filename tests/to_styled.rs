@@ -0,0 +1,50 @@
+use coloring::*;
+
+mod common;
+
+struct Status {
+    name: &'static str,
+    ok: bool,
+}
+
+impl ToStyled for Status {
+    fn style(&self, theme: &Theme) -> StyledString {
+        let mut line = StyledString::from(self.name);
+        line.push_str(": ");
+        let (text, name) = if self.ok { ("up", "success") } else { ("down", "error") };
+        line.push_styled(text, theme.get(name).unwrap_or(&Formatting::new()));
+        line
+    }
+}
+
+#[test]
+fn with_theme() {
+    let _color = common::colors_on();
+    let theme = Theme::default();
+    let db = Status { name: "db", ok: false };
+    assert_eq!(db.to_styled_string(&theme), "db: \x1B[31;1mdown\x1B[0m");
+    assert_eq!(format!("[{}]", db.with_theme(&theme)), "[db: \x1B[31;1mdown\x1B[0m]");
+    assert_eq!(db.to_styled_string(&Theme::new()), "db: down");
+}
+
+#[test]
+fn blanket_impls() {
+    let _color = common::colors_on();
+    let theme = Theme::default();
+    let statuses: Vec<Box<dyn ToStyled>> =
+        vec![Box::new(Status { name: "a", ok: true }), Box::new(StyledString::from("plain"))];
+    let lines: Vec<String> = statuses.iter().map(|status| status.to_styled_string(&theme)).collect();
+    assert_eq!(lines, ["a: \x1B[32mup\x1B[0m", "plain"]);
+
+    let status = Status { name: "b", ok: true };
+    let by_ref = &status;
+    assert_eq!(by_ref.style(&theme).plain(), "b: up");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn styled_uses_registry() {
+    // Nothing else in this file touches the registry.
+    let status = Status { name: "c", ok: true };
+    assert_eq!(status.styled().to_string(), "c: up");
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "tracing")]
+
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use coloring::tracing_format::EventFormatter;
+use tracing_subscriber::fmt::MakeWriter;
+
+mod common;
+
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Buffer {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Buffer {
+    type Writer = Buffer;
+
+    fn make_writer(&'a self) -> Buffer {
+        self.clone()
+    }
+}
+
+fn capture(ansi: bool, log: impl FnOnce()) -> String {
+    let buffer = Buffer::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(ansi)
+        .event_format(EventFormatter::new())
+        .with_writer(buffer.clone())
+        .finish();
+    tracing::subscriber::with_default(subscriber, log);
+    let bytes = buffer.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+fn colored() {
+    let _color = common::colors_on();
+    let output = capture(true, || {
+        let _span = tracing::info_span!("request", id = 7).entered();
+        tracing::warn!(target: "app", "slow");
+    });
+    // The span's fields are colored by tracing-subscriber's own field formatter.
+    let fields = "{\x1B[3mid\x1B[0m\x1B[2m=\x1B[0m7}";
+    assert_eq!(output, format!("\x1B[33;1mWARN \x1B[0m \x1B[1;4mrequest\x1B[0m{}: \x1B[90mapp\x1B[0m: slow\n", fields));
+}
+
+#[test]
+fn without_ansi() {
+    let output = capture(false, || tracing::error!(target: "app", answer = 42, "oh no"));
+    assert_eq!(output, "ERROR app: oh no answer=42\n");
+}